@@ -36,6 +36,19 @@ impl Span {
             end: s.end(),
         }
     }
+
+    /// Checks if the given offset lies within this span. Like pest spans, the end of a span is exclusive.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Creates the smallest span covering both spans, including any text between them.
+    pub fn merge(&self, other: &Span) -> Span {
+        Span {
+            start: std::cmp::min(self.start, other.start),
+            end: std::cmp::max(self.end, other.end),
+        }
+    }
 }
 
 impl std::fmt::Display for Span {
@@ -65,6 +78,13 @@ impl Identifier {
             span: Span::empty(),
         }
     }
+
+    pub fn with_lifted_span(self, offset: usize) -> Identifier {
+        Identifier {
+            name: self.name,
+            span: lift_span(&self.span, offset),
+        }
+    }
 }
 
 impl WithSpan for Identifier {
@@ -150,7 +170,11 @@ impl Argument {
     pub fn new_function(name: &str, fn_name: &str, value: Vec<Value>) -> Argument {
         Argument {
             name: Identifier::new(name),
-            value: Value::Function(fn_name.to_string(), value, Span::empty()),
+            value: Value::Function(
+                fn_name.to_string(),
+                value.into_iter().map(|v| Argument::new("", v)).collect(),
+                Span::empty(),
+            ),
             span: Span::empty(),
         }
     }
@@ -162,6 +186,14 @@ impl Argument {
             span: Span::empty(),
         }
     }
+
+    pub fn with_lifted_span(self, offset: usize) -> Argument {
+        Argument {
+            name: self.name.with_lifted_span(offset),
+            value: self.value.with_lifted_span(offset),
+            span: lift_span(&self.span, offset),
+        }
+    }
 }
 
 // TODO: Rename to expression.
@@ -181,12 +213,26 @@ pub enum Value {
     /// This is used for representing builtin enums.
     ConstantValue(String, Span),
     /// A function with a name and arguments, which is evaluated at client side.
-    Function(String, Vec<Value>, Span),
+    /// Arguments may be named, e.g. the sort order in `@@index([createdAt(sort: Desc)])`.
+    Function(String, Vec<Argument>, Span),
     /// An array of other values.
     Array(Vec<Value>, Span),
 }
 
 impl Value {
+    /// The span of the value in the source, for arrays and functions including all elements.
+    pub fn span(&self) -> Span {
+        match self {
+            Value::NumericValue(_, s) => *s,
+            Value::BooleanValue(_, s) => *s,
+            Value::StringValue(_, s) => *s,
+            Value::ConstantValue(_, s) => *s,
+            Value::Function(_, _, s) => *s,
+            Value::Array(_, s) => *s,
+            Value::Any(_, s) => *s,
+        }
+    }
+
     pub fn with_lifted_span(&self, offset: usize) -> Value {
         match self {
             Value::NumericValue(v, s) => Value::NumericValue(v.clone(), lift_span(&s, offset)),
@@ -195,7 +241,7 @@ impl Value {
             Value::ConstantValue(v, s) => Value::ConstantValue(v.clone(), lift_span(&s, offset)),
             Value::Function(v, a, s) => Value::Function(
                 v.clone(),
-                a.iter().map(|elem| elem.with_lifted_span(offset)).collect(),
+                a.iter().map(|arg| arg.clone().with_lifted_span(offset)).collect(),
                 lift_span(&s, offset),
             ),
             Value::Array(v, s) => Value::Array(
@@ -215,6 +261,10 @@ pub fn lift_span(span: &Span, offset: usize) -> Span {
     }
 }
 
+fn lift_spans<T>(nodes: Vec<T>, offset: usize, lift: fn(T, usize) -> T) -> Vec<T> {
+    nodes.into_iter().map(|node| lift(node, offset)).collect()
+}
+
 /// Creates a friendly readable representation for a value's type.
 pub fn describe_value_type(val: &Value) -> &'static str {
     match val {
@@ -258,6 +308,14 @@ impl Directive {
             span: Span::empty(),
         }
     }
+
+    pub fn with_lifted_span(self, offset: usize) -> Directive {
+        Directive {
+            name: self.name.with_lifted_span(offset),
+            arguments: lift_spans(self.arguments, offset, Argument::with_lifted_span),
+            span: lift_span(&self.span, offset),
+        }
+    }
 }
 
 impl WithIdentifier for Directive {
@@ -307,6 +365,20 @@ impl WithIdentifier for Field {
     }
 }
 
+impl Field {
+    pub fn with_lifted_span(self, offset: usize) -> Field {
+        Field {
+            field_type: self.field_type.with_lifted_span(offset),
+            name: self.name.with_lifted_span(offset),
+            arity: self.arity,
+            default_value: self.default_value.map(|value| value.with_lifted_span(offset)),
+            directives: lift_spans(self.directives, offset, Directive::with_lifted_span),
+            documentation: self.documentation,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithSpan for Field {
     fn span(&self) -> &Span {
         &self.span
@@ -346,6 +418,18 @@ impl WithIdentifier for Enum {
     }
 }
 
+impl Enum {
+    pub fn with_lifted_span(self, offset: usize) -> Enum {
+        Enum {
+            name: self.name.with_lifted_span(offset),
+            values: lift_spans(self.values, offset, EnumValue::with_lifted_span),
+            directives: lift_spans(self.directives, offset, Directive::with_lifted_span),
+            documentation: self.documentation,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithSpan for Enum {
     fn span(&self) -> &Span {
         &self.span
@@ -379,6 +463,15 @@ impl WithName for EnumValue {
     }
 }
 
+impl EnumValue {
+    pub fn with_lifted_span(self, offset: usize) -> EnumValue {
+        EnumValue {
+            name: self.name,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithSpan for EnumValue {
     fn span(&self) -> &Span {
         &self.span
@@ -406,6 +499,18 @@ impl WithIdentifier for Model {
     }
 }
 
+impl Model {
+    pub fn with_lifted_span(self, offset: usize) -> Model {
+        Model {
+            name: self.name.with_lifted_span(offset),
+            fields: lift_spans(self.fields, offset, Field::with_lifted_span),
+            directives: lift_spans(self.directives, offset, Directive::with_lifted_span),
+            documentation: self.documentation,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithSpan for Model {
     fn span(&self) -> &Span {
         &self.span
@@ -443,6 +548,17 @@ impl WithIdentifier for SourceConfig {
     }
 }
 
+impl SourceConfig {
+    pub fn with_lifted_span(self, offset: usize) -> SourceConfig {
+        SourceConfig {
+            name: self.name.with_lifted_span(offset),
+            properties: lift_spans(self.properties, offset, Argument::with_lifted_span),
+            documentation: self.documentation,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithKeyValueConfig for SourceConfig {
     fn properties(&self) -> &Vec<Argument> {
         &self.properties
@@ -480,6 +596,17 @@ impl WithIdentifier for GeneratorConfig {
     }
 }
 
+impl GeneratorConfig {
+    pub fn with_lifted_span(self, offset: usize) -> GeneratorConfig {
+        GeneratorConfig {
+            name: self.name.with_lifted_span(offset),
+            properties: lift_spans(self.properties, offset, Argument::with_lifted_span),
+            documentation: self.documentation,
+            span: lift_span(&self.span, offset),
+        }
+    }
+}
+
 impl WithKeyValueConfig for GeneratorConfig {
     fn properties(&self) -> &Vec<Argument> {
         &self.properties
@@ -534,6 +661,17 @@ impl WithSpan for Top {
 }
 
 impl Top {
+    /// Adds an offset to all spans of this top level node and its children.
+    pub fn with_lifted_span(self, offset: usize) -> Top {
+        match self {
+            Top::Enum(x) => Top::Enum(x.with_lifted_span(offset)),
+            Top::Model(x) => Top::Model(x.with_lifted_span(offset)),
+            Top::Source(x) => Top::Source(x.with_lifted_span(offset)),
+            Top::Generator(x) => Top::Generator(x.with_lifted_span(offset)),
+            Top::Type(x) => Top::Type(x.with_lifted_span(offset)),
+        }
+    }
+
     pub fn get_type(&self) -> &str {
         match self {
             Top::Enum(_) => "enum",
@@ -50,11 +50,16 @@ pub fn parse_expression(token: &pest::iterators::Pair<'_, Rule>) -> Value {
 
 fn parse_function(token: &pest::iterators::Pair<'_, Rule>) -> Value {
     let mut name: Option<String> = None;
-    let mut arguments: Vec<Value> = vec![];
+    let mut arguments: Vec<Argument> = vec![];
 
     match_children! { token, current,
         Rule::identifier => name = Some(current.as_str().to_string()),
-        Rule::argument_value => arguments.push(parse_arg_value(&current)),
+        Rule::argument => arguments.push(parse_directive_arg(&current)),
+        Rule::argument_value => arguments.push(Argument {
+            name: Identifier::new(""),
+            value: parse_arg_value(&current),
+            span: Span::from_pest(current.as_span())
+        }),
         _ => unreachable!("Encounterd impossible function during parsing: {:?}", current.tokens())
     };
 
@@ -87,10 +92,8 @@ fn parse_arg_value(token: &pest::iterators::Pair<'_, Rule>) -> Value {
 fn parse_doc_comment(token: &pest::iterators::Pair<'_, Rule>) -> String {
     match_first! { token, current,
         Rule::doc_content => {
-            let mut comment = String::from(current.as_str());
-            // Remove new line character.
-            comment.truncate(comment.len() - 1);
-            comment
+            // Remove new line characters.
+            String::from(current.as_str().trim_end_matches(|c| c == '\n' || c == '\r'))
         },
         _ => unreachable!("Encountered impossible doc comment during parsing: {:?}", current.tokens())
     }
@@ -169,10 +172,18 @@ fn parse_directive(token: &pest::iterators::Pair<'_, Rule>) -> Directive {
 fn parse_base_type(token: &pest::iterators::Pair<'_, Rule>) -> String {
     match_first! { token, current,
         Rule::identifier => current.as_str().to_string(),
+        Rule::unsupported_type => format!("Unsupported(\"{}\")", parse_unsupported_type(&current)),
         _ => unreachable!("Encounterd impossible type during parsing: {:?}", current.tokens())
     }
 }
 
+fn parse_unsupported_type(token: &pest::iterators::Pair<'_, Rule>) -> String {
+    match_first! { token, current,
+        Rule::string_literal => parse_string_literal(&current),
+        _ => unreachable!("Encounterd impossible unsupported type during parsing: {:?}", current.tokens())
+    }
+}
+
 fn parse_field_type(token: &pest::iterators::Pair<'_, Rule>) -> Result<(FieldArity, String), ValidationError> {
     match_first! { token, current,
         Rule::optional_type => Ok((FieldArity::Optional, parse_base_type(&current))),
@@ -469,6 +480,105 @@ pub fn parse(datamodel_string: &str) -> Result<Datamodel, ErrorCollection> {
     }
 }
 
+/// Parses a Prisma V2 datamodel document from a reader into an internal AST representation.
+///
+/// The input is consumed one top level block at a time, so only the text of the block
+/// currently being parsed is kept in memory next to the AST. All spans are byte offsets
+/// relative to the start of the input, like the spans produced by `parse`.
+pub fn parse_reader<R: std::io::Read>(reader: R) -> Result<Datamodel, ErrorCollection> {
+    use std::io::BufRead;
+
+    let mut reader = std::io::BufReader::new(reader);
+    let mut errors = ErrorCollection::new();
+    let mut models: Vec<Top> = vec![];
+
+    let mut chunk = String::new();
+    let mut chunk_offset = 0;
+    let mut depth = 0;
+
+    loop {
+        let line_start = chunk.len();
+
+        match reader.read_line(&mut chunk) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                errors.push(ValidationError::new_validation_error(
+                    &format!("Could not read the datamodel: {}", err),
+                    Span::new(chunk_offset + line_start, chunk_offset + line_start),
+                ));
+                return Err(errors);
+            }
+        }
+
+        // A top level block is complete as soon as its closing brace brings us back to the top level.
+        if track_block_depth(&chunk[line_start..], &mut depth) && depth <= 0 {
+            parse_chunk(&chunk, chunk_offset, &mut models, &mut errors);
+            chunk_offset += chunk.len();
+            chunk.clear();
+            depth = 0;
+        }
+    }
+
+    if !chunk.is_empty() {
+        parse_chunk(&chunk, chunk_offset, &mut models, &mut errors);
+    }
+
+    errors.ok()?;
+
+    Ok(Datamodel { models })
+}
+
+/// Parses a part of a datamodel document, lifting all spans by the chunk's offset in the document.
+fn parse_chunk(chunk: &str, offset: usize, models: &mut Vec<Top>, errors: &mut ErrorCollection) {
+    match parse(chunk) {
+        Ok(datamodel) => models.extend(datamodel.models.into_iter().map(|top| top.with_lifted_span(offset))),
+        Err(err) => {
+            for error in err.to_iter() {
+                errors.push(lift_parser_error(error, offset));
+            }
+        }
+    }
+}
+
+fn lift_parser_error(error: &ValidationError, offset: usize) -> ValidationError {
+    match error {
+        ValidationError::ParserError { expected, span, .. } => {
+            ValidationError::new_parser_error(expected, lift_span(span, offset))
+        }
+        ValidationError::LegacyParserError { message, span } => {
+            ValidationError::new_legacy_parser_error(message, lift_span(span, offset))
+        }
+        _ => unreachable!("Encountered a non-parser error during parsing: {:?}", error),
+    }
+}
+
+/// Updates the block depth with the braces on the given line, ignoring braces inside of
+/// string literals and comments. Returns true if the line closes a block.
+fn track_block_depth(line: &str, depth: &mut i32) -> bool {
+    let mut closes_block = false;
+    let mut in_string = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '/' if !in_string && chars.peek() == Some(&'/') => break,
+            '{' if !in_string => *depth += 1,
+            '}' if !in_string => {
+                *depth -= 1;
+                closes_block = true;
+            }
+            _ => {}
+        }
+    }
+
+    closes_block
+}
+
 pub fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
     positives
         .iter()
@@ -501,6 +611,7 @@ pub fn rule_to_string(rule: Rule) -> &'static str {
         Rule::directive => "directive",
         Rule::optional_type => "optional type",
         Rule::base_type => "type",
+        Rule::unsupported_type => "unsupported type",
         Rule::list_type => "list type",
         Rule::field_type => "field type",
         Rule::field_declaration => "field declaration",
@@ -527,6 +527,13 @@ impl Reformatter {
                     target.write(current.as_str());
                     target.write("(");
                 }
+                Rule::argument => {
+                    if expr_count > 0 {
+                        target.write(", ");
+                    }
+                    Self::reformat_directive_arg(target, &current);
+                    expr_count += 1;
+                }
                 Rule::argument_value => {
                     if expr_count > 0 {
                         target.write(", ");
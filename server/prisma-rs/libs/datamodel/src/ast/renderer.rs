@@ -76,8 +76,13 @@ impl<'a> Renderer<'a> {
     pub fn render_documentation(target: &mut dyn LineWriteable, obj: &dyn ast::WithDocumentation) {
         if let Some(doc) = &obj.documentation() {
             for line in doc.text.split('\n') {
-                target.write("/// ");
-                target.write(line);
+                // Empty lines are rendered without trailing whitespace.
+                if line.is_empty() {
+                    target.write("///");
+                } else {
+                    target.write("/// ");
+                    target.write(line);
+                }
                 target.end_line();
             }
         }
@@ -309,12 +314,10 @@ impl<'a> Renderer<'a> {
         };
     }
 
-    pub fn render_func(target: &mut dyn LineWriteable, name: &str, vals: &[ast::Value]) {
+    pub fn render_func(target: &mut dyn LineWriteable, name: &str, args: &[ast::Argument]) {
         target.write(name);
         target.write("(");
-        for val in vals {
-            Self::render_value(target, val);
-        }
+        Self::render_arguments(target, args);
         target.write(")");
     }
 
@@ -25,13 +25,20 @@ const BUILTIN_UUID_FUNCTIONAL: builtin::ServerSideTrivialFunctional = builtin::S
     name: "uuid",
     return_type: PrismaType::String,
 };
+// The id is generated by the datastore. The return type is replaced by
+// the type of the field when the default value is parsed.
+const BUILTIN_AUTO_FUNCTIONAL: builtin::ServerSideTrivialFunctional = builtin::ServerSideTrivialFunctional {
+    name: "auto",
+    return_type: PrismaType::Int,
+};
 
 /// Array of all builtin functionals.
-const BUILTIN_FUNCTIONALS: [&dyn Functional; 4] = [
+const BUILTIN_FUNCTIONALS: [&dyn Functional; 5] = [
     &BUILTIN_ENV_FUNCTIONAL,
     &BUILTIN_NOW_FUNCTIONAL,
     &BUILTIN_CUID_FUNCTIONAL,
     &BUILTIN_UUID_FUNCTIONAL,
+    &BUILTIN_AUTO_FUNCTIONAL,
 ];
 
 /// Evaluator for arbitrary expressions.
@@ -61,15 +68,20 @@ impl FunctionalEvaluator {
     fn evaluate_functional(
         &self,
         name: &str,
-        args: &[ast::Value],
+        args: &[ast::Argument],
         span: ast::Span,
     ) -> Result<MaybeExpression, ValidationError> {
         for f in &BUILTIN_FUNCTIONALS {
             if f.name() == name {
                 let mut resolved_args: Vec<ValueValidator> = Vec::new();
 
-                for value in args {
-                    resolved_args.push(ValueValidator::new(value)?)
+                for arg in args {
+                    // Builtin functions only take unnamed arguments.
+                    if !arg.name.name.is_empty() {
+                        return Err(ValidationError::new_unused_argument_error(&arg.name.name, arg.span));
+                    }
+
+                    resolved_args.push(ValueValidator::new(&arg.value)?)
                 }
 
                 return f.apply(&resolved_args, span);
@@ -16,37 +16,57 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaType {
     Int,
+    /// A 64 bit integer.
+    BigInt,
     Float,
     Decimal,
     Boolean,
     String,
     DateTime,
+    Xml,
 }
 
-impl FromStrAndSpan for PrismaType {
-    fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, ValidationError> {
+impl PrismaType {
+    /// Parses the name of a builtin base type, as it appears in the datamodel.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<PrismaType> {
         match s {
-            "Int" => Ok(PrismaType::Int),
-            "Float" => Ok(PrismaType::Float),
-            "Decimal" => Ok(PrismaType::Decimal),
-            "Boolean" => Ok(PrismaType::Boolean),
-            "String" => Ok(PrismaType::String),
-            "DateTime" => Ok(PrismaType::DateTime),
-            _ => Err(ValidationError::new_type_not_found_error(s, span)),
+            "Int" => Some(PrismaType::Int),
+            "BigInt" => Some(PrismaType::BigInt),
+            "Float" => Some(PrismaType::Float),
+            "Decimal" => Some(PrismaType::Decimal),
+            "Boolean" => Some(PrismaType::Boolean),
+            "String" => Some(PrismaType::String),
+            "DateTime" => Some(PrismaType::DateTime),
+            "Xml" => Some(PrismaType::Xml),
+            _ => None,
+        }
+    }
+
+    /// The name of the builtin base type, as it appears in the datamodel.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrismaType::Int => "Int",
+            PrismaType::BigInt => "BigInt",
+            PrismaType::Float => "Float",
+            PrismaType::Decimal => "Decimal",
+            PrismaType::Boolean => "Boolean",
+            PrismaType::String => "String",
+            PrismaType::DateTime => "DateTime",
+            PrismaType::Xml => "Xml",
         }
     }
 }
 
+impl FromStrAndSpan for PrismaType {
+    fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, ValidationError> {
+        PrismaType::from_str(s).ok_or_else(|| ValidationError::new_type_not_found_error(s, span))
+    }
+}
+
 impl ToString for PrismaType {
     fn to_string(&self) -> String {
-        match self {
-            PrismaType::Int => String::from("Int"),
-            PrismaType::Float => String::from("Float"),
-            PrismaType::Decimal => String::from("Decimal"),
-            PrismaType::Boolean => String::from("Boolean"),
-            PrismaType::String => String::from("String"),
-            PrismaType::DateTime => String::from("DateTime"),
-        }
+        String::from(self.as_str())
     }
 }
 
@@ -54,6 +74,7 @@ impl ToString for PrismaType {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum PrismaValue {
     Int(i32),
+    BigInt(i64),
     Float(f32),
     Decimal(f32),
     Boolean(bool),
@@ -61,12 +82,14 @@ pub enum PrismaValue {
     DateTime(DateTime<Utc>),
     ConstantLiteral(String),
     Expression(String, PrismaType, Vec<PrismaValue>),
+    List(Vec<PrismaValue>),
 }
 
 impl PrismaValue {
     fn get_type(&self) -> PrismaType {
         match self {
             PrismaValue::Int(_) => PrismaType::Int,
+            PrismaValue::BigInt(_) => PrismaType::BigInt,
             PrismaValue::Float(_) => PrismaType::Float,
             PrismaValue::Decimal(_) => PrismaType::Decimal,
             PrismaValue::Boolean(_) => PrismaType::Boolean,
@@ -76,6 +99,7 @@ impl PrismaValue {
             PrismaValue::ConstantLiteral(_) => {
                 panic!("Constant literal values do not map to a base type and should never surface.")
             }
+            PrismaValue::List(_) => panic!("List values do not map to a base type and should never surface."),
         }
     }
 }
@@ -84,6 +108,7 @@ impl ToString for PrismaValue {
     fn to_string(&self) -> String {
         match self {
             PrismaValue::Int(val) => val.to_string(),
+            PrismaValue::BigInt(val) => val.to_string(),
             PrismaValue::Float(val) => val.to_string(),
             PrismaValue::Decimal(val) => val.to_string(),
             PrismaValue::Boolean(val) => val.to_string(),
@@ -91,6 +116,10 @@ impl ToString for PrismaValue {
             PrismaValue::DateTime(val) => val.to_string(),
             PrismaValue::ConstantLiteral(val) => val.to_string(),
             PrismaValue::Expression(_, t, _) => format!("Function<{}>", t.to_string()),
+            PrismaValue::List(values) => format!(
+                "[{}]",
+                values.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(", ")
+            ),
         }
     }
 }
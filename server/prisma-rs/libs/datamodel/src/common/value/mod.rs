@@ -88,12 +88,22 @@ impl ValueValidator {
         match &self.value {
             MaybeExpression::Value(_, _) => match scalar_type {
                 PrismaType::Int => wrap_value!(self.as_int(), dml::Value::Int, self),
+                PrismaType::BigInt => wrap_value!(self.as_big_int(), dml::Value::BigInt, self),
                 PrismaType::Float => wrap_value!(self.as_float(), dml::Value::Float, self),
                 PrismaType::Decimal => wrap_value!(self.as_decimal(), dml::Value::Decimal, self),
                 PrismaType::Boolean => wrap_value!(self.as_bool(), dml::Value::Boolean, self),
                 PrismaType::DateTime => wrap_value!(self.as_date_time(), dml::Value::DateTime, self),
-                PrismaType::String => wrap_value!(self.as_str(), dml::Value::String, self),
+                PrismaType::String | PrismaType::Xml => wrap_value!(self.as_str(), dml::Value::String, self),
             },
+            // `auto()` leaves the value to the datastore, so it adopts the type of the field it is used on.
+            MaybeExpression::Expression(PrismaValue::Expression(name, _, args), _)
+                if name == "auto"
+                    && (scalar_type == PrismaType::Int
+                        || scalar_type == PrismaType::BigInt
+                        || scalar_type == PrismaType::String) =>
+            {
+                Ok(PrismaValue::Expression(name.clone(), scalar_type, args.clone()))
+            }
             MaybeExpression::Expression(expr, _) => {
                 if expr.get_type() == scalar_type {
                     Ok(expr.clone())
@@ -121,15 +131,7 @@ impl ValueValidator {
     /// Accesses the span of the wrapped value.
     pub fn span(&self) -> ast::Span {
         match &self.value {
-            MaybeExpression::Value(_, val) => match val {
-                ast::Value::StringValue(_, s) => *s,
-                ast::Value::NumericValue(_, s) => *s,
-                ast::Value::BooleanValue(_, s) => *s,
-                ast::Value::ConstantValue(_, s) => *s,
-                ast::Value::Function(_, _, s) => *s,
-                ast::Value::Array(_, s) => *s,
-                ast::Value::Any(_, s) => *s,
-            },
+            MaybeExpression::Value(_, val) => val.span(),
             MaybeExpression::Expression(_, s) => *s,
         }
     }
@@ -162,6 +164,19 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to a Prisma BigInt.
+    pub fn as_big_int(&self) -> Result<i64, ValidationError> {
+        match &self.value {
+            MaybeExpression::Value(_, ast::Value::NumericValue(value, _)) => {
+                self.wrap_error_from_result(value.parse::<i64>(), "numeric")
+            }
+            MaybeExpression::Value(_, ast::Value::Any(value, _)) => {
+                self.wrap_error_from_result(value.parse::<i64>(), "numeric")
+            }
+            _ => Err(self.construct_error("numeric")),
+        }
+    }
+
     /// Tries to convert the wrapped value to a Prisma Float.
     pub fn as_float(&self) -> Result<f32, ValidationError> {
         match &self.value {
@@ -225,6 +240,14 @@ impl ValueValidator {
         }
     }
 
+    /// Checks if the wrapped value is an array literal.
+    pub fn is_array(&self) -> bool {
+        match &self.value {
+            MaybeExpression::Value(_, ast::Value::Array(_, _)) => true,
+            _ => false,
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Result<Vec<ValueValidator>, ValidationError> {
         match &self.value {
@@ -288,11 +311,15 @@ impl Into<ast::Value> for &dml::Value {
             dml::Value::Decimal(value) => ast::Value::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Float(value) => ast::Value::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Int(value) => ast::Value::NumericValue(value.to_string(), ast::Span::empty()),
+            dml::Value::BigInt(value) => ast::Value::NumericValue(value.to_string(), ast::Span::empty()),
             dml::Value::Expression(name, _, args) => ast::Value::Function(
                 name.clone(),
-                args.iter().map(|a| a.into()).collect(),
+                args.iter().map(|a| ast::Argument::new("", a.into())).collect(),
                 ast::Span::empty(),
             ),
+            dml::Value::List(values) => {
+                ast::Value::Array(values.iter().map(|v| v.into()).collect(), ast::Span::empty())
+            }
         }
     }
 }
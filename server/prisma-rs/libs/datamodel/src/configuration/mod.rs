@@ -27,6 +27,15 @@ impl Configuration {
             datasources: source::render_sources_to_json_value(&self.datasources),
         }
     }
+
+    /// The datasource the given model is stored in: the one named by `@@datasource`, or the first one.
+    pub fn datasource_for_model(&self, model: &crate::dml::Model) -> Option<&dyn Source> {
+        match &model.datasource {
+            Some(name) => self.datasources.iter().find(|source| source.name() == name),
+            None => self.datasources.first(),
+        }
+        .map(|source| source.as_ref())
+    }
 }
 
 impl From<SerializeableMcf> for Configuration {
@@ -0,0 +1,50 @@
+use super::builtin::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME};
+
+/// The features a connector supports beyond the ones every connector has.
+///
+/// Datamodels using a feature the connector of one of their sources does not support are rejected during
+/// validation, instead of failing when the migration engine applies them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectorCapabilities {
+    /// Scalar lists can be stored as native array columns, declared via `@scalarList(strategy: EMBEDDED)`.
+    pub scalar_list_arrays: bool,
+    /// Tables can live in other schemas than the one of the connector, declared via `@@schema`.
+    pub multiple_schemas: bool,
+    /// Indexes can be declared as `@@fulltext`.
+    pub fulltext_indexes: bool,
+    /// Columns can have `CHECK` constraints, declared via `@check`.
+    pub check_constraints: bool,
+}
+
+impl ConnectorCapabilities {
+    /// The capabilities of the builtin connector with the given type. Connectors defined by plugins are
+    /// not restricted.
+    pub fn for_connector_type(connector_type: &str) -> ConnectorCapabilities {
+        match connector_type {
+            POSTGRES_SOURCE_NAME => ConnectorCapabilities {
+                scalar_list_arrays: true,
+                multiple_schemas: true,
+                fulltext_indexes: true,
+                check_constraints: true,
+            },
+            MYSQL_SOURCE_NAME => ConnectorCapabilities {
+                scalar_list_arrays: false,
+                multiple_schemas: false,
+                fulltext_indexes: true,
+                check_constraints: false,
+            },
+            SQLITE_SOURCE_NAME => ConnectorCapabilities {
+                scalar_list_arrays: false,
+                multiple_schemas: false,
+                fulltext_indexes: false,
+                check_constraints: true,
+            },
+            _ => ConnectorCapabilities {
+                scalar_list_arrays: true,
+                multiple_schemas: true,
+                fulltext_indexes: true,
+                check_constraints: true,
+            },
+        }
+    }
+}
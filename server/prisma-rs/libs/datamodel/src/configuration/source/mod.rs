@@ -1,3 +1,4 @@
+mod capabilities;
 mod json;
 mod loader;
 mod serializer;
@@ -6,6 +7,7 @@ mod traits;
 pub mod builtin;
 
 pub use builtin::*;
+pub use capabilities::*;
 pub use json::{render_sources_to_json, render_sources_to_json_value, sources_from_json_value_with_plugins};
 pub use loader::*;
 pub use serializer::*;
@@ -1,3 +1,4 @@
+use super::ConnectorCapabilities;
 use crate::common::argument::Arguments;
 use crate::dml::validator::directive::DirectiveValidator;
 use crate::errors::ValidationError;
@@ -37,6 +38,11 @@ pub trait Source {
 
     /// Documentation of this source.
     fn documentation(&self) -> &Option<String>;
+
+    /// Gets the features supported by the connector of this source.
+    fn capabilities(&self) -> ConnectorCapabilities {
+        ConnectorCapabilities::for_connector_type(self.connector_type())
+    }
 }
 
 /// Trait for source definitions.
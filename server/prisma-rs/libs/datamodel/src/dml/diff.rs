@@ -0,0 +1,263 @@
+use super::datamodel::Datamodel;
+use super::field::*;
+use super::model::Model;
+use crate::common::PrismaValue;
+
+/// The high-level differences between two datamodels, see `Datamodel::diff`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DatamodelDiff {
+    pub changes: Vec<DatamodelChange>,
+}
+
+impl DatamodelDiff {
+    /// Checks if the two datamodels have no differences this diff can express.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A single difference between two datamodels.
+#[derive(Debug, PartialEq, Clone)]
+pub enum DatamodelChange {
+    ModelAdded {
+        model: String,
+    },
+    ModelRemoved {
+        model: String,
+    },
+    FieldAdded {
+        model: String,
+        field: String,
+    },
+    FieldRemoved {
+        model: String,
+        field: String,
+    },
+    /// A field that exists in both datamodels, but differs in at least one property.
+    FieldChanged {
+        model: String,
+        field: String,
+        changes: Vec<FieldChange>,
+    },
+    EnumAdded {
+        enm: String,
+    },
+    EnumRemoved {
+        enm: String,
+    },
+    /// An enum that exists in both datamodels, but with different values.
+    EnumValuesChanged {
+        enm: String,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// A relation, identified by its name, together with the models it connects.
+    RelationAdded {
+        name: String,
+        models: (String, String),
+    },
+    RelationRemoved {
+        name: String,
+        models: (String, String),
+    },
+}
+
+/// A changed property of a field, holding the previous and the next value.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldChange {
+    Type {
+        previous: FieldType,
+        next: FieldType,
+    },
+    Arity {
+        previous: FieldArity,
+        next: FieldArity,
+    },
+    DefaultValue {
+        previous: Option<PrismaValue>,
+        next: Option<PrismaValue>,
+    },
+    Unique {
+        previous: bool,
+        next: bool,
+    },
+    DatabaseName {
+        previous: Option<String>,
+        next: Option<String>,
+    },
+}
+
+impl Datamodel {
+    /// Computes the changes that lead from this datamodel to the other one.
+    ///
+    /// Models, fields and enums are matched by name, so a rename shows up as a removal and an addition.
+    /// Relations are matched by their name, which is only reliable on standardised datamodels.
+    pub fn diff(&self, other: &Datamodel) -> DatamodelDiff {
+        let mut changes = Vec::new();
+
+        for model in self.models().filter(|m| !other.has_model(&m.name)) {
+            changes.push(DatamodelChange::ModelRemoved {
+                model: model.name.clone(),
+            });
+        }
+
+        for next_model in other.models() {
+            match self.find_model(&next_model.name) {
+                Some(previous_model) => diff_fields(previous_model, next_model, &mut changes),
+                None => changes.push(DatamodelChange::ModelAdded {
+                    model: next_model.name.clone(),
+                }),
+            }
+        }
+
+        for enm in self.enums().filter(|e| !other.has_enum(&e.name)) {
+            changes.push(DatamodelChange::EnumRemoved { enm: enm.name.clone() });
+        }
+
+        for next_enum in other.enums() {
+            match self.find_enum(&next_enum.name) {
+                Some(previous_enum) => {
+                    let added: Vec<String> = next_enum
+                        .values
+                        .iter()
+                        .filter(|v| !previous_enum.values.contains(v))
+                        .cloned()
+                        .collect();
+                    let removed: Vec<String> = previous_enum
+                        .values
+                        .iter()
+                        .filter(|v| !next_enum.values.contains(v))
+                        .cloned()
+                        .collect();
+
+                    if !added.is_empty() || !removed.is_empty() {
+                        changes.push(DatamodelChange::EnumValuesChanged {
+                            enm: next_enum.name.clone(),
+                            added,
+                            removed,
+                        });
+                    }
+                }
+                None => changes.push(DatamodelChange::EnumAdded {
+                    enm: next_enum.name.clone(),
+                }),
+            }
+        }
+
+        let previous_relations = self.relations();
+        let next_relations = other.relations();
+
+        for (name, models) in previous_relations.iter().filter(|r| !next_relations.contains(r)) {
+            changes.push(DatamodelChange::RelationRemoved {
+                name: name.clone(),
+                models: models.clone(),
+            });
+        }
+
+        for (name, models) in next_relations.iter().filter(|r| !previous_relations.contains(r)) {
+            changes.push(DatamodelChange::RelationAdded {
+                name: name.clone(),
+                models: models.clone(),
+            });
+        }
+
+        DatamodelDiff { changes }
+    }
+
+    /// All relations as pairs of their name and the models they connect, in alphabetical order.
+    fn relations(&self) -> Vec<(String, (String, String))> {
+        let mut relations = Vec::new();
+
+        for model in self.models() {
+            for field in model.fields() {
+                if let FieldType::Relation(info) = &field.field_type {
+                    let models = if model.name <= info.to {
+                        (model.name.clone(), info.to.clone())
+                    } else {
+                        (info.to.clone(), model.name.clone())
+                    };
+                    let relation = (info.name.clone(), models);
+
+                    if !relations.contains(&relation) {
+                        relations.push(relation);
+                    }
+                }
+            }
+        }
+
+        relations
+    }
+}
+
+fn diff_fields(previous_model: &Model, next_model: &Model, changes: &mut Vec<DatamodelChange>) {
+    for field in previous_model
+        .fields()
+        .filter(|f| next_model.find_field(&f.name).is_none())
+    {
+        changes.push(DatamodelChange::FieldRemoved {
+            model: previous_model.name.clone(),
+            field: field.name.clone(),
+        });
+    }
+
+    for next_field in next_model.fields() {
+        match previous_model.find_field(&next_field.name) {
+            Some(previous_field) => {
+                let field_changes = diff_field(previous_field, next_field);
+
+                if !field_changes.is_empty() {
+                    changes.push(DatamodelChange::FieldChanged {
+                        model: next_model.name.clone(),
+                        field: next_field.name.clone(),
+                        changes: field_changes,
+                    });
+                }
+            }
+            None => changes.push(DatamodelChange::FieldAdded {
+                model: next_model.name.clone(),
+                field: next_field.name.clone(),
+            }),
+        }
+    }
+}
+
+fn diff_field(previous: &Field, next: &Field) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    if previous.field_type != next.field_type {
+        changes.push(FieldChange::Type {
+            previous: previous.field_type.clone(),
+            next: next.field_type.clone(),
+        });
+    }
+
+    if previous.arity != next.arity {
+        changes.push(FieldChange::Arity {
+            previous: previous.arity,
+            next: next.arity,
+        });
+    }
+
+    if previous.default_value != next.default_value {
+        changes.push(FieldChange::DefaultValue {
+            previous: previous.default_value.clone(),
+            next: next.default_value.clone(),
+        });
+    }
+
+    if previous.is_unique != next.is_unique {
+        changes.push(FieldChange::Unique {
+            previous: previous.is_unique,
+            next: next.is_unique,
+        });
+    }
+
+    if previous.database_name != next.database_name {
+        changes.push(FieldChange::DatabaseName {
+            previous: previous.database_name.clone(),
+            next: next.database_name.clone(),
+        });
+    }
+
+    changes
+}
@@ -1,4 +1,5 @@
 use super::id::*;
+use super::native_type::*;
 use super::relation::*;
 use super::scalar::*;
 use super::traits::*;
@@ -28,6 +29,40 @@ pub enum FieldType {
     },
     /// Base (built-in scalar) type.
     Base(PrismaType),
+    /// A database type Prisma cannot model, e.g. `cidr`. Written as `Unsupported("cidr")` in the datamodel.
+    Unsupported(String),
+}
+
+impl FieldType {
+    /// Whether the field relates to another model.
+    pub fn is_relation(&self) -> bool {
+        self.as_relation().is_some()
+    }
+
+    /// Whether the field has a built-in scalar type, with or without a connector specific type.
+    /// Enums and unsupported types are not scalars in this sense.
+    pub fn is_scalar(&self) -> bool {
+        match self {
+            FieldType::Base(_) | FieldType::ConnectorSpecific { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the field has an enum type.
+    pub fn is_enum(&self) -> bool {
+        match self {
+            FieldType::Enum(_) => true,
+            _ => false,
+        }
+    }
+
+    /// The relation info of a relation field.
+    pub fn as_relation(&self) -> Option<&RelationInfo> {
+        match self {
+            FieldType::Relation(relation_info) => Some(relation_info),
+            _ => None,
+        }
+    }
 }
 
 /// Holds information about an id, or priamry key.
@@ -39,6 +74,17 @@ pub struct IdInfo {
     pub sequence: Option<Sequence>,
 }
 
+/// A directive on a field which has no definition, e.g. a user defined one, or one of a future version.
+/// It has no effect, but is kept so it survives a round trip through the datamodel.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct UnknownDirective {
+    /// Name of the directive, without the leading `@`.
+    pub name: String,
+    /// The arguments of the directive as pairs of name and value, with the values rendered as written.
+    /// The name is empty for unnamed arguments.
+    pub arguments: Vec<(String, String)>,
+}
+
 /// Represents a field in a model.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Field {
@@ -60,6 +106,11 @@ pub struct Field {
     /// Strategy for representing scalar lists. Only valid if
     /// the field arity is list and the type is scalar.
     pub scalar_list_strategy: Option<ScalarListStrategy>,
+    /// The native database type of this field, if it deviates from
+    /// the default type for its scalar type.
+    pub native_type: Option<NativeType>,
+    /// The SQL expression of a `CHECK` constraint on this field's column.
+    pub check: Option<String>,
     /// Comments associated with this field.
     pub documentation: Option<String>,
     /// If set, signals that this field was internally generated
@@ -68,6 +119,8 @@ pub struct Field {
     /// If set, signals that this field is updated_at and will be updated to now()
     /// automatically.
     pub is_updated_at: bool,
+    /// Directives on this field which have no definition, in the order they were written.
+    pub unknown_directives: Vec<UnknownDirective>,
 }
 
 impl WithName for Field {
@@ -100,9 +153,12 @@ impl Field {
             is_unique: false,
             id_info: None,
             scalar_list_strategy: None,
+            native_type: None,
+            check: None,
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            unknown_directives: Vec::new(),
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -116,9 +172,26 @@ impl Field {
             is_unique: false,
             id_info: None,
             scalar_list_strategy: None,
+            native_type: None,
+            check: None,
             documentation: None,
             is_generated: true,
             is_updated_at: false,
+            unknown_directives: Vec::new(),
+        }
+    }
+
+    /// The `onDelete` strategy of the foreign key held by this relation field. Unless it is given explicitly,
+    /// deleting the referenced record cascades to records with a required relation, and sets optional
+    /// relations to null. An explicit `NONE` counts as not given. Returns `None` if this is not a relation field.
+    pub fn on_delete_strategy(&self) -> Option<OnDeleteStrategy> {
+        match &self.field_type {
+            FieldType::Relation(relation_info) => match (relation_info.on_delete, self.arity) {
+                (OnDeleteStrategy::None, FieldArity::Required) => Some(OnDeleteStrategy::Cascade),
+                (OnDeleteStrategy::None, _) => Some(OnDeleteStrategy::SetNull),
+                (explicit, _) => Some(explicit),
+            },
+            _ => None,
         }
     }
 }
@@ -10,6 +10,12 @@ use crate::common::FromStrAndSpan;
 pub enum IdStrategy {
     Auto,
     None,
+    /// The id is taken from a database sequence, declared via `@id(strategy: SEQUENCE)`.
+    /// Only Postgres creates the sequence, other SQL connectors treat it like `Auto`.
+    Sequence,
+    /// The id is generated by the datastore itself, declared via `@default(auto())`.
+    /// SQL connectors treat it like `Auto`.
+    External,
 }
 
 impl FromStrAndSpan for IdStrategy {
@@ -17,6 +23,7 @@ impl FromStrAndSpan for IdStrategy {
         match s {
             "AUTO" => Ok(IdStrategy::Auto),
             "NONE" => Ok(IdStrategy::None),
+            "SEQUENCE" => Ok(IdStrategy::Sequence),
             _ => Err(ValidationError::new_literal_parser_error("id strategy", s, span)),
         }
     }
@@ -27,6 +34,8 @@ impl ToString for IdStrategy {
         match self {
             IdStrategy::Auto => String::from("AUTO"),
             IdStrategy::None => String::from("NONE"),
+            IdStrategy::Sequence => String::from("SEQUENCE"),
+            IdStrategy::External => String::from("EXTERNAL"),
         }
     }
 }
@@ -4,20 +4,24 @@
 // * How do we handle ocnnector specific settings, like indeces? Maybe inheritance, traits and having a Connector<T>?
 mod comment;
 mod datamodel;
+mod diff;
 mod enummodel;
 mod field;
 mod id;
 mod model;
+mod native_type;
 mod relation;
 mod scalar;
 mod traits;
 
 pub use self::datamodel::*;
 pub use comment::*;
+pub use diff::*;
 pub use enummodel::*;
 pub use field::*;
 pub use id::*;
 pub use model::*;
+pub use native_type::*;
 pub use relation::*;
 pub use scalar::*;
 pub use traits::*;
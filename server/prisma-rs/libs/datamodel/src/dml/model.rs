@@ -1,5 +1,10 @@
 use super::field::*;
+use super::relation::*;
 use super::traits::*;
+use super::ScalarType;
+use crate::ast;
+use crate::common::FromStrAndSpan;
+use crate::errors::ValidationError;
 use serde::{Deserialize, Serialize};
 
 /// Represents a model in a prisma datamodel.
@@ -17,6 +22,82 @@ pub struct Model {
     pub is_embedded: bool,
     /// Indicates if this model is generated.
     pub is_generated: bool,
+    /// Indexes declared on this model via `@@unique`, `@@index` or `@@fulltext`.
+    #[serde(default)]
+    pub indices: Vec<IndexDefinition>,
+    /// The fields of the compound id declared via `@@id`. Empty if there is none.
+    #[serde(default)]
+    pub compound_id_fields: Vec<String>,
+    /// The database schema the table of this model lives in, declared via `@@schema`.
+    /// If not set, the schema of the connector is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// The name of the datasource this model is stored in, declared via `@@datasource`.
+    /// If not set, the model is stored in the first datasource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datasource: Option<String>,
+}
+
+/// Represents an index declared on a model.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct IndexDefinition {
+    /// The explicit name of the index, if given.
+    pub name: Option<String>,
+    /// The names of the fields covered by the index.
+    pub fields: Vec<String>,
+    /// The type of the index.
+    pub tpe: IndexType,
+    /// The predicate of a partial index, declared via `where` on `@@index`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    /// The sort order of each field covered by the index, declared via e.g. `@@index([createdAt(sort: Desc)])`.
+    /// Empty if all fields are sorted ascending.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sort_orders: Vec<SortOrder>,
+}
+
+impl IndexDefinition {
+    /// The sort order of the field at the given position of `fields`.
+    pub fn sort_order(&self, position: usize) -> SortOrder {
+        self.sort_orders.get(position).cloned().unwrap_or(SortOrder::Asc)
+    }
+}
+
+/// The order in which an index sorts the values of a field.
+#[derive(Debug, Copy, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl FromStrAndSpan for SortOrder {
+    fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, ValidationError> {
+        match s {
+            "Asc" => Ok(SortOrder::Asc),
+            "Desc" => Ok(SortOrder::Desc),
+            _ => Err(ValidationError::new_literal_parser_error("sort order", s, span)),
+        }
+    }
+}
+
+impl ToString for SortOrder {
+    fn to_string(&self) -> String {
+        match self {
+            SortOrder::Asc => String::from("Asc"),
+            SortOrder::Desc => String::from("Desc"),
+        }
+    }
+}
+
+/// The type of an index.
+#[derive(Debug, Copy, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub enum IndexType {
+    /// A unique index, declared via `@@unique`.
+    Unique,
+    /// A normal index, declared via `@@index`.
+    Normal,
+    /// A full-text search index, declared via `@@fulltext`.
+    Fulltext,
 }
 
 impl Model {
@@ -29,6 +110,10 @@ impl Model {
             database_name: None,
             is_embedded: false,
             is_generated: false,
+            indices: vec![],
+            compound_id_fields: vec![],
+            schema: None,
+            datasource: None,
         }
     }
 
@@ -37,6 +122,24 @@ impl Model {
         self.fields.push(field)
     }
 
+    /// Adds a scalar field with the given name, type and arity to this model.
+    /// Returns the new field, so that it can be configured further.
+    pub fn add_scalar_field(&mut self, name: &str, scalar_type: ScalarType, arity: FieldArity) -> &mut Field {
+        let mut field = Field::new(name, FieldType::Base(scalar_type));
+        field.arity = arity;
+        self.add_field(field);
+        self.fields.last_mut().unwrap()
+    }
+
+    /// Adds a relation field with the given name and arity, pointing to the given model, to this model.
+    /// Returns the new field, so that it can be configured further.
+    pub fn add_relation_field(&mut self, name: &str, to: &str, arity: FieldArity) -> &mut Field {
+        let mut field = Field::new(name, FieldType::Relation(RelationInfo::new(to)));
+        field.arity = arity;
+        self.add_field(field);
+        self.fields.last_mut().unwrap()
+    }
+
     /// Removes a field with the given name from this model.
     pub fn remove_field(&mut self, name: &str) {
         self.fields.retain(|f| f.name != name);
@@ -52,6 +155,27 @@ impl Model {
         self.fields.iter_mut()
     }
 
+    /// Gets an iterator over all scalar fields, i.e. all fields which are not relation fields.
+    pub fn scalar_fields(&self) -> impl std::iter::Iterator<Item = &Field> {
+        self.fields().filter(|f| match f.field_type {
+            FieldType::Relation(_) => false,
+            _ => true,
+        })
+    }
+
+    /// Gets an iterator over all relation fields.
+    pub fn relation_fields(&self) -> impl std::iter::Iterator<Item = &Field> {
+        self.fields().filter(|f| match f.field_type {
+            FieldType::Relation(_) => true,
+            _ => false,
+        })
+    }
+
+    /// The name of the table backing this model: the name set with `@@map`, or the model name.
+    pub fn db_name(&self) -> &str {
+        self.database_name.as_ref().unwrap_or(&self.name)
+    }
+
     /// Finds a field by name.
     pub fn find_field(&self, name: &str) -> Option<&Field> {
         self.fields().find(|f| f.name == *name)
@@ -62,6 +186,18 @@ impl Model {
         self.fields_mut().find(|f| f.name == *name)
     }
 
+    /// Adds an index to this model.
+    pub fn add_index(&mut self, index: IndexDefinition) {
+        self.indices.push(index)
+    }
+
+    /// Finds an index by its explicit name.
+    pub fn find_index(&self, name: &str) -> Option<&IndexDefinition> {
+        self.indices
+            .iter()
+            .find(|i| i.name.as_ref().map(|n| n == name).unwrap_or(false))
+    }
+
     /// Finds the name of all id fields
     pub fn id_field_names(&self) -> impl std::iter::Iterator<Item = &String> {
         self.fields().filter(|x| x.id_info.is_some()).map(|x| &x.name)
@@ -72,6 +208,33 @@ impl Model {
         self.fields().filter(|x| x.id_info.is_some())
     }
 
+    /// Checks if the given fields uniquely identify a record of this model, i.e. whether they are the id
+    /// fields, the fields of a `@@unique` or a single `@unique` field. The order of the fields does not matter.
+    pub fn is_unique_criteria(&self, field_names: &[&String]) -> bool {
+        if field_names.is_empty() {
+            return false;
+        }
+
+        let matches = |candidates: Vec<&String>| {
+            candidates.len() == field_names.len() && candidates.iter().all(|c| field_names.contains(c))
+        };
+
+        let is_single_unique_field = field_names.len() == 1
+            && self
+                .find_field(field_names[0])
+                .map(|f| f.is_unique || f.id_info.is_some())
+                .unwrap_or(false);
+
+        is_single_unique_field
+            || matches(self.id_field_names().collect())
+            || matches(self.compound_id_fields.iter().collect())
+            || self
+                .indices
+                .iter()
+                .filter(|i| i.tpe == IndexType::Unique)
+                .any(|i| matches(i.fields.iter().collect()))
+    }
+
     /// Finds a field with a certain relation guarantee.
     /// exclude_field are necessary to avoid corner cases with self-relations (e.g. we must not recognize a field as its own related field).
     pub fn related_field(&self, to: &str, name: &str, exclude_field: &str) -> Option<&Field> {
@@ -99,18 +262,27 @@ impl Model {
         })
     }
 
-    /// Checks if this is a relation model. A relation model has exactly
-    /// two relations, which are required.
+    /// Checks if this is a relation model, also known as a join model.
+    ///
+    /// A relation model has exactly two relation fields, which are both required.
+    /// Its only scalar fields are the ones making up its optional composite key (`@@id`).
     pub fn is_relation_model(&self) -> bool {
-        let related_fields = self.fields().filter(|f| -> bool {
-            if let FieldType::Relation(_) = f.field_type {
-                f.arity == FieldArity::Required
-            } else {
-                false
-            }
-        });
+        let relation_fields: Vec<&Field> = self
+            .fields()
+            .filter(|f| match f.field_type {
+                FieldType::Relation(_) => true,
+                _ => false,
+            })
+            .collect();
+
+        let has_two_required_relations =
+            relation_fields.len() == 2 && relation_fields.iter().all(|f| f.arity == FieldArity::Required);
+        let scalars_form_composite_key = self
+            .fields()
+            .filter(|f| !relation_fields.contains(f))
+            .all(|f| self.compound_id_fields.contains(&f.name));
 
-        related_fields.count() == 2
+        has_two_required_relations && scalars_form_composite_key
     }
 
     /// Checks if this is a pure relation model.
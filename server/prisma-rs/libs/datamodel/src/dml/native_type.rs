@@ -0,0 +1,37 @@
+use crate::common::PrismaType as ScalarType;
+use serde::{Deserialize, Serialize};
+
+/// A native database type a field is stored as, instead of the default type for its scalar type.
+///
+/// Native types are declared with the directives of the `db` namespace, e.g. `@db.Timestamptz`.
+/// Database families without such a type fall back to the default type.
+#[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
+pub enum NativeType {
+    /// A timezone aware timestamp, `timestamptz` on Postgres.
+    Timestamptz,
+    /// A UUID, `uuid` on Postgres. Other families store UUIDs as strings.
+    Uuid,
+}
+
+impl NativeType {
+    /// All native types that can be declared in a datamodel.
+    pub fn all() -> Vec<NativeType> {
+        vec![NativeType::Timestamptz, NativeType::Uuid]
+    }
+
+    /// The name of the directive declaring this native type.
+    pub fn directive_name(self) -> &'static str {
+        match self {
+            NativeType::Timestamptz => "db.Timestamptz",
+            NativeType::Uuid => "db.Uuid",
+        }
+    }
+
+    /// The scalar type of the fields this native type may be declared on.
+    pub fn scalar_type(self) -> ScalarType {
+        match self {
+            NativeType::Timestamptz => ScalarType::DateTime,
+            NativeType::Uuid => ScalarType::String,
+        }
+    }
+}
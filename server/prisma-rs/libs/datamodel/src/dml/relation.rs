@@ -11,11 +11,23 @@ pub struct RelationInfo {
     pub to: String,
     /// The target field of the relation.
     pub to_fields: Vec<String>,
+    /// The scalar fields on this model which hold the foreign key.
+    /// Empty if the foreign key is implicit.
+    #[serde(default)]
+    pub fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// A strategy indicating what happens when
+    /// the referenced key of a related node is updated.
+    #[serde(default)]
+    pub on_update: OnUpdateStrategy,
+    /// The name of the foreign key constraint, given by the `map` argument.
+    /// If not set, the database chooses a name.
+    #[serde(default)]
+    pub fk_name: Option<String>,
 }
 
 impl RelationInfo {
@@ -25,16 +37,22 @@ impl RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields: Vec::new(),
+            fields: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            on_update: OnUpdateStrategy::None,
+            fk_name: None,
         }
     }
     pub fn new_with_field(to: &str, to_field: &str) -> RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields: vec![String::from(to_field)],
+            fields: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            on_update: OnUpdateStrategy::None,
+            fk_name: None,
         }
     }
 
@@ -42,8 +60,11 @@ impl RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields,
+            fields: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            on_update: OnUpdateStrategy::None,
+            fk_name: None,
         }
     }
 }
@@ -53,6 +74,9 @@ impl RelationInfo {
 #[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
 pub enum OnDeleteStrategy {
     Cascade,
+    SetNull,
+    SetDefault,
+    Restrict,
     None,
 }
 
@@ -60,6 +84,9 @@ impl FromStrAndSpan for OnDeleteStrategy {
     fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, ValidationError> {
         match s {
             "CASCADE" => Ok(OnDeleteStrategy::Cascade),
+            "SET_NULL" => Ok(OnDeleteStrategy::SetNull),
+            "SET_DEFAULT" => Ok(OnDeleteStrategy::SetDefault),
+            "RESTRICT" => Ok(OnDeleteStrategy::Restrict),
             "NONE" => Ok(OnDeleteStrategy::None),
             _ => Err(ValidationError::new_literal_parser_error("onDelete strategy", s, span)),
         }
@@ -70,7 +97,52 @@ impl ToString for OnDeleteStrategy {
     fn to_string(&self) -> String {
         match self {
             OnDeleteStrategy::Cascade => String::from("CASCADE"),
+            OnDeleteStrategy::SetNull => String::from("SET_NULL"),
+            OnDeleteStrategy::SetDefault => String::from("SET_DEFAULT"),
+            OnDeleteStrategy::Restrict => String::from("RESTRICT"),
             OnDeleteStrategy::None => String::from("NONE"),
         }
     }
 }
+
+/// Describes what happens when the referenced
+/// key of related nodes is updated.
+#[derive(Debug, Copy, PartialEq, Clone, Serialize, Deserialize)]
+pub enum OnUpdateStrategy {
+    Cascade,
+    SetNull,
+    SetDefault,
+    Restrict,
+    None,
+}
+
+impl Default for OnUpdateStrategy {
+    fn default() -> Self {
+        OnUpdateStrategy::None
+    }
+}
+
+impl FromStrAndSpan for OnUpdateStrategy {
+    fn from_str_and_span(s: &str, span: ast::Span) -> Result<Self, ValidationError> {
+        match s {
+            "CASCADE" => Ok(OnUpdateStrategy::Cascade),
+            "SET_NULL" => Ok(OnUpdateStrategy::SetNull),
+            "SET_DEFAULT" => Ok(OnUpdateStrategy::SetDefault),
+            "RESTRICT" => Ok(OnUpdateStrategy::Restrict),
+            "NONE" => Ok(OnUpdateStrategy::None),
+            _ => Err(ValidationError::new_literal_parser_error("onUpdate strategy", s, span)),
+        }
+    }
+}
+
+impl ToString for OnUpdateStrategy {
+    fn to_string(&self) -> String {
+        match self {
+            OnUpdateStrategy::Cascade => String::from("CASCADE"),
+            OnUpdateStrategy::SetNull => String::from("SET_NULL"),
+            OnUpdateStrategy::SetDefault => String::from("SET_DEFAULT"),
+            OnUpdateStrategy::Restrict => String::from("RESTRICT"),
+            OnUpdateStrategy::None => String::from("NONE"),
+        }
+    }
+}
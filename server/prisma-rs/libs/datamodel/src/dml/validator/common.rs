@@ -88,6 +88,40 @@ pub fn field_validation_error(
     )
 }
 
+/// Checks if the given value can be the default value of a field of the given type.
+///
+/// Function calls match if their return type matches. Enum fields accept any constant literal,
+/// list values match if all of their elements do.
+pub fn value_matches_type(value: &dml::Value, field_type: &dml::FieldType) -> bool {
+    let scalar_type = match field_type {
+        dml::FieldType::Base(scalar_type) => scalar_type,
+        dml::FieldType::ConnectorSpecific { base_type, .. } => base_type,
+        dml::FieldType::Enum(_) => {
+            return match value {
+                dml::Value::ConstantLiteral(_) => true,
+                dml::Value::List(values) => values.iter().all(|v| value_matches_type(v, field_type)),
+                _ => false,
+            }
+        }
+        dml::FieldType::Relation(_) | dml::FieldType::Unsupported(_) => return false,
+    };
+
+    match (value, scalar_type) {
+        (dml::Value::Int(_), dml::ScalarType::Int)
+        | (dml::Value::BigInt(_), dml::ScalarType::BigInt)
+        | (dml::Value::Float(_), dml::ScalarType::Float)
+        | (dml::Value::Decimal(_), dml::ScalarType::Decimal)
+        | (dml::Value::Boolean(_), dml::ScalarType::Boolean)
+        | (dml::Value::String(_), dml::ScalarType::String)
+        | (dml::Value::String(_), dml::ScalarType::Xml)
+        | (dml::Value::DateTime(_), dml::ScalarType::DateTime) => true,
+        (dml::Value::Expression(_, return_type, _), _) => return_type == scalar_type,
+        (dml::Value::List(values), _) => values.iter().all(|v| value_matches_type(v, field_type)),
+        _ => false,
+    }
+}
+
+/// Errors which concern both sides of a relation are only reported on the side which wins the `tie`.
 pub fn tie(a_model: &dml::Model, a_field: &dml::Field, b_model: &dml::Model, b_field: &dml::Field) -> bool {
     // Model with lower name wins, if name is equal fall back to field.
     a_model.name < b_model.name || (a_model.name == b_model.name && a_field.name < b_field.name)
@@ -0,0 +1,45 @@
+use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
+use crate::{ast, dml};
+
+/// Prismas builtin `@check` directive.
+pub struct CheckDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for CheckDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"check"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), Error> {
+        if obj.field_type.is_relation() {
+            return self.error("Relation fields cannot have a check constraint.", args.span());
+        }
+
+        let expression_arg = args.default_arg("expression")?;
+        let expression = match expression_arg.as_str() {
+            Ok(expression) => expression,
+            Err(err) => return self.parser_error(&err),
+        };
+
+        if expression.trim().is_empty() {
+            return self.error(
+                "The expression of a check constraint must not be empty.",
+                expression_arg.span(),
+            );
+        }
+
+        obj.check = Some(expression);
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        if let Some(check) = &field.check {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", check)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,30 @@
+use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@datasource` directive.
+pub struct DatasourceDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for DatasourceDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"datasource"
+    }
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), Error> {
+        match args.default_arg("name")?.as_str() {
+            Ok(value) => obj.datasource = Some(value),
+            Err(err) => return self.parser_error(&err),
+        };
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        if let Some(datasource) = &model.datasource {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", datasource)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
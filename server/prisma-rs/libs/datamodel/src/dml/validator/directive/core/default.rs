@@ -1,3 +1,4 @@
+use crate::common::value::ValueValidator;
 use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
 use crate::{ast, dml};
 
@@ -9,38 +10,57 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
         &"default"
     }
     fn validate_and_apply(&self, args: &mut Args, field: &mut dml::Field) -> Result<(), Error> {
-        // If we allow list default values, we need to adjust the types below properly for that case.
-        if field.arity == dml::FieldArity::List {
-            return self.error("Cannot set a default value on list field.", args.span());
+        match field.field_type {
+            dml::FieldType::Base(_) | dml::FieldType::Enum(_) => {}
+            _ => return self.error("Cannot set a default value on a relation field.", args.span()),
         }
 
-        if let dml::FieldType::Base(scalar_type) = field.field_type {
-            match args.default_arg("value")?.as_type(scalar_type) {
-                // TODO: Here, a default value directive can override the default value syntax sugar.
-                Ok(value) => field.default_value = Some(value),
-                Err(err) => return self.parser_error(&err),
+        let value = args.default_arg("value")?;
+
+        // TODO: Here, a default value directive can override the default value syntax sugar.
+        if field.arity == dml::FieldArity::List {
+            if !value.is_array() {
+                return self.error(
+                    "The default value of a list field must be an array literal, e.g. `@default([...])`.",
+                    value.span(),
+                );
             }
-        } else if let dml::FieldType::Enum(_) = &field.field_type {
-            match args.default_arg("value")?.as_constant_literal() {
-                // TODO: We should also check if this value is a valid enum value.
-                Ok(value) => field.default_value = Some(dml::Value::ConstantLiteral(value)),
-                Err(err) => return self.parser_error(&err),
+
+            let mut values = Vec::new();
+            for element in value.as_array()? {
+                values.push(self.parse_value(&element, &field.field_type)?);
             }
+            field.default_value = Some(dml::Value::List(values));
         } else {
-            return self.error("Cannot set a default value on a relation field.", args.span());
+            field.default_value = Some(self.parse_value(&value, &field.field_type)?);
         }
 
         Ok(())
     }
 
-    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if let Some(default_value) = &field.default_value {
-            return Ok(Some(ast::Directive::new(
+            return Ok(vec![ast::Directive::new(
                 self.directive_name(),
                 vec![ast::Argument::new("", default_value.into())],
-            )));
+            )]);
         }
 
-        Ok(None)
+        Ok(vec![])
+    }
+}
+
+impl DefaultDirectiveValidator {
+    fn parse_value(&self, value: &ValueValidator, field_type: &dml::FieldType) -> Result<dml::Value, Error> {
+        let parsed = match field_type {
+            dml::FieldType::Base(scalar_type) => value.as_type(*scalar_type),
+            // TODO: We should also check if this value is a valid enum value.
+            dml::FieldType::Enum(_) => value.as_constant_literal().map(dml::Value::ConstantLiteral),
+            _ => unreachable!("Only scalar and enum fields can have a default value."),
+        };
+
+        parsed.map_err(|err| {
+            Error::new_directive_validation_error(&format!("{}", err), self.directive_name(), err.span())
+        })
     }
 }
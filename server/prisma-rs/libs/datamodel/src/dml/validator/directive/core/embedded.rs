@@ -13,11 +13,11 @@ impl DirectiveValidator<dml::Model> for EmbeddedDirectiveValidator {
         Ok(())
     }
 
-    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if model.is_embedded {
-            return Ok(Some(ast::Directive::new(self.directive_name(), vec![])));
+            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
         }
 
-        Ok(None)
+        Ok(vec![])
     }
 }
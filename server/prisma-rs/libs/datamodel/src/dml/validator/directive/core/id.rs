@@ -1,3 +1,4 @@
+use crate::common::value::ValueListValidator;
 use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
 use crate::{ast, dml};
 
@@ -22,21 +23,83 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
             id_info.strategy = arg.parse_literal::<dml::IdStrategy>()?
         }
 
+        if let Ok(arg) = args.arg("sequence") {
+            if id_info.strategy != dml::IdStrategy::Sequence {
+                return self.error("A sequence can only be set for the `SEQUENCE` id strategy.", arg.span());
+            }
+
+            id_info.sequence = Some(dml::Sequence {
+                name: arg.as_str()?,
+                initial_value: 1,
+                allocation_size: 1,
+            });
+        }
+
+        let is_int = obj.field_type == dml::FieldType::Base(dml::ScalarType::Int);
+        if id_info.strategy == dml::IdStrategy::Sequence && !is_int {
+            return self.error(
+                "The `SEQUENCE` id strategy is only supported on fields of type Int.",
+                args.span(),
+            );
+        }
+
         obj.id_info = Some(id_info);
 
         Ok(())
     }
 
-    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if let Some(id_info) = &field.id_info {
             let mut args = Vec::new();
 
-            if id_info.strategy != dml::IdStrategy::Auto {
+            // The external strategy is derived from `@default(auto())`, which is rendered by the default directive.
+            if id_info.strategy == dml::IdStrategy::None || id_info.strategy == dml::IdStrategy::Sequence {
                 args.push(ast::Argument::new_constant("strategy", &id_info.strategy.to_string()));
             }
-            return Ok(Some(ast::Directive::new(self.directive_name(), args)));
+            if let Some(sequence) = &id_info.sequence {
+                args.push(ast::Argument::new_string("sequence", &sequence.name));
+            }
+            return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
-        Ok(None)
+        Ok(vec![])
+    }
+}
+
+/// Prismas builtin `@@id` directive.
+pub struct ModelLevelIdValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelIdValidator {
+    fn directive_name(&self) -> &'static str {
+        &"id"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), Error> {
+        let fields = args.default_arg("fields")?.as_array()?.to_literal_vec()?;
+
+        if fields.is_empty() {
+            return self.error("The `@@id` directive requires at least one field.", args.span());
+        }
+
+        obj.compound_id_fields = fields;
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        if model.compound_id_fields.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let fields: Vec<ast::Value> = model
+            .compound_id_fields
+            .iter()
+            .map(|f| ast::Value::ConstantValue(f.clone(), ast::Span::empty()))
+            .collect();
+
+        Ok(vec![ast::Directive::new(
+            self.directive_name(),
+            vec![ast::Argument::new_array("", fields)],
+        )])
     }
 }
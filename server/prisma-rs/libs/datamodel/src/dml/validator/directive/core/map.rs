@@ -9,8 +9,16 @@ impl<T: dml::WithDatabaseName> DirectiveValidator<T> for MapDirectiveValidator {
         &"map"
     }
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), Error> {
-        match args.default_arg("name")?.as_str() {
-            Ok(value) => obj.set_database_name(&Some(value)),
+        let name_arg = args.default_arg("name")?;
+
+        match name_arg.as_str() {
+            Ok(value) => {
+                if let Some(message) = invalid_database_name_message(&value) {
+                    return Err(Error::new_directive_validation_error(&message, "map", name_arg.span()));
+                }
+
+                obj.set_database_name(&Some(value))
+            }
             // self.parser_error would be better here, but we cannot call it due to rust limitations.
             Err(err) => {
                 return Err(Error::new_directive_validation_error(
@@ -24,14 +32,31 @@ impl<T: dml::WithDatabaseName> DirectiveValidator<T> for MapDirectiveValidator {
         Ok(())
     }
 
-    fn serialize(&self, obj: &T, _atamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, obj: &T, _atamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if let Some(db_name) = obj.database_name() {
-            return Ok(Some(ast::Directive::new(
+            return Ok(vec![ast::Directive::new(
                 DirectiveValidator::<T>::directive_name(self),
                 vec![ast::Argument::new_string("", db_name)],
-            )));
+            )]);
         }
 
-        Ok(None)
+        Ok(vec![])
+    }
+}
+
+/// Mapped names are quoted in SQL, but empty names are rejected by every database, and whitespace or quotes
+/// are almost certainly typos that would need escaping.
+fn invalid_database_name_message(name: &str) -> Option<String> {
+    let is_invalid_char = |c: char| c.is_whitespace() || c == '"' || c == '`' || c == '\0';
+
+    if name.is_empty() {
+        Some("The database name given in `map` cannot be empty.".to_string())
+    } else if name.chars().any(is_invalid_char) {
+        Some(format!(
+            "The database name `{}` is not a valid identifier. It must not contain whitespace or quotes.",
+            name
+        ))
+    } else {
+        None
     }
 }
@@ -2,14 +2,19 @@ use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 use std::collections::BTreeMap;
 
+mod check;
+mod datasource;
 mod default;
 mod embedded;
 mod id;
 mod map;
+mod native_type;
 mod relation;
 mod scalarlist;
+mod schema;
 mod sequence;
 mod unique;
+mod unique_and_index;
 mod updated_at;
 
 /// Returns a directive list validator containing all builtin field directives.
@@ -26,6 +31,11 @@ pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(check::CheckDirectiveValidator {}));
+
+    for native_type in dml::NativeType::all() {
+        validator.add(Box::new(native_type::NativeTypeDirectiveValidator { native_type }));
+    }
 
     validator
 }
@@ -38,6 +48,12 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
 
     validator.add(Box::new(map::MapDirectiveValidator {}));
     validator.add(Box::new(embedded::EmbeddedDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
+    validator.add(Box::new(datasource::DatasourceDirectiveValidator {}));
+    validator.add(Box::new(id::ModelLevelIdValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelUniqueValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelIndexValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextValidator {}));
 
     validator
 }
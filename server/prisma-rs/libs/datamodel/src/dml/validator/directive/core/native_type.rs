@@ -0,0 +1,40 @@
+use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
+use crate::{ast, dml};
+
+/// Prismas builtin `@db.*` directives, declaring the native database type of a field.
+pub struct NativeTypeDirectiveValidator {
+    pub native_type: dml::NativeType,
+}
+
+impl DirectiveValidator<dml::Field> for NativeTypeDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        self.native_type.directive_name()
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), Error> {
+        let scalar_type = self.native_type.scalar_type();
+
+        if obj.field_type != dml::FieldType::Base(scalar_type) {
+            return self.error(
+                &format!(
+                    "Fields that are marked with @{} must be of type {}.",
+                    self.directive_name(),
+                    scalar_type.as_str()
+                ),
+                args.span(),
+            );
+        }
+
+        obj.native_type = Some(self.native_type);
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        if field.native_type == Some(self.native_type) {
+            Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
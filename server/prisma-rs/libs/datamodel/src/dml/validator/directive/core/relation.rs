@@ -26,17 +26,35 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 relation_info.to_fields = related_fields.as_array()?.to_literal_vec()?;
             }
 
+            if let Ok(base_fields) = args.arg("fields") {
+                relation_info.fields = base_fields.as_array()?.to_literal_vec()?;
+            }
+
             if let Ok(on_delete) = args.arg("onDelete") {
                 relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
             }
 
+            if let Ok(on_update) = args.arg("onUpdate") {
+                relation_info.on_update = on_update.parse_literal::<dml::OnUpdateStrategy>()?;
+            }
+
+            if let Ok(map_arg) = args.arg("map") {
+                let fk_name = map_arg.as_str()?;
+
+                if fk_name.is_empty() {
+                    return self.error("The name of a foreign key cannot be empty.", map_arg.span());
+                }
+
+                relation_info.fk_name = Some(fk_name);
+            }
+
             Ok(())
         } else {
             self.error("Invalid field type, not a relation.", args.span())
         }
     }
 
-    fn serialize(&self, field: &dml::Field, datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, field: &dml::Field, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if let dml::FieldType::Relation(relation_info) = &field.field_type {
             let mut args = Vec::new();
 
@@ -55,16 +73,29 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_string("", &relation_info.name));
             }
 
+            if !relation_info.fields.is_empty() {
+                let mut base_fields: Vec<ast::Value> = Vec::new();
+
+                for base_field in &relation_info.fields {
+                    base_fields.push(ast::Value::ConstantValue(base_field.clone(), ast::Span::empty()));
+                }
+
+                args.push(ast::Argument::new_array("fields", base_fields));
+            }
+
             // We only add the references arg,
             // if we have references
             // and we do only reference the IDs, which is the default case.
+            // If the foreign key fields are given explicitly, the references are always rendered.
 
             let mut relation_fields: Vec<&String> = relation_info.to_fields.iter().collect();
 
             relation_fields.sort();
             all_related_ids.sort();
 
-            if !relation_info.to_fields.is_empty() && relation_fields != all_related_ids {
+            if !relation_info.to_fields.is_empty()
+                && (relation_fields != all_related_ids || !relation_info.fields.is_empty())
+            {
                 let mut related_fields: Vec<ast::Value> = Vec::new();
 
                 for related_field in &relation_info.to_fields {
@@ -81,11 +112,22 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 ));
             }
 
+            if relation_info.on_update != dml::OnUpdateStrategy::None {
+                args.push(ast::Argument::new_constant(
+                    "onUpdate",
+                    &relation_info.on_update.to_string(),
+                ));
+            }
+
+            if let Some(fk_name) = &relation_info.fk_name {
+                args.push(ast::Argument::new_string("map", fk_name));
+            }
+
             if !args.is_empty() {
-                return Ok(Some(ast::Directive::new(self.directive_name(), args)))
+                return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
             }
         }
 
-        Ok(None)
+        Ok(vec![])
     }
 }
@@ -15,11 +15,11 @@ impl DirectiveValidator<dml::Field> for UniqueDirectiveValidator {
         Ok(())
     }
 
-    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if field.is_unique {
-            return Ok(Some(ast::Directive::new(self.directive_name(), vec![])));
+            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
         }
 
-        Ok(None)
+        Ok(vec![])
     }
 }
@@ -0,0 +1,217 @@
+use crate::common::value::{MaybeExpression, ValueListValidator, ValueValidator};
+use crate::dml::validator::directive::{Args, DirectiveValidator, Error};
+use crate::errors::ValidationError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@@unique` directive.
+pub struct ModelLevelUniqueValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelUniqueValidator {
+    fn directive_name(&self) -> &'static str {
+        &"unique"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), Error> {
+        let index = validate_index_args(args, dml::IndexType::Unique)?;
+        obj.add_index(index);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        Ok(serialize_indices(self.directive_name(), model, dml::IndexType::Unique))
+    }
+}
+
+/// Prismas builtin `@@index` directive.
+pub struct ModelLevelIndexValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelIndexValidator {
+    fn directive_name(&self) -> &'static str {
+        &"index"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), Error> {
+        let index = validate_index_args(args, dml::IndexType::Normal)?;
+        obj.add_index(index);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        Ok(serialize_indices(self.directive_name(), model, dml::IndexType::Normal))
+    }
+}
+
+/// Prismas builtin `@@fulltext` directive.
+pub struct ModelLevelFulltextValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelFulltextValidator {
+    fn directive_name(&self) -> &'static str {
+        &"fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), Error> {
+        let index = validate_index_args(args, dml::IndexType::Fulltext)?;
+        obj.add_index(index);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
+        Ok(serialize_indices(
+            self.directive_name(),
+            model,
+            dml::IndexType::Fulltext,
+        ))
+    }
+}
+
+fn validate_index_args(args: &mut Args, tpe: dml::IndexType) -> Result<dml::IndexDefinition, Error> {
+    let fields_arg = args.default_arg("fields")?;
+    let (fields, sort_orders) = match fields_arg.value() {
+        MaybeExpression::Value(_, ast::Value::Array(values, _)) => validate_index_fields(values, tpe)?,
+        _ => (fields_arg.as_array()?.to_literal_vec()?, Vec::new()),
+    };
+
+    let name = match args.arg("name") {
+        Ok(name_arg) => Some(name_arg.as_str()?),
+        Err(_) => None,
+    };
+
+    // Only `@@index` accepts a predicate. On other indexes, `where` is reported as an unused argument.
+    let predicate = match tpe {
+        dml::IndexType::Normal => match args.arg("where") {
+            Ok(where_arg) => {
+                let predicate = where_arg.as_str()?;
+
+                if predicate.trim().is_empty() {
+                    return Err(ValidationError::new_directive_validation_error(
+                        "The `where` predicate of a partial index must not be empty.",
+                        "index",
+                        where_arg.span(),
+                    ));
+                }
+
+                Some(predicate)
+            }
+            Err(_) => None,
+        },
+        dml::IndexType::Unique | dml::IndexType::Fulltext => None,
+    };
+
+    Ok(dml::IndexDefinition {
+        name,
+        fields,
+        tpe,
+        predicate,
+        sort_orders,
+    })
+}
+
+/// Reads the fields of an index. Fields of an `@@index` may declare their sort order, e.g. `createdAt(sort: Desc)`.
+/// The sort orders are only kept if any field is sorted descending.
+fn validate_index_fields(
+    values: &[ast::Value],
+    tpe: dml::IndexType,
+) -> Result<(Vec<String>, Vec<dml::SortOrder>), Error> {
+    let mut fields = Vec::new();
+    let mut sort_orders = Vec::new();
+
+    for value in values {
+        match value {
+            ast::Value::Function(field_name, arguments, span) => {
+                let directive_name = match tpe {
+                    dml::IndexType::Normal => None,
+                    dml::IndexType::Unique => Some("unique"),
+                    dml::IndexType::Fulltext => Some("fulltext"),
+                };
+
+                if let Some(directive_name) = directive_name {
+                    return Err(ValidationError::new_directive_validation_error(
+                        "A sort order can only be declared on the fields of an `@@index`.",
+                        directive_name,
+                        *span,
+                    ));
+                }
+
+                let mut sort_order = None;
+
+                for argument in arguments {
+                    if argument.name.name != "sort" {
+                        return Err(ValidationError::new_unused_argument_error(
+                            &argument.name.name,
+                            argument.span,
+                        ));
+                    }
+
+                    sort_order = Some(ValueValidator::new(&argument.value)?.parse_literal::<dml::SortOrder>()?);
+                }
+
+                match sort_order {
+                    Some(sort_order) => sort_orders.push(sort_order),
+                    None => return Err(ValidationError::new_argument_not_found_error("sort", *span)),
+                }
+
+                fields.push(field_name.clone());
+            }
+            _ => {
+                fields.push(ValueValidator::new(value)?.as_constant_literal()?);
+                sort_orders.push(dml::SortOrder::Asc);
+            }
+        }
+    }
+
+    if sort_orders.iter().all(|sort_order| *sort_order == dml::SortOrder::Asc) {
+        sort_orders.clear();
+    }
+
+    Ok((fields, sort_orders))
+}
+
+fn serialize_indices(directive_name: &str, model: &dml::Model, tpe: dml::IndexType) -> Vec<ast::Directive> {
+    model
+        .indices
+        .iter()
+        .filter(|index| index.tpe == tpe)
+        .map(|index| {
+            let fields: Vec<ast::Value> = index
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(position, f)| match index.sort_order(position) {
+                    dml::SortOrder::Asc => ast::Value::ConstantValue(f.clone(), ast::Span::empty()),
+                    sort_order => ast::Value::Function(
+                        f.clone(),
+                        vec![ast::Argument::new_constant("sort", &sort_order.to_string())],
+                        ast::Span::empty(),
+                    ),
+                })
+                .collect();
+
+            let mut args = vec![ast::Argument::new_array("", fields)];
+
+            if let Some(name) = &index.name {
+                args.push(ast::Argument::new_string("name", name));
+            }
+
+            if let Some(predicate) = &index.predicate {
+                args.push(ast::Argument::new_string("where", predicate));
+            }
+
+            ast::Directive::new(directive_name, args)
+        })
+        .collect()
+}
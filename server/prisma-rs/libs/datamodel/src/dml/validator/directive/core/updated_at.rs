@@ -17,20 +17,16 @@ impl DirectiveValidator<dml::Field> for UpdatedAtDirectiveValidator {
             );
         }
 
-        if obj.arity == dml::FieldArity::List {
-            return self.error("Fields that are marked with @updatedAt can not be lists.", args.span());
-        }
-
         obj.is_updated_at = true;
 
         Ok(())
     }
 
-    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         if field.is_updated_at {
-            Ok(Some(ast::Directive::new(self.directive_name(), Vec::new())))
+            Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())])
         } else {
-            Ok(None)
+            Ok(vec![])
         }
     }
 }
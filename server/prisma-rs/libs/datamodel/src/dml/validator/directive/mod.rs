@@ -28,8 +28,13 @@ pub trait DirectiveValidator<T> {
     /// to the given object.
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), Error>;
 
+    /// Indicates if this directive may be declared several times on the same object.
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        false
+    }
+
     /// Serilizes the given directive's arguments for rendering.
-    fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error>;
+    fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error>;
 
     /// Shorthand to construct an directive validation error.
     fn error(&self, msg: &str, span: ast::Span) -> Result<(), Error> {
@@ -80,7 +85,10 @@ impl<T> DirectiveValidator<T> for DirectiveScope<T> {
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), Error> {
         self.inner.validate_and_apply(args, obj)
     }
-    fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Option<ast::Directive>, Error> {
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        self.inner.is_duplicate_definition_allowed()
+    }
+    fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, Error> {
         self.inner.serialize(obj, datamodel)
     }
 }
@@ -134,9 +142,39 @@ impl<T: 'static> DirectiveListValidator<T> {
     /// For each directive in the given object, picks the correct
     /// directive definition and uses it to validate and apply the directive.
     pub fn validate_and_apply(&self, ast: &dyn ast::WithDirectives, t: &mut T) -> Result<(), ErrorCollection> {
+        self.validate_and_apply_impl(ast, t, false).map(|_| ())
+    }
+
+    /// Like `validate_and_apply`, but instead of failing on namespaced directives of an unknown namespace,
+    /// e.g. `@myTool.label`, returns them so they can be kept as they were written.
+    pub fn validate_and_apply_retaining_unknown(
+        &self,
+        ast: &dyn ast::WithDirectives,
+        t: &mut T,
+    ) -> Result<Vec<ast::Directive>, ErrorCollection> {
+        self.validate_and_apply_impl(ast, t, true)
+    }
+
+    fn validate_and_apply_impl(
+        &self,
+        ast: &dyn ast::WithDirectives,
+        t: &mut T,
+        retain_unknown: bool,
+    ) -> Result<Vec<ast::Directive>, ErrorCollection> {
         let mut errors = ErrorCollection::new();
+        let mut unknown_directives = Vec::new();
 
         for directive in ast.directives() {
+            let duplicates_allowed = self
+                .known_directives
+                .get(&directive.name.name)
+                .map(|validator| validator.is_duplicate_definition_allowed())
+                .unwrap_or(false);
+
+            if duplicates_allowed {
+                continue;
+            }
+
             for other_directive in ast.directives() {
                 if directive as *const ast::Directive != other_directive as *const ast::Directive
                     && directive.name.name == other_directive.name.name
@@ -170,6 +208,15 @@ impl<T: 'static> DirectiveListValidator<T> {
                                 span,
                             ))
                         }
+                        // Arguments of the wrong type are reported on the value, naming the directive they belong to.
+                        Err(err @ ValidationError::TypeMismatchError { .. })
+                        | Err(err @ ValidationError::ValueParserError { .. }) => {
+                            errors.push(ValidationError::new_directive_validation_error(
+                                &format!("{}", err),
+                                &directive.name.name,
+                                err.span(),
+                            ))
+                        }
                         Err(err) => {
                             errors.push(err);
                         }
@@ -181,6 +228,9 @@ impl<T: 'static> DirectiveListValidator<T> {
                         }
                     }
                 }
+                None if retain_unknown && self.is_foreign(&directive.name.name) => {
+                    unknown_directives.push(directive.clone())
+                }
                 None => errors.push(ValidationError::new_directive_not_known_error(
                     &directive.name.name,
                     directive.name.span,
@@ -190,7 +240,19 @@ impl<T: 'static> DirectiveListValidator<T> {
 
         errors.ok()?;
 
-        Ok(())
+        Ok(unknown_directives)
+    }
+
+    /// Namespaced directives of a namespace without known directives belong to other tools. Directives without
+    /// namespace, or of a namespace with known directives like `db`, are most likely misspelled.
+    fn is_foreign(&self, directive_name: &str) -> bool {
+        match directive_name.find('.') {
+            Some(position) => {
+                let namespace = &directive_name[..=position];
+                !self.known_directives.keys().any(|name| name.starts_with(namespace))
+            }
+            None => false,
+        }
     }
 
     pub fn serialize(&self, t: &T, datamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, ErrorCollection> {
@@ -199,8 +261,7 @@ impl<T: 'static> DirectiveListValidator<T> {
 
         for directive in self.known_directives.values() {
             match directive.serialize(t, datamodel) {
-                Ok(Some(directive)) => directives.push(directive),
-                Ok(None) => {}
+                Ok(mut serialized) => directives.append(&mut serialized),
                 Err(err) => errors.push(err),
             };
         }
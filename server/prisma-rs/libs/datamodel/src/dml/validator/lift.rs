@@ -1,6 +1,6 @@
 use super::{common::*, DirectiveBox};
 use crate::{
-    ast,
+    ast::{self, renderer::Renderer},
     common::value::ValueValidator,
     common::{FromStrAndSpan, PrismaType},
     configuration, dml,
@@ -143,8 +143,24 @@ impl LiftAstToDml {
         // We merge arttributes so we can fail on duplicates.
         let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
 
-        if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
-            errors.append(&mut err);
+        match self
+            .directives
+            .field
+            .validate_and_apply_retaining_unknown(&attributes, &mut field)
+        {
+            Ok(unknown_directives) => {
+                field.unknown_directives = unknown_directives
+                    .iter()
+                    .map(|directive| self.lift_unknown_directive(directive))
+                    .collect()
+            }
+            Err(mut err) => errors.append(&mut err),
+        }
+
+        if let (Some(id_info), Some(dml::Value::Expression(name, _, _))) = (&mut field.id_info, &field.default_value) {
+            if name == "auto" {
+                id_info.strategy = dml::IdStrategy::External;
+            }
         }
 
         if errors.has_errors() {
@@ -154,6 +170,18 @@ impl LiftAstToDml {
         }
     }
 
+    /// Internal: Lift a directive without definition, keeping its arguments as written.
+    fn lift_unknown_directive(&self, directive: &ast::Directive) -> dml::UnknownDirective {
+        dml::UnknownDirective {
+            name: directive.name.name.clone(),
+            arguments: directive
+                .arguments
+                .iter()
+                .map(|arg| (arg.name.name.clone(), Renderer::render_value_to_string(&arg.value)))
+                .collect(),
+        }
+    }
+
     /// Internal: Lift a field's arity.
     fn lift_field_arity(&self, ast_field: &ast::FieldArity) -> dml::FieldArity {
         match ast_field {
@@ -173,7 +201,9 @@ impl LiftAstToDml {
     ) -> Result<(dml::FieldType, Vec<ast::Directive>), ValidationError> {
         let type_name = &ast_field.field_type.name;
 
-        if let Ok(scalar_type) = PrismaType::from_str_and_span(type_name, ast_field.field_type.span) {
+        if let Some(database_type) = unsupported_database_type(type_name) {
+            Ok((dml::FieldType::Unsupported(database_type.to_string()), vec![]))
+        } else if let Ok(scalar_type) = PrismaType::from_str_and_span(type_name, ast_field.field_type.span) {
             Ok((dml::FieldType::Base(scalar_type), vec![]))
         } else if ast_schema.find_model(type_name).is_some() {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
@@ -225,3 +255,15 @@ impl LiftAstToDml {
         }
     }
 }
+
+/// The parser keeps unsupported types as `Unsupported("...")`, this returns the raw database type.
+fn unsupported_database_type(type_name: &str) -> Option<&str> {
+    let prefix = "Unsupported(\"";
+    let suffix = "\")";
+
+    if type_name.starts_with(prefix) && type_name.ends_with(suffix) && type_name.len() >= prefix.len() + suffix.len() {
+        Some(&type_name[prefix.len()..type_name.len() - suffix.len()])
+    } else {
+        None
+    }
+}
@@ -0,0 +1,165 @@
+use super::common::*;
+use crate::{ast, dml, errors::DatamodelWarning};
+
+/// Checks a valid datamodel for style issues.
+///
+/// Linting never fails, it only collects warnings. It is not part of the validation pipeline.
+#[derive(Default)]
+pub struct Linter {}
+
+impl Linter {
+    /// Creates a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lints a datamodel. The AST is needed to point the warnings to their source.
+    pub fn lint(&self, ast_schema: &ast::Datamodel, schema: &dml::Datamodel) -> Vec<DatamodelWarning> {
+        let mut warnings = Vec::new();
+
+        for model in schema.models().filter(|m| !m.is_generated) {
+            let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+            if !is_pascal_case(&model.name) {
+                warnings.push(DatamodelWarning::new(
+                    &format!("The model name `{}` should be written in PascalCase.", model.name),
+                    ast_model.name.span,
+                ));
+            }
+
+            if model.is_embedded && !self.is_referenced(schema, model) {
+                warnings.push(DatamodelWarning::new(
+                    &format!(
+                        "The embedded model `{}` is not reachable from any model which is not embedded, so it is never stored.",
+                        model.name
+                    ),
+                    ast_model.name.span,
+                ));
+            }
+
+            for field in model.fields().filter(|f| !f.is_generated) {
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+
+                if !is_camel_case(&field.name) {
+                    warnings.push(DatamodelWarning::new(
+                        &format!(
+                            "The field name `{}` on model `{}` should be written in camelCase.",
+                            field.name, model.name
+                        ),
+                        ast_field.name.span,
+                    ));
+                }
+
+                if self.is_ambiguous_prone_relation(ast_field, model, field) {
+                    warnings.push(DatamodelWarning::new(
+                        &format!(
+                            "The relation field `{}` on model `{}` has no explicit relation name, but there are other relation fields between the same models. Name the relation with `@relation(name: \"...\")` to keep it unambiguous.",
+                            field.name, model.name
+                        ),
+                        ast_field.name.span,
+                    ));
+                }
+
+                for directive in &field.unknown_directives {
+                    let ast_directive = ast_field
+                        .directives
+                        .iter()
+                        .find(|d| d.name.name == directive.name)
+                        .expect(STATE_ERROR);
+
+                    warnings.push(DatamodelWarning::new(
+                        &format!(
+                            "The directive `@{}` on field `{}` of model `{}` is not known. It is kept, but has no effect.",
+                            directive.name, field.name, model.name
+                        ),
+                        ast_directive.name.span,
+                    ));
+                }
+            }
+        }
+
+        for enum_ in schema.enums() {
+            let ast_enum = ast_schema.find_enum(&enum_.name).expect(STATE_ERROR);
+
+            for value in &ast_enum.values {
+                if !is_screaming_case(&value.name) {
+                    warnings.push(DatamodelWarning::new(
+                        &format!(
+                            "The value `{}` of enum `{}` should be written in SCREAMING_CASE.",
+                            value.name, enum_.name
+                        ),
+                        value.span,
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Embedded models are stored within the models referencing them, which may be embedded themselves.
+    /// So an embedded model is only stored if a model which is not embedded references it, directly or through
+    /// other embedded models. Generated back relation fields do not store anything and are not followed.
+    fn is_referenced(&self, schema: &dml::Datamodel, model: &dml::Model) -> bool {
+        let mut reachable: Vec<&str> = schema
+            .models()
+            .filter(|m| !m.is_embedded)
+            .map(|m| m.name.as_str())
+            .collect();
+        let mut next = 0;
+
+        while next < reachable.len() {
+            let current = schema.find_model(reachable[next]).expect(STATE_ERROR);
+
+            for field in current.fields().filter(|f| !f.is_generated) {
+                if let dml::FieldType::Relation(rel) = &field.field_type {
+                    if !reachable.contains(&rel.to.as_str()) {
+                        reachable.push(&rel.to);
+                    }
+                }
+            }
+
+            next += 1;
+        }
+
+        reachable.contains(&model.name.as_str())
+    }
+
+    /// A relation without explicit name is prone to become ambiguous if there is
+    /// another relation field on the model pointing to the same model, e.g. for self relations.
+    fn is_ambiguous_prone_relation(&self, ast_field: &ast::Field, model: &dml::Model, field: &dml::Field) -> bool {
+        let rel = match &field.field_type {
+            dml::FieldType::Relation(rel) => rel,
+            _ => return false,
+        };
+
+        let has_explicit_name = ast_field
+            .directives
+            .iter()
+            .filter(|directive| directive.name.name == "relation")
+            .flat_map(|directive| directive.arguments.iter())
+            .any(|argument| argument.name.name == "name" || argument.name.name == "");
+
+        !has_explicit_name
+            && model.fields().any(|other| {
+                other.name != field.name
+                    && match &other.field_type {
+                        dml::FieldType::Relation(other_rel) => other_rel.to == rel.to,
+                        _ => false,
+                    }
+            })
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.chars().next().map_or(false, char::is_uppercase) && !name.contains('_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.chars().next().map_or(false, char::is_lowercase) && !name.contains('_')
+}
+
+fn is_screaming_case(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
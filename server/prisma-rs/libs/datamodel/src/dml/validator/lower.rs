@@ -107,13 +107,34 @@ impl LowerDmlToAst {
             name: ast::Identifier::new(&field.name),
             arity: self.lower_field_arity(field.arity),
             default_value: field.default_value.clone().map(|v| v.into()),
-            directives: self.directives.field.serialize(field, datamodel)?,
+            directives: self.lower_field_directives(field, datamodel)?,
             field_type: self.lower_type(&field.field_type, field, model, &datamodel),
             documentation: field.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
         })
     }
 
+    /// Internal: Lowers a field's directives, followed by the directives without definition it was parsed with.
+    fn lower_field_directives(
+        &self,
+        field: &dml::Field,
+        datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, ErrorCollection> {
+        let mut directives = self.directives.field.serialize(field, datamodel)?;
+
+        for directive in &field.unknown_directives {
+            let arguments = directive
+                .arguments
+                .iter()
+                .map(|(name, value)| ast::Argument::new_constant(name, value))
+                .collect();
+
+            directives.push(ast::Directive::new(&directive.name, arguments));
+        }
+
+        Ok(directives)
+    }
+
     /// Internal: Lowers a field's arity.
     fn lower_field_arity(&self, field_arity: dml::FieldArity) -> ast::FieldArity {
         match field_arity {
@@ -132,8 +153,9 @@ impl LowerDmlToAst {
         datamodel: &dml::Datamodel,
     ) -> ast::Identifier {
         match field_type {
-            dml::FieldType::Base(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::Base(tpe) => ast::Identifier::new(tpe.as_str()),
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&format!("Unsupported(\"{}\")", tpe)),
             dml::FieldType::Relation(rel) => {
                 let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
 
@@ -1,5 +1,6 @@
 mod directive_box;
 mod lift;
+mod lint;
 mod lower;
 mod precheck;
 mod standardise;
@@ -12,6 +13,7 @@ pub mod directive;
 use directive_box::*;
 
 pub use lift::*;
+pub use lint::*;
 pub use lower::*;
 pub use precheck::*;
 pub use standardise::*;
@@ -69,6 +69,14 @@ impl Precheck {
     }
 
     fn precheck_enum(enum_type: &ast::Enum, errors: &mut ErrorCollection) {
+        if enum_type.values.is_empty() {
+            errors.push(ValidationError::new_enum_validation_error(
+                "An enum must have at least one value",
+                &enum_type.name.name,
+                enum_type.span,
+            ));
+        }
+
         let mut checker = DuplicateChecker::new();
         for value in &enum_type.values {
             checker.check_if_duplicate_exists(value, |_| {
@@ -155,6 +155,11 @@ impl Standardiser {
             is_embedded: false,
             fields: vec![a_related_field, b_related_field],
             is_generated: true,
+            indices: vec![],
+            compound_id_fields: vec![],
+            schema: None,
+            // Relations cannot span datasources, so both models are stored in the same one.
+            datasource: a_model.datasource.clone(),
         }
     }
 
@@ -164,8 +169,11 @@ impl Standardiser {
             dml::FieldType::Relation(dml::RelationInfo {
                 to: model.name.clone(),
                 to_fields: model.id_field_names().cloned().collect(),
+                fields: vec![],
                 name: String::from(relation_name), // Will be corrected in later step
                 on_delete: dml::OnDeleteStrategy::None,
+                on_update: dml::OnUpdateStrategy::None,
+                fk_name: None,
             }),
         )
     }
@@ -244,11 +252,17 @@ impl Standardiser {
                     .expect(STATE_ERROR);
 
                 errors.push(field_validation_error(
-                    "Automatic opposite related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
+                    &format!(
+                        "Automatic opposite related field generation would cause a naming conflict with the field `{}` of model `{}`. Please add an explicit opposite relation field or rename the conflicting field.",
+                        name, model.name
+                    ),
                     &source_model,
                     &source_field,
                     &ast_schema,
                 ));
+
+                // The generated field must not shadow the existing one.
+                continue;
             }
 
             let model = schema.find_model_mut(&forward.to).expect(STATE_ERROR);
@@ -296,8 +310,11 @@ impl Standardiser {
                             dml::RelationInfo {
                                 to: model.name.clone(),
                                 to_fields: vec![],
+                                fields: vec![],
                                 name: rel.name.clone(),
                                 on_delete: rel.on_delete,
+                                on_update: rel.on_update,
+                                fk_name: None,
                             },
                         ));
                     } else {
@@ -8,7 +8,12 @@ use crate::{
 ///
 /// When validating, we check if the datamodel is valid, and generate errors otherwise.
 #[derive(Default)]
-pub struct Validator {}
+pub struct Validator {
+    /// The connector types of the sources the datamodel is validated against, with their capabilities.
+    source_capabilities: Vec<(String, configuration::ConnectorCapabilities)>,
+    /// The names of the sources the datamodel is validated against, in the order of their declaration.
+    source_names: Vec<String>,
+}
 
 /// State error message. Seeing this error means something went really wrong internally. It's the datamodel equivalent of a bluescreen.
 const STATE_ERROR: &str = "Failed lookup of model, field or optional property during internal processing. This means that the internal representation was mutated incorrectly.";
@@ -19,12 +24,38 @@ impl Validator {
         Self::default()
     }
 
-    /// Creates a new instance, with all builtin directives and
-    /// the directives defined by the given sources registered.
-    ///
-    /// The directives defined by the given sources will be namespaced.
-    pub fn with_sources(_sources: &[Box<dyn configuration::Source>]) -> Validator {
-        Self::default()
+    /// Creates a new instance, which checks the datamodel against the capabilities of the given sources,
+    /// and the datasources of the models against the names of the given sources.
+    pub fn with_sources(sources: &[Box<dyn configuration::Source>]) -> Validator {
+        Validator {
+            source_capabilities: sources
+                .iter()
+                .map(|s| (String::from(s.connector_type()), s.capabilities()))
+                .collect(),
+            source_names: sources.iter().map(|s| s.name().clone()).collect(),
+        }
+    }
+
+    /// Creates a new instance, which checks the datamodel against the given capabilities of a connector,
+    /// without any datasource being declared. Errors about unsupported features name the connector type.
+    pub fn with_capabilities(connector_type: &str, capabilities: configuration::ConnectorCapabilities) -> Validator {
+        Validator {
+            source_capabilities: vec![(String::from(connector_type), capabilities)],
+            source_names: Vec::new(),
+        }
+    }
+
+    /// The name of the source the given model is stored in, see `Model::datasource`.
+    fn datasource_of<'a>(&'a self, model: &'a dml::Model) -> Option<&'a String> {
+        model.datasource.as_ref().or_else(|| self.source_names.first())
+    }
+
+    /// Finds the connector type of the first source that lacks a capability.
+    fn source_lacking(&self, has_capability: fn(&configuration::ConnectorCapabilities) -> bool) -> Option<&String> {
+        self.source_capabilities
+            .iter()
+            .find(|(_, capabilities)| !has_capability(capabilities))
+            .map(|(source_type, _)| source_type)
     }
 
     pub fn validate(&self, ast_schema: &ast::Datamodel, schema: &mut dml::Datamodel) -> Result<(), ErrorCollection> {
@@ -32,65 +63,247 @@ impl Validator {
 
         // Model level validations.
         for model in schema.models() {
-            if let Err(err) = self.validate_model_has_id(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
-            {
-                errors.push(err);
-            }
-            if let Err(err) = self.validate_id_fields_valid(ast_schema, model) {
-                errors.push(err);
-            }
-            if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
-                errors.push(err);
-            }
-            if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
-                errors.push(err);
-            }
+            self.validate_model_into(ast_schema, schema, model, &mut errors);
+        }
+
+        if errors.has_errors() {
+            errors.sort_by_span();
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Runs the model level validations for a single model of an already lifted datamodel, e.g. for an
+    /// editor that revalidates the model being edited. Reports the same errors for the model as `validate`.
+    pub fn validate_model(
+        &self,
+        ast_schema: &ast::Datamodel,
+        schema: &dml::Datamodel,
+        model_name: &str,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        match schema.find_model(model_name) {
+            Some(model) => self.validate_model_into(ast_schema, schema, model, &mut errors),
+            None => errors.push(ValidationError::new_type_not_found_error(
+                model_name,
+                ast::Span::empty(),
+            )),
         }
 
         if errors.has_errors() {
+            errors.sort_by_span();
             Err(errors)
         } else {
             Ok(())
         }
     }
 
+    fn validate_model_into(
+        &self,
+        ast_schema: &ast::Datamodel,
+        schema: &dml::Datamodel,
+        model: &dml::Model,
+        errors: &mut ErrorCollection,
+    ) {
+        if let Err(err) = self.validate_model_has_id(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_id_fields_valid(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_field_arities(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_relation_arities(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_relation_base_fields(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_composite_references_have_base_fields(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_indices(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_referential_actions(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_list_defaults(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_foreign_key_names_unique(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_default_types(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_enum_defaults(ast_schema, schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_auto_defaults_only_on_ids(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_uuid_defaults(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_connector_capabilities(ast_schema, model) {
+            errors.push(err);
+        }
+        if let Err(err) = self.validate_datasource(ast_schema, schema, model) {
+            errors.push(err);
+        }
+    }
+
     fn validate_model_has_id(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ValidationError> {
         if model.is_relation_model() {
             return Ok(());
             // Extempt from the id rule, we have an relation table.
         }
 
-        match model.id_fields().count() {
-            1 => Ok(()),
+        let id_field_names: Vec<&str> = model.id_fields().map(|f| f.name.as_str()).collect();
+
+        match (id_field_names.len(), model.compound_id_fields.is_empty()) {
+            (1, true) | (0, false) => Ok(()),
+            (_, false) => Err(ValidationError::new_model_validation_error(
+                &format!(
+                    "A model must be identified either by one field marked with `@id` or by one `@@id` directive, but not both. The field(s) `{}` are marked with `@id` while `@@id([{}])` is declared as well.",
+                    id_field_names.join("`, `"),
+                    model.compound_id_fields.join(", ")
+                ),
+                &model.name,
+                ast_model.span,
+            )),
             _ => Err(ValidationError::new_model_validation_error(
                 "Exactly one field must be marked as the id field with the `@id` directive.",
                 &model.name,
                 ast_model.span,
             )),
+        }?;
+
+        for field_name in &model.compound_id_fields {
+            let field = match model.find_field(field_name) {
+                Some(field) => field,
+                None => {
+                    return Err(ValidationError::new_model_validation_error(
+                        &format!(
+                            "The id field `{}` does not exist on model `{}`.",
+                            field_name, model.name
+                        ),
+                        &model.name,
+                        ast_model.span,
+                    ))
+                }
+            };
+
+            let is_required_scalar = match field.field_type {
+                dml::FieldType::Base(_) => field.arity == dml::FieldArity::Required,
+                _ => false,
+            };
+
+            if !is_required_scalar {
+                return Err(ValidationError::new_model_validation_error(
+                    &format!(
+                        "The field `{}` cannot be part of the `@@id` of model `{}`. Only required scalar fields can identify a model.",
+                        field_name, model.name
+                    ),
+                    &model.name,
+                    element_span(ast_model, "id", &["fields", ""], field_name),
+                ));
+            }
         }
+
+        Ok(())
     }
 
-    fn validate_id_fields_valid(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
-        for id_field in model.id_fields() {
-            let is_valid = match (&id_field.default_value, &id_field.field_type, &id_field.arity) {
+    /// Checks which directives may be combined with which field arity.
+    ///
+    /// | Arity    | `@id`                   | `@unique` | `@updatedAt` | `@relation(fields: [...])` |
+    /// |----------|-------------------------|-----------|--------------|----------------------------|
+    /// | Required | yes                     | yes       | yes          | yes                        |
+    /// | Optional | no (checked by `@id`)   | yes       | yes          | yes                        |
+    /// | List     | no (checked by `@id`)   | no        | no           | no                         |
+    ///
+    /// Optional lists (`Type[]?`) cannot be expressed and are rejected by the parser.
+    fn validate_field_arities(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            if field.arity != dml::FieldArity::List {
+                continue;
+            }
+
+            let (error_message, directive_name) = if field.is_unique {
+                (
+                    "Fields that are marked as unique can not be lists.".to_string(),
+                    "unique",
+                )
+            } else if field.is_updated_at {
                 (
-                    Some(dml::Value::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::String),
-                    dml::FieldArity::Required,
-                ) => {
-                    let name_eq = name == "cuid" || name == "uuid";
-                    let type_eq = return_type == &dml::ScalarType::String;
-                    let args_eq = args.is_empty();
-
-                    name_eq && type_eq && args_eq
+                    "Fields that are marked with @updatedAt can not be lists.".to_string(),
+                    "updatedAt",
+                )
+            } else {
+                match &field.field_type {
+                    dml::FieldType::Relation(rel) if !rel.fields.is_empty() => (
+                        format!(
+                            "The relation field `{}` is a list and can therefore not hold the foreign key. Declare `fields` on the other side of the relation.",
+                            field.name
+                        ),
+                        "relation",
+                    ),
+                    _ => continue,
                 }
-                (None, dml::FieldType::Base(dml::ScalarType::Int), dml::FieldArity::Required) => true,
-                _ => false,
             };
 
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+            let span = directive_span(&ast_field.directives, directive_name, ast_field.span);
+
+            return Err(ValidationError::new_directive_validation_error(
+                &error_message,
+                directive_name,
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_id_fields_valid(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
+        for id_field in model.id_fields() {
+            let generator_names: &[&str] = match id_field.field_type {
+                dml::FieldType::Base(dml::ScalarType::String) => &["cuid", "uuid", "auto"],
+                dml::FieldType::Base(dml::ScalarType::Int) | dml::FieldType::Base(dml::ScalarType::BigInt) => &["auto"],
+                _ => &[],
+            };
+
+            let is_valid = id_field.arity == dml::FieldArity::Required
+                && match &id_field.default_value {
+                    None => match id_field.field_type {
+                        dml::FieldType::Base(dml::ScalarType::Int) | dml::FieldType::Base(dml::ScalarType::BigInt) => {
+                            true
+                        }
+                        _ => false,
+                    },
+                    Some(default_value) => match default_value {
+                        dml::Value::Expression(name, _, args) => {
+                            generator_names.contains(&name.as_str())
+                                && args.is_empty()
+                                && value_matches_type(default_value, &id_field.field_type)
+                        }
+                        _ => false,
+                    },
+                };
+
             if !is_valid {
                 return Err(ValidationError::new_model_validation_error(
-                    "Invalid ID field. ID field must be one of: Int @id, String @id @default(cuid()), String @id @default(uuid()).", 
+                    "Invalid ID field. ID field must be one of: Int @id, BigInt @id, Int @id @default(auto()), BigInt @id @default(auto()), String @id @default(cuid()), String @id @default(uuid()), String @id @default(auto()).", 
                     &model.name,
                     ast_schema.find_field(&model.name, &id_field.name).expect(STATE_ERROR).span));
             }
@@ -112,8 +325,22 @@ impl Validator {
                 if !field.is_generated {
                     if let dml::FieldType::Relation(rel) = &field.field_type {
                         // TODO: I am not sure if this check is d'accord with the query engine.
-                        let related = datamodel.find_model(&rel.to).unwrap();
-                        let related_field = related.related_field(&model.name, &rel.name, &field.name).unwrap();
+                        let related = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+                        let related_field = match related.related_field(&model.name, &rel.name, &field.name) {
+                            Some(related_field) => related_field,
+                            // Referencing fields of the related model does not need an opposite field.
+                            None if !rel.to_fields.is_empty() => continue,
+                            None => {
+                                return Err(ValidationError::new_model_validation_error(
+                                    &format!(
+                                        "The relation field `{}` of the embedded model `{}` has no opposite relation field on model `{}`. Use `@relation(references: [...])` to reference the related model from an embedded model.",
+                                        field.name, model.name, related.name
+                                    ),
+                                    &model.name,
+                                    ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                                ))
+                            }
+                        };
 
                         if rel.to_fields.is_empty() && !related_field.is_generated {
                             // TODO: Refactor that out, it's way too much boilerplate.
@@ -131,54 +358,727 @@ impl Validator {
         Ok(())
     }
 
+    /// Ensures that indexes only cover existing fields and that
+    /// no two indexes share the same name.
+    fn validate_indices(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+        let ast_index_directives: Vec<&ast::Directive> = ast_model
+            .directives
+            .iter()
+            .filter(|d| d.name.name == "unique" || d.name.name == "index" || d.name.name == "fulltext")
+            .collect();
+
+        for (position, index) in model.indices.iter().enumerate() {
+            let directive = ast_index_directives.get(position).expect(STATE_ERROR);
+
+            for field_name in &index.fields {
+                let field = match model.find_field(field_name) {
+                    Some(field) => field,
+                    None => {
+                        return Err(ValidationError::new_directive_validation_error(
+                            &format!(
+                                "The index field `{}` does not exist on model `{}`.",
+                                field_name, model.name
+                            ),
+                            &directive.name.name,
+                            directive.span,
+                        ))
+                    }
+                };
+
+                if index.tpe == dml::IndexType::Fulltext
+                    && field.field_type != dml::FieldType::Base(dml::ScalarType::String)
+                {
+                    return Err(ValidationError::new_directive_validation_error(
+                        &format!(
+                            "The field `{}` cannot be part of a fulltext index, because it is not of type String.",
+                            field_name
+                        ),
+                        &directive.name.name,
+                        directive.span,
+                    ));
+                }
+            }
+
+            if index.tpe == dml::IndexType::Fulltext {
+                if let Some(source_type) = self.source_lacking(|c| c.fulltext_indexes) {
+                    return Err(ValidationError::new_directive_validation_error(
+                        &format!("Fulltext indexes are not supported by the `{}` connector.", source_type),
+                        &directive.name.name,
+                        directive.span,
+                    ));
+                }
+            }
+
+            if let Some(name) = &index.name {
+                let declared_in_previous_models = datamodel
+                    .models()
+                    .take_while(|m| m.name != model.name)
+                    .flat_map(|m| m.indices.iter())
+                    .any(|other| other.name.as_ref() == Some(name));
+                let declared_before = declared_in_previous_models
+                    || model.indices[..position]
+                        .iter()
+                        .any(|other| other.name.as_ref() == Some(name));
+
+                if declared_before {
+                    return Err(ValidationError::new_directive_validation_error(
+                        &format!("The index name `{}` is declared multiple times.", name),
+                        &directive.name.name,
+                        directive.span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the foreign key of a relation can actually be set to null
+    /// or to its default value when the related record is deleted or its key is updated.
+    fn validate_referential_actions(
+        &self,
+        ast_schema: &ast::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let base_fields: Vec<&dml::Field> = rel.fields.iter().filter_map(|f| model.find_field(f)).collect();
+
+                let sets_null = |strategy: &str| {
+                    if field.arity != dml::FieldArity::Optional
+                        || base_fields.iter().any(|f| f.arity != dml::FieldArity::Optional)
+                    {
+                        Some(format!(
+                            "The {} strategy SET_NULL requires the relation field `{}` and its foreign key fields to be optional.",
+                            strategy, field.name
+                        ))
+                    } else {
+                        None
+                    }
+                };
+
+                let sets_default = |strategy: &str| {
+                    if base_fields.is_empty() || base_fields.iter().any(|f| f.default_value.is_none()) {
+                        Some(format!(
+                            "The {} strategy SET_DEFAULT requires the foreign key fields of the relation field `{}` to be declared explicitly and to have a default value.",
+                            strategy, field.name
+                        ))
+                    } else {
+                        None
+                    }
+                };
+
+                let on_delete_error = match rel.on_delete {
+                    dml::OnDeleteStrategy::SetNull => sets_null("onDelete"),
+                    dml::OnDeleteStrategy::SetDefault => sets_default("onDelete"),
+                    _ => None,
+                };
+
+                let on_update_error = match rel.on_update {
+                    dml::OnUpdateStrategy::SetNull => sets_null("onUpdate"),
+                    dml::OnUpdateStrategy::SetDefault => sets_default("onUpdate"),
+                    _ => None,
+                };
+
+                let error_message = match on_delete_error.or(on_update_error) {
+                    Some(error_message) => error_message,
+                    None => continue,
+                };
+
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let span = directive_span(&ast_field.directives, "relation", ast_field.span);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &error_message,
+                    "relation",
+                    span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Foreign key names given via `@relation(map: ...)` must be unique within the table of a model.
+    fn validate_foreign_key_names_unique(
+        &self,
+        ast_schema: &ast::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        let mut fk_names: Vec<&String> = Vec::new();
+
+        for field in model.fields() {
+            let fk_name = match &field.field_type {
+                dml::FieldType::Relation(dml::RelationInfo {
+                    fk_name: Some(fk_name), ..
+                }) => fk_name,
+                _ => continue,
+            };
+
+            if fk_names.contains(&fk_name) {
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let span = directive_span(&ast_field.directives, "relation", ast_field.span);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &format!(
+                        "The foreign key name `{}` is used more than once in model `{}`.",
+                        fk_name, model.name
+                    ),
+                    "relation",
+                    span,
+                ));
+            }
+
+            fk_names.push(fk_name);
+        }
+
+        Ok(())
+    }
+
+    /// Default values must match the type of their field, so that they can be stored.
+    fn validate_default_types(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            let default_value = match &field.default_value {
+                Some(default_value) => default_value,
+                None => continue,
+            };
+
+            if value_matches_type(default_value, &field.field_type) {
+                continue;
+            }
+
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+
+            return Err(ValidationError::new_directive_validation_error(
+                &format!(
+                    "The default value of field `{}` does not match its type `{}`.",
+                    field.name, ast_field.field_type.name
+                ),
+                "default",
+                argument_span(ast_field, "default", &["value", ""]),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Default values of enum fields must be values of the enum.
+    fn validate_enum_defaults(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            let enum_name = match &field.field_type {
+                dml::FieldType::Enum(enum_name) => enum_name,
+                _ => continue,
+            };
+
+            let default_values = match &field.default_value {
+                Some(dml::Value::List(values)) => values.iter().collect(),
+                Some(value) => vec![value],
+                None => continue,
+            };
+
+            let enum_values = &datamodel.find_enum(enum_name).expect(STATE_ERROR).values;
+
+            for default_value in default_values {
+                let value = match default_value {
+                    dml::Value::ConstantLiteral(value) if !enum_values.contains(value) => value,
+                    _ => continue,
+                };
+
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &format!("The default value `{}` is not a value of enum `{}`.", value, enum_name),
+                    "default",
+                    element_span(ast_field, "default", &["value", ""], value),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `@default(auto())` hands the generation of ids to the datastore, so it is only valid on id fields.
+    fn validate_auto_defaults_only_on_ids(
+        &self,
+        ast_schema: &ast::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            let is_auto = match &field.default_value {
+                Some(dml::Value::Expression(name, _, _)) => name == "auto",
+                _ => false,
+            };
+
+            if is_auto && field.id_info.is_none() {
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let span = argument_span(ast_field, "default", &["value", ""]);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    "The `auto()` default can only be used on id fields.",
+                    "default",
+                    span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fields stored as native UUIDs can only generate their default values with `uuid()`.
+    fn validate_uuid_defaults(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            if field.native_type != Some(dml::NativeType::Uuid) {
+                continue;
+            }
+
+            let generator = match &field.default_value {
+                Some(dml::Value::Expression(name, _, _)) if name != "uuid" => name,
+                _ => continue,
+            };
+
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+            let span = argument_span(ast_field, "default", &["value", ""]);
+
+            return Err(ValidationError::new_directive_validation_error(
+                &format!(
+                    "The `{}()` default cannot be used on fields marked with @db.Uuid. Use `uuid()` instead.",
+                    generator
+                ),
+                "default",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Models can only be stored in a declared datasource, and only be related to models of the same datasource.
+    fn validate_datasource(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+        if let Some(datasource) = &model.datasource {
+            if !self.source_names.contains(datasource) {
+                let span = directive_span(&ast_model.directives, "datasource", ast_model.span);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &format!("The datasource `{}` is not defined.", datasource),
+                    "datasource",
+                    span,
+                ));
+            }
+        }
+
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) => rel,
+                _ => continue,
+            };
+
+            let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+            let datasource = self.datasource_of(model);
+            let related_datasource = self.datasource_of(related_model);
+
+            if datasource == related_datasource {
+                continue;
+            }
+
+            if let Some(related_field) = related_model.related_field(&model.name, &rel.name, &field.name) {
+                if !tie(model, field, related_model, related_field) {
+                    continue;
+                }
+            }
+
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+            let describe = |datasource: Option<&String>| match datasource {
+                Some(name) => format!("`{}`", name),
+                None => String::from("no datasource"),
+            };
+
+            return Err(ValidationError::new_model_validation_error(
+                &format!(
+                    "The relation field `{}` relates models of different datasources. Model `{}` is stored in {}, model `{}` in {}.",
+                    field.name,
+                    model.name,
+                    describe(datasource),
+                    related_model.name,
+                    describe(related_datasource)
+                ),
+                &model.name,
+                ast_field.span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Features that only some connectors support must be rejected before the migration engine tries to apply them.
+    fn validate_connector_capabilities(
+        &self,
+        ast_schema: &ast::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+        if let (Some(_), Some(source_type)) = (&model.schema, self.source_lacking(|c| c.multiple_schemas)) {
+            let span = directive_span(&ast_model.directives, "schema", ast_model.span);
+
+            return Err(ValidationError::new_directive_validation_error(
+                &format!("Schemas are not supported by the `{}` connector.", source_type),
+                "schema",
+                span,
+            ));
+        }
+
+        if let Some(source_type) = self.source_lacking(|c| c.scalar_list_arrays) {
+            for field in model.fields() {
+                if field.scalar_list_strategy != Some(dml::ScalarListStrategy::Embedded) {
+                    continue;
+                }
+
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let span = directive_span(&ast_field.directives, "scalarList", ast_field.span);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &format!(
+                        "Scalar lists stored as arrays are not supported by the `{}` connector.",
+                        source_type
+                    ),
+                    "scalarList",
+                    span,
+                ));
+            }
+        }
+
+        if let Some(source_type) = self.source_lacking(|c| c.check_constraints) {
+            if let Some(field) = model.fields().find(|f| f.check.is_some()) {
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let span = directive_span(&ast_field.directives, "check", ast_field.span);
+
+                return Err(ValidationError::new_directive_validation_error(
+                    &format!(
+                        "Check constraints are not supported by the `{}` connector.",
+                        source_type
+                    ),
+                    "check",
+                    span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Default values of scalar lists can only be stored if the list is an array column, which requires the
+    /// embedded strategy. Connectors which store scalar lists in a separate table cannot hold them at all.
+    fn validate_list_defaults(&self, ast_schema: &ast::Datamodel, model: &dml::Model) -> Result<(), ValidationError> {
+        let side_table_source = self.source_lacking(|c| c.scalar_list_arrays);
+
+        for field in model.fields() {
+            if field.arity != dml::FieldArity::List || field.default_value.is_none() {
+                continue;
+            }
+
+            let error_message = match (&field.scalar_list_strategy, side_table_source) {
+                (_, Some(source_type)) => format!(
+                    "The list field `{}` cannot have a default value, because the `{}` connector stores scalar lists in a separate table.",
+                    field.name, source_type
+                ),
+                (Some(dml::ScalarListStrategy::Embedded), None) => continue,
+                _ => format!(
+                    "The list field `{}` cannot have a default value, because it is stored in a separate table. Only lists stored as an array (`@scalarList(strategy: EMBEDDED)`) support default values.",
+                    field.name
+                ),
+            };
+
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+            let span = argument_span(ast_field, "default", &["value", ""]);
+
+            return Err(ValidationError::new_directive_validation_error(
+                &error_message,
+                "default",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// A single foreign key column cannot reference multiple fields. Relations to models with a
+    /// compound id, or to multiple fields, must therefore list their base fields explicitly.
+    fn validate_composite_references_have_base_fields(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) if field.arity != dml::FieldArity::List && rel.fields.is_empty() => rel,
+                _ => continue,
+            };
+
+            let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+
+            let references_multiple_fields = if rel.to_fields.is_empty() {
+                // The foreign key defaults to the id of the related model, if it is stored on this side.
+                let related_field = match related_model.related_field(&model.name, &rel.name, &field.name) {
+                    Some(related_field) => related_field,
+                    None => continue,
+                };
+
+                let holds_default_foreign_key = match &related_field.field_type {
+                    dml::FieldType::Relation(related_rel) if holds_foreign_key(related_rel) => false,
+                    _ if related_field.arity == dml::FieldArity::List => true,
+                    _ => tie(model, field, related_model, related_field),
+                };
+
+                holds_default_foreign_key && !related_model.compound_id_fields.is_empty()
+            } else {
+                rel.to_fields.len() > 1
+            };
+
+            if references_multiple_fields {
+                return Err(ValidationError::new_model_validation_error(
+                    &format!(
+                        "The relation field `{}` references multiple fields of model `{}`, but a foreign key on a single column cannot hold them. Please specify the fields and the referenced fields explicitly with `@relation(fields: [...], references: [...])`.",
+                        field.name, related_model.name
+                    ),
+                    &model.name,
+                    ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the fields given in `@relation(fields: [...])` exist on the model
+    /// and match the types of the referenced fields.
+    fn validate_relation_base_fields(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+
+                for referenced_field_name in &rel.to_fields {
+                    if related_model.find_field(referenced_field_name).is_none() {
+                        let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+
+                        return Err(ValidationError::new_directive_validation_error(
+                            &format!(
+                                "The referenced field `{}` does not exist on model `{}`.",
+                                referenced_field_name, related_model.name
+                            ),
+                            "relation",
+                            element_span(ast_field, "relation", &["references"], referenced_field_name),
+                        ));
+                    }
+                }
+
+                // Foreign keys on the list side are rejected by the arity checks.
+                if rel.fields.is_empty() || field.arity == dml::FieldArity::List {
+                    continue;
+                }
+
+                let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+                let referenced_fields: Vec<&String> = if rel.to_fields.is_empty() {
+                    related_model.id_field_names().collect()
+                } else {
+                    rel.to_fields.iter().collect()
+                };
+
+                if referenced_fields.len() != rel.fields.len() {
+                    return Err(ValidationError::new_directive_validation_error(
+                        "The number of fields must match the number of referenced fields.",
+                        "relation",
+                        argument_span(ast_field, "relation", &["fields"]),
+                    ));
+                }
+
+                // A composite foreign key can only reference a tuple the database knows to be unique.
+                if referenced_fields.len() > 1 && !related_model.is_unique_criteria(&referenced_fields) {
+                    let field_names: Vec<&str> = referenced_fields.iter().map(|f| f.as_str()).collect();
+
+                    return Err(ValidationError::new_directive_validation_error(
+                        &format!(
+                            "The referenced fields `{}` must be the `@@id` or a `@@unique` of model `{}`.",
+                            field_names.join(", "),
+                            related_model.name
+                        ),
+                        "relation",
+                        argument_span(ast_field, "relation", &["references"]),
+                    ));
+                }
+
+                for (base_field_name, referenced_field_name) in rel.fields.iter().zip(referenced_fields) {
+                    let base_field = match model.find_field(base_field_name) {
+                        Some(base_field) => base_field,
+                        None => {
+                            return Err(ValidationError::new_directive_validation_error(
+                                &format!(
+                                    "The field `{}` does not exist on model `{}`.",
+                                    base_field_name, model.name
+                                ),
+                                "relation",
+                                element_span(ast_field, "relation", &["fields"], base_field_name),
+                            ))
+                        }
+                    };
+
+                    if let dml::FieldType::Relation(_) = base_field.field_type {
+                        return Err(ValidationError::new_directive_validation_error(
+                            &format!("The field `{}` must be a scalar field.", base_field_name),
+                            "relation",
+                            element_span(ast_field, "relation", &["fields"], base_field_name),
+                        ));
+                    }
+
+                    if let Some(referenced_field) = related_model.find_field(referenced_field_name) {
+                        if referenced_field.field_type != base_field.field_type {
+                            return Err(ValidationError::new_directive_validation_error(
+                                &format!(
+                                    "The type of the field `{}` does not match the type of the referenced field `{}.{}`.",
+                                    base_field_name, related_model.name, referenced_field_name
+                                ),
+                                "relation",
+                                element_span(ast_field, "relation", &["fields"], base_field_name),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the arities of both sides of a relation are coherent with where its foreign key is declared.
+    ///
+    /// | Side A   | Side B   | Kind         | Foreign key                                      |
+    /// |----------|----------|--------------|--------------------------------------------------|
+    /// | List     | List     | many-to-many | in a relation table, neither side may declare it |
+    /// | List     | Singular | one-to-many  | on side B                                        |
+    /// | Singular | Singular | one-to-one   | on at most one side                              |
+    ///
+    /// Relations without an explicit opposite field are always coherent, the opposite field is generated.
+    fn validate_relation_arities(
+        &self,
+        ast_schema: &ast::Datamodel,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ValidationError> {
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) => rel,
+                _ => continue,
+            };
+
+            let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
+            let related_field = match related_model.related_field(&model.name, &rel.name, &field.name) {
+                Some(related_field) => related_field,
+                None => continue,
+            };
+            let related_rel = match &related_field.field_type {
+                dml::FieldType::Relation(related_rel) => related_rel,
+                _ => continue,
+            };
+
+            let ast_field = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR);
+            let span = directive_span(&ast_field.directives, "relation", ast_field.span);
+
+            let reported_here = tie(model, field, related_model, related_field);
+
+            let error_message = match (field.arity, related_field.arity) {
+                // `fields` on a list are rejected by the arity checks.
+                (dml::FieldArity::List, dml::FieldArity::List) if !rel.to_fields.is_empty() => format!(
+                    "The relation field `{}` on model `{}` can not declare `references`. Both sides of the relation are lists, so it is a many-to-many relation which is stored in a separate relation table.",
+                    field.name, model.name
+                ),
+                (dml::FieldArity::List, _) if !rel.to_fields.is_empty() => format!(
+                    "The relation field `{}` on model `{}` is a list and can therefore not declare `references`. The foreign key of this one-to-many relation must be declared on `{}.{}`.",
+                    field.name, model.name, related_model.name, related_field.name
+                ),
+                (dml::FieldArity::List, _) | (_, dml::FieldArity::List) => continue,
+                (_, _) if reported_here && holds_foreign_key(rel) && holds_foreign_key(related_rel) => format!(
+                    "The relation fields `{}` on model `{}` and `{}` on model `{}` both declare the foreign key of the relation. The foreign key of a one-to-one relation can only be declared on one side.",
+                    field.name, model.name, related_field.name, related_model.name
+                ),
+                (_, _) => continue,
+            };
+
+            return Err(ValidationError::new_directive_validation_error(
+                &error_message,
+                "relation",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Elegantly checks if any relations in the model are ambigious.
     fn validate_relations_not_ambiguous(
         &self,
         ast_schema: &ast::Datamodel,
         model: &dml::Model,
     ) -> Result<(), ValidationError> {
-        for field_a in model.fields() {
-            for field_b in model.fields() {
-                if field_a != field_b {
-                    if let dml::FieldType::Relation(rel_a) = &field_a.field_type {
-                        if let dml::FieldType::Relation(rel_b) = &field_b.field_type {
-                            if rel_a.to != model.name && rel_b.to != model.name {
-                                // Not a self relation
-                                // but pointing to the same foreign model,
-                                // and also no names set.
-                                if rel_a.to == rel_b.to && rel_a.name == rel_b.name {
-                                    return Err(ValidationError::new_model_validation_error(
-                                        "Ambiguous relation detected.",
-                                        &model.name,
-                                        ast_schema
-                                            .find_field(&model.name, &field_a.name)
-                                            .expect(STATE_ERROR)
-                                            .span,
-                                    ));
-                                }
-                            } else {
-                                // A self relation...
-                                for field_c in model.fields() {
-                                    if field_a != field_c && field_b != field_c {
-                                        if let dml::FieldType::Relation(rel_c) = &field_c.field_type {
-                                            // ...but there are more thatn three fields without a name.
-                                            if rel_c.to == model.name
-                                                && rel_a.name == rel_b.name
-                                                && rel_a.name == rel_c.name
-                                            {
-                                                return Err(ValidationError::new_model_validation_error(
-                                                    "Ambiguous self relation detected.",
-                                                    &model.name,
-                                                    ast_schema
-                                                        .find_field(&model.name, &field_a.name)
-                                                        .expect(STATE_ERROR)
-                                                        .span,
-                                                ));
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+        let relation_fields = || {
+            model
+                .fields()
+                .filter_map(|field| field.field_type.as_relation().map(|rel| (field, rel)))
+        };
+
+        for (field_a, rel_a) in relation_fields() {
+            for (field_b, rel_b) in relation_fields().filter(|(field_b, _)| field_a != *field_b) {
+                if rel_a.to != model.name && rel_b.to != model.name {
+                    // Not a self relation
+                    // but pointing to the same foreign model,
+                    // and also no names set.
+                    if rel_a.to == rel_b.to && rel_a.name == rel_b.name {
+                        return Err(ValidationError::new_model_validation_error(
+                            "Ambiguous relation detected.",
+                            &model.name,
+                            ast_schema
+                                .find_field(&model.name, &field_a.name)
+                                .expect(STATE_ERROR)
+                                .span,
+                        ));
+                    }
+                } else {
+                    // A self relation...
+                    for (_, rel_c) in
+                        relation_fields().filter(|(field_c, _)| field_a != *field_c && field_b != *field_c)
+                    {
+                        // ...but there are more thatn three fields without a name.
+                        if rel_c.to == model.name && rel_a.name == rel_b.name && rel_a.name == rel_c.name {
+                            return Err(ValidationError::new_model_validation_error(
+                                "Ambiguous self relation detected.",
+                                &model.name,
+                                ast_schema
+                                    .find_field(&model.name, &field_a.name)
+                                    .expect(STATE_ERROR)
+                                    .span,
+                            ));
                         }
                     }
                 }
@@ -188,3 +1088,67 @@ impl Validator {
         Ok(())
     }
 }
+
+/// A relation field declares the foreign key if it lists the referenced or the referencing fields.
+fn holds_foreign_key(rel: &dml::RelationInfo) -> bool {
+    !rel.to_fields.is_empty() || !rel.fields.is_empty()
+}
+
+/// The span of a directive of a model or field, falling back to the given span of the model or field itself.
+fn directive_span(directives: &[ast::Directive], directive_name: &str, fallback: ast::Span) -> ast::Span {
+    directives
+        .iter()
+        .find(|d| d.name.name == directive_name)
+        .map(|d| d.span)
+        .unwrap_or(fallback)
+}
+
+/// The span of the value of the first given argument of a field or model directive, which is present.
+/// Falls back to the span of the directive, or of the field or model, if the argument is not written out.
+fn argument_span<T: ast::WithDirectives + ast::WithSpan>(
+    ast_node: &T,
+    directive_name: &str,
+    argument_names: &[&str],
+) -> ast::Span {
+    match ast_node.directives().iter().find(|d| d.name.name == directive_name) {
+        Some(directive) => directive
+            .arguments
+            .iter()
+            .find(|arg| argument_names.contains(&arg.name.name.as_str()))
+            .map(|arg| arg.value.span())
+            .unwrap_or(directive.span),
+        None => *ast_node.span(),
+    }
+}
+
+/// The span of a single element of an array argument of a field or model directive, see `argument_span`.
+fn element_span<T: ast::WithDirectives + ast::WithSpan>(
+    ast_node: &T,
+    directive_name: &str,
+    argument_names: &[&str],
+    element: &str,
+) -> ast::Span {
+    let elements = ast_node
+        .directives()
+        .iter()
+        .find(|d| d.name.name == directive_name)
+        .and_then(|d| {
+            d.arguments
+                .iter()
+                .find(|arg| argument_names.contains(&arg.name.name.as_str()))
+        })
+        .map(|arg| match &arg.value {
+            ast::Value::Array(elements, _) => elements.as_slice(),
+            value => std::slice::from_ref(value),
+        })
+        .unwrap_or(&[]);
+
+    elements
+        .iter()
+        .find(|value| match value {
+            ast::Value::ConstantValue(name, _) => name == element,
+            _ => false,
+        })
+        .map(|value| value.span())
+        .unwrap_or_else(|| argument_span(ast_node, directive_name, argument_names))
+}
@@ -27,6 +27,19 @@ impl ValidationPipeline {
         }
     }
 
+    /// Creates a new instance, with all builtin directives registered, which rejects the features
+    /// the given capabilities of a connector do not include.
+    pub fn with_capabilities(
+        connector_type: &str,
+        capabilities: configuration::ConnectorCapabilities,
+    ) -> ValidationPipeline {
+        ValidationPipeline {
+            lifter: LiftAstToDml::new(),
+            validator: Validator::with_capabilities(connector_type, capabilities),
+            standardiser: Standardiser::new(),
+        }
+    }
+
     /// Validates an AST semantically and promotes it to a datamodel/schema.
     ///
     /// This method will attempt to
@@ -4,6 +4,7 @@ use crate::common::FromStrAndSpan;
 use crate::common::PrismaType;
 use crate::dml;
 use chrono::{DateTime, Utc};
+use std::convert::TryFrom;
 
 fn type_from_string(scalar: &str) -> PrismaType {
     PrismaType::from_str_and_span(scalar, Span::empty()).unwrap()
@@ -12,48 +13,86 @@ fn type_from_string(scalar: &str) -> PrismaType {
 pub fn default_value_from_serde(
     container: &Option<serde_json::Value>,
     field_type: &dml::FieldType,
-) -> Option<dml::Value> {
+) -> Result<Option<dml::Value>, String> {
     match (container, field_type) {
-        // Scalar.
-        (Some(value), dml::FieldType::Base(scalar_type)) => Some(match (value, scalar_type) {
-            (serde_json::Value::Bool(val), PrismaType::Boolean) => dml::Value::Boolean(*val),
-            (serde_json::Value::String(val), PrismaType::String) => dml::Value::String(String::from(val.as_str())),
-            (serde_json::Value::Number(val), PrismaType::Float) => dml::Value::Float(val.as_f64().unwrap() as f32),
-            (serde_json::Value::Number(val), PrismaType::Int) => dml::Value::Int(val.as_i64().unwrap() as i32),
-            (serde_json::Value::Number(val), PrismaType::Decimal) => dml::Value::Decimal(val.as_f64().unwrap() as f32),
-            (serde_json::Value::String(val), PrismaType::DateTime) => {
-                dml::Value::DateTime(String::from(val.as_str()).parse::<DateTime<Utc>>().unwrap())
-            }
-            // Function.
-            (serde_json::Value::Object(_), _) => {
-                let func = serde_json::from_value::<Function>(value.clone()).expect("Failed to parse function JSON");
-                function_from_dmmf(&func, *scalar_type)
+        // List.
+        (Some(serde_json::Value::Array(values)), _) => {
+            let mut list = Vec::with_capacity(values.len());
+
+            for value in values {
+                match default_value_from_serde(&Some(value.clone()), field_type)? {
+                    Some(dml::Value::List(_)) | None => {
+                        return Err(format!("Invalid element {} in a list default value.", value))
+                    }
+                    Some(element) => list.push(element),
+                }
             }
-            _ => panic!("Invalid type/value combination for default value."),
-        }),
-        // Enum.
-        (Some(value), dml::FieldType::Enum(_)) => {
-            Some(dml::Value::ConstantLiteral(String::from(value.as_str().unwrap())))
+
+            Ok(Some(dml::Value::List(list)))
+        }
+        // Scalar.
+        (Some(value), dml::FieldType::Base(scalar_type)) => {
+            let invalid = || {
+                format!(
+                    "Invalid default value {} for a field of type {}.",
+                    value,
+                    scalar_type.to_string()
+                )
+            };
+
+            Ok(Some(match (value, scalar_type) {
+                (serde_json::Value::Bool(val), PrismaType::Boolean) => dml::Value::Boolean(*val),
+                (serde_json::Value::String(val), PrismaType::String) => dml::Value::String(String::from(val.as_str())),
+                (serde_json::Value::String(val), PrismaType::Xml) => dml::Value::String(String::from(val.as_str())),
+                (serde_json::Value::Number(val), PrismaType::Float) => {
+                    dml::Value::Float(val.as_f64().ok_or_else(invalid)? as f32)
+                }
+                (serde_json::Value::Number(val), PrismaType::Int) => dml::Value::Int(
+                    val.as_i64()
+                        .and_then(|val| i32::try_from(val).ok())
+                        .ok_or_else(invalid)?,
+                ),
+                (serde_json::Value::Number(val), PrismaType::BigInt) => {
+                    dml::Value::BigInt(val.as_i64().ok_or_else(invalid)?)
+                }
+                (serde_json::Value::Number(val), PrismaType::Decimal) => {
+                    dml::Value::Decimal(val.as_f64().ok_or_else(invalid)? as f32)
+                }
+                (serde_json::Value::String(val), PrismaType::DateTime) => {
+                    dml::Value::DateTime(val.parse::<DateTime<Utc>>().map_err(|_| invalid())?)
+                }
+                // Function.
+                (serde_json::Value::Object(_), _) => {
+                    let func = serde_json::from_value::<Function>(value.clone()).map_err(|_| invalid())?;
+                    function_from_dmmf(&func, *scalar_type)?
+                }
+                _ => return Err(invalid()),
+            }))
         }
-        (Some(_), _) => panic!("Fields with non-scalar type cannot have default value"),
-        _ => None,
+        // Enum.
+        (Some(value), dml::FieldType::Enum(_)) => match value.as_str() {
+            Some(literal) => Ok(Some(dml::Value::ConstantLiteral(String::from(literal)))),
+            None => Err(format!("Invalid default value {} for an enum field.", value)),
+        },
+        (Some(_), _) => Err(String::from("Fields with non-scalar type cannot have default value")),
+        _ => Ok(None),
     }
 }
 
-fn function_from_dmmf(func: &Function, expected_type: PrismaType) -> dml::Value {
+fn function_from_dmmf(func: &Function, expected_type: PrismaType) -> Result<dml::Value, String> {
     if !func.args.is_empty() {
-        panic!("Function argument deserialization is not supported with DMMF. There are no type annotations yet, so it's not clear which is meant.");
+        return Err(String::from("Function argument deserialization is not supported with DMMF. There are no type annotations yet, so it's not clear which is meant."));
     }
 
     if func.return_type != expected_type.to_string() {
-        panic!(
+        return Err(format!(
             "Type missmatch during deserialization. Expected: {}, but got: {}.",
             expected_type.to_string(),
             func.return_type
-        );
+        ));
     }
 
-    dml::Value::Expression(func.name.clone(), expected_type, vec![])
+    Ok(dml::Value::Expression(func.name.clone(), expected_type, vec![]))
 }
 
 fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
@@ -63,16 +102,27 @@ fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
     }
 }
 
+fn get_on_update_strategy(strategy: &Option<String>) -> dml::OnUpdateStrategy {
+    match strategy {
+        Some(val) => dml::OnUpdateStrategy::from_str_and_span(&val, Span::empty()).unwrap(),
+        None => dml::OnUpdateStrategy::None,
+    }
+}
+
 fn get_field_type(field: &Field) -> dml::FieldType {
     match &field.kind as &str {
         "object" => dml::FieldType::Relation(dml::RelationInfo {
             to: field.field_type.clone(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
+            fields: field.relation_from_fields.clone().unwrap_or_default(),
             name: field.relation_name.clone().unwrap_or(String::new()),
             on_delete: get_on_delete_strategy(&field.relation_on_delete),
+            on_update: get_on_update_strategy(&field.relation_on_update),
+            fk_name: None,
         }),
         "enum" => dml::FieldType::Enum(field.field_type.clone()),
         "scalar" => dml::FieldType::Base(type_from_string(&field.field_type)),
+        "unsupported" => dml::FieldType::Unsupported(field.field_type.clone()),
         _ => panic!(format!("Unknown field kind {}.", &field.kind)),
     }
 }
@@ -95,9 +145,10 @@ pub fn enum_from_dmmf(en: &Enum) -> dml::Enum {
     }
 }
 
-pub fn field_from_dmmf(field: &Field) -> dml::Field {
+pub fn field_from_dmmf(field: &Field) -> Result<dml::Field, String> {
     let field_type = get_field_type(field);
-    let default_value = default_value_from_serde(&field.default, &field_type);
+    let default_value = default_value_from_serde(&field.default, &field_type)
+        .map_err(|err| format!("Field {}: {}", field.name, err))?;
     // TODO: Id details?
     let id_info = match &field.is_id {
         true => Some(dml::IdInfo {
@@ -107,7 +158,7 @@ pub fn field_from_dmmf(field: &Field) -> dml::Field {
         false => None,
     };
 
-    dml::Field {
+    Ok(dml::Field {
         name: field.name.clone(),
         arity: get_field_arity(field.is_required, field.is_list),
         database_name: field.db_name.clone(),
@@ -117,41 +168,55 @@ pub fn field_from_dmmf(field: &Field) -> dml::Field {
         is_unique: field.is_unique,
         // TODO: Scalar List Strategy
         scalar_list_strategy: None,
+        native_type: None,
+        check: None,
         is_generated: field.is_generated.unwrap_or(false),
         is_updated_at: field.is_updated_at.unwrap_or(false),
         documentation: field.documentation.clone(),
-    }
+        unknown_directives: Vec::new(),
+    })
 }
 
-pub fn model_from_dmmf(model: &Model) -> dml::Model {
-    dml::Model {
+pub fn model_from_dmmf(model: &Model) -> Result<dml::Model, String> {
+    let fields = model
+        .fields
+        .iter()
+        .map(&field_from_dmmf)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Model {}, {}", model.name, err))?;
+
+    Ok(dml::Model {
         name: model.name.clone(),
         database_name: model.db_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields.iter().map(&field_from_dmmf).collect(),
+        fields,
         documentation: model.documentation.clone(),
         is_generated: model.is_generated.unwrap_or(false),
-    }
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        datasource: None,
+    })
 }
 
-pub fn schema_from_dmmf(schema: &Datamodel) -> dml::Datamodel {
+pub fn schema_from_dmmf(schema: &Datamodel) -> Result<dml::Datamodel, String> {
     let mut datamodel = dml::Datamodel {
         models: vec![],
         enums: vec![],
     };
 
     for model in &schema.models {
-        datamodel.add_model(model_from_dmmf(&model));
+        datamodel.add_model(model_from_dmmf(&model)?);
     }
 
     for enum_model in &schema.enums {
         datamodel.add_enum(enum_from_dmmf(&enum_model));
     }
 
-    datamodel
+    Ok(datamodel)
 }
 
-pub fn parse_from_dmmf(dmmf: &str) -> dml::Datamodel {
+pub fn parse_from_dmmf(dmmf: &str) -> Result<dml::Datamodel, String> {
     let parsed_dmmf = serde_json::from_str::<Datamodel>(&dmmf).expect("Failed to parse JSON");
     schema_from_dmmf(&parsed_dmmf)
 }
@@ -29,8 +29,12 @@ pub struct Field {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_to_fields: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_from_fields: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_on_delete: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_on_update: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_updated_at: Option<bool>,
@@ -8,6 +8,7 @@ fn get_field_kind(field: &dml::Field) -> String {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_) => String::from("scalar"),
+        dml::FieldType::Unsupported(_) => String::from("unsupported"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
     }
 }
@@ -21,6 +22,7 @@ fn get_field_type(field: &dml::Field) -> String {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
         dml::FieldType::Base(t) => type_to_string(t),
+        dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::ConnectorSpecific {
             base_type: t,
             ..
@@ -51,9 +53,11 @@ pub fn value_to_serde(value: &dml::Value) -> serde_json::Value {
         dml::Value::ConstantLiteral(val) => serde_json::Value::String(val.clone()),
         dml::Value::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::Value::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        dml::Value::BigInt(val) => serde_json::Value::Number(serde_json::Number::from(*val)),
         dml::Value::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::Value::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         dml::Value::Expression(name, return_type, args) => function_to_serde(&name, *return_type, &args),
+        dml::Value::List(values) => serde_json::Value::Array(values.iter().map(value_to_serde).collect()),
     }
 }
 
@@ -81,6 +85,15 @@ pub fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
     }
 }
 
+pub fn get_relation_from_fields(field: &dml::Field) -> Option<Vec<String>> {
+    match &field.field_type {
+        dml::FieldType::Relation(relation_info) if !relation_info.fields.is_empty() => {
+            Some(relation_info.fields.clone())
+        }
+        _ => None,
+    }
+}
+
 pub fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => Some(relation_info.on_delete.to_string()),
@@ -88,6 +101,13 @@ pub fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     }
 }
 
+pub fn get_relation_update_strategy(field: &dml::Field) -> Option<String> {
+    match &field.field_type {
+        dml::FieldType::Relation(relation_info) => Some(relation_info.on_update.to_string()),
+        _ => None,
+    }
+}
+
 pub fn field_to_dmmf(field: &dml::Field) -> Field {
     Field {
         name: field.name.clone(),
@@ -100,7 +120,9 @@ pub fn field_to_dmmf(field: &dml::Field) -> Field {
         is_unique: field.is_unique,
         relation_name: get_relation_name(field),
         relation_to_fields: get_relation_to_fields(field),
+        relation_from_fields: get_relation_from_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_update: get_relation_update_strategy(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated),
         is_updated_at: Some(field.is_updated_at),
@@ -35,6 +35,20 @@ impl ErrorCollection {
         self.errors.append(&mut errs.errors)
     }
 
+    /// Orders all errors by the start offset of their span.
+    ///
+    /// The sort is stable, so errors at the same position keep their relative order.
+    pub fn sort_by_span(&mut self) {
+        self.errors.sort_by_key(|err| err.span().start)
+    }
+
+    /// Serializes all errors to a JSON array, for tooling integration.
+    ///
+    /// Each error is represented as an object with the fields `message`, `start`, `end` and `kind`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.errors.iter().map(|err| err.to_json()).collect())
+    }
+
     pub fn ok(&self) -> Result<(), ErrorCollection> {
         if self.has_errors() {
             Err(self.clone())
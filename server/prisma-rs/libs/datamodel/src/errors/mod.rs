@@ -1,5 +1,7 @@
 mod collection;
+mod warning;
 pub use collection::*;
+pub use warning::*;
 
 use crate::ast::Span;
 use colored::Colorize;
@@ -98,6 +100,9 @@ pub enum ValidationError {
     #[fail(display = "Error validating model \"{}\": {}.", model_name, message)]
     ModelValidationError { message: String, model_name: String, span: Span  },
 
+    #[fail(display = "Error validating enum \"{}\": {}.", enum_name, message)]
+    EnumValidationError { message: String, enum_name: String, span: Span  },
+
     #[fail(display = "Error validating: {}.", message)]
     ValidationError { message: String, span: Span  },
 }
@@ -234,6 +239,14 @@ impl ValidationError {
         }
     }
 
+    pub fn new_enum_validation_error(message: &str, enum_name: &str, span: Span) -> ValidationError {
+        ValidationError::EnumValidationError {
+            message: String::from(message),
+            enum_name: String::from(enum_name),
+            span,
+        }
+    }
+
     pub fn new_validation_error(message: &str, span: Span) -> ValidationError {
         ValidationError::ValidationError {
             message: String::from(message),
@@ -315,6 +328,7 @@ impl ValidationError {
             ValidationError::ValidationError { span, .. } => *span,
             ValidationError::LegacyParserError { span, .. } => *span,
             ValidationError::ModelValidationError { span, .. } => *span,
+            ValidationError::EnumValidationError { span, .. } => *span,
             ValidationError::DuplicateDirectiveError { span, .. } => *span,
             ValidationError::DuplicateConfigKeyError { span, .. } => *span,
             ValidationError::DuplicateTopError { span, .. } => *span,
@@ -325,13 +339,96 @@ impl ValidationError {
             ValidationError::UnusedArgumentError { span, .. } => *span
         }
     }
+    /// The name of the error kind, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ValidationError::ArgumentNotFound { .. } => "ArgumentNotFound",
+            ValidationError::DirectiveArgumentNotFound { .. } => "DirectiveArgumentNotFound",
+            ValidationError::ArgumentCountMissmatch { .. } => "ArgumentCountMissmatch",
+            ValidationError::SourceArgumentNotFound { .. } => "SourceArgumentNotFound",
+            ValidationError::GeneratorArgumentNotFound { .. } => "GeneratorArgumentNotFound",
+            ValidationError::DirectiveValidationError { .. } => "DirectiveValidationError",
+            ValidationError::DirectiveNotKnownError { .. } => "DirectiveNotKnownError",
+            ValidationError::ReservedScalarTypeError { .. } => "ReservedScalarTypeError",
+            ValidationError::FunctionNotKnownError { .. } => "FunctionNotKnownError",
+            ValidationError::SourceNotKnownError { .. } => "SourceNotKnownError",
+            ValidationError::LiteralParseError { .. } => "LiteralParseError",
+            ValidationError::TypeNotFoundError { .. } => "TypeNotFoundError",
+            ValidationError::ScalarTypeNotFoundError { .. } => "ScalarTypeNotFoundError",
+            ValidationError::ParserError { .. } => "ParserError",
+            ValidationError::FunctionalEvaluationError { .. } => "FunctionalEvaluationError",
+            ValidationError::EnvironmentFunctionalEvaluationError { .. } => "EnvironmentFunctionalEvaluationError",
+            ValidationError::TypeMismatchError { .. } => "TypeMismatchError",
+            ValidationError::ValueParserError { .. } => "ValueParserError",
+            ValidationError::ValidationError { .. } => "ValidationError",
+            ValidationError::LegacyParserError { .. } => "LegacyParserError",
+            ValidationError::ModelValidationError { .. } => "ModelValidationError",
+            ValidationError::EnumValidationError { .. } => "EnumValidationError",
+            ValidationError::DuplicateDirectiveError { .. } => "DuplicateDirectiveError",
+            ValidationError::DuplicateConfigKeyError { .. } => "DuplicateConfigKeyError",
+            ValidationError::DuplicateTopError { .. } => "DuplicateTopError",
+            ValidationError::DuplicateFieldError { .. } => "DuplicateFieldError",
+            ValidationError::DuplicateEnumValueError { .. } => "DuplicateEnumValueError",
+            ValidationError::DuplicateArgumentError { .. } => "DuplicateArgumentError",
+            ValidationError::DuplicateDefaultArgumentError { .. } => "DuplicateDefaultArgumentError",
+            ValidationError::UnusedArgumentError { .. } => "UnusedArgumentError"
+        }
+    }
+
     pub fn description(&self) -> String {
         format!("{}", self)
     }
 
+    /// Serializes the error to a JSON object with the fields `message`, `start`, `end` and `kind`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let span = self.span();
+
+        serde_json::json!({
+            "message": self.description(),
+            "start": span.start,
+            "end": span.end,
+            "kind": self.kind(),
+        })
+    }
+
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
         pretty_print_error(f, file_name, text, self)
     }
+
+    /// Renders the error without colors, for tools that show errors themselves: The offending line of the
+    /// given source, with the span underlined by carets and followed by the message, similar to rustc.
+    /// Spans covering several lines are only underlined up to the end of their first line.
+    pub fn pretty_print_plain(&self, source: &str) -> String {
+        let span = self.span();
+        let start = std::cmp::min(span.start, source.len());
+
+        let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = source[start..].find('\n').map(|i| start + i).unwrap_or(source.len());
+        let line = source[line_start..line_end].trim_end_matches('\r');
+        let line_number = (source[..start].matches('\n').count() + 1).to_string();
+
+        // Tabs are kept, so the carets line up with the source line however tabs are displayed.
+        let indentation: String = source[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let end = std::cmp::max(std::cmp::min(span.end, line_start + line.len()), start);
+        let underline = "^".repeat(std::cmp::max(source[start..end].chars().count(), 1));
+
+        let gutter = " ".repeat(line_number.len());
+        let column = source[line_start..start].chars().count() + 1;
+
+        format!(
+            "{gutter}--> {line_number}:{column}\n{gutter} |\n{line_number} | {line}\n{gutter} | {indentation}{underline} {message}\n",
+            gutter = gutter,
+            line_number = line_number,
+            column = column,
+            line = line,
+            indentation = indentation,
+            underline = underline,
+            message = self.description()
+        )
+    }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
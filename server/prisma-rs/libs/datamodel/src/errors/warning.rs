@@ -0,0 +1,27 @@
+use crate::ast::Span;
+
+/// A style warning about a datamodel.
+///
+/// Unlike a `ValidationError`, a warning never makes a datamodel invalid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatamodelWarning {
+    pub message: String,
+    pub span: Span,
+}
+
+impl DatamodelWarning {
+    pub fn new(message: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning {
+            message: String::from(message),
+            span,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn description(&self) -> String {
+        self.message.clone()
+    }
+}
@@ -78,6 +78,23 @@ pub fn parse_with_plugins(
     source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
 ) -> Result<Datamodel, errors::ErrorCollection> {
     let ast = parser::parse(datamodel_string)?;
+    validate_with_plugins(&ast, source_definitions)
+}
+
+/// Parses and validates a datamodel read from the given reader, using core attributes and the given plugins.
+/// The input is parsed block by block, instead of being read into memory as a whole first.
+pub fn parse_reader_with_plugins<R: std::io::Read>(
+    reader: R,
+    source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
+) -> Result<Datamodel, errors::ErrorCollection> {
+    let ast = parser::parse_reader(reader)?;
+    validate_with_plugins(&ast, source_definitions)
+}
+
+fn validate_with_plugins(
+    ast: &ast::Datamodel,
+    source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
+) -> Result<Datamodel, errors::ErrorCollection> {
     let mut source_loader = SourceLoader::new();
     for source in get_builtin_sources() {
         source_loader.add_source_definition(source);
@@ -88,7 +105,7 @@ pub fn parse_with_plugins(
 
     let mut errors = errors::ErrorCollection::new();
 
-    let sources = match source_loader.load(&ast) {
+    let sources = match source_loader.load(ast) {
         Ok(src) => src,
         Err(mut err) => {
             errors.append(&mut err);
@@ -97,7 +114,7 @@ pub fn parse_with_plugins(
     };
     let validator = ValidationPipeline::with_sources(&sources);
 
-    match validator.validate(&ast) {
+    match validator.validate(ast) {
         Ok(src) => Ok(src),
         Err(mut err) => {
             errors.append(&mut err);
@@ -106,6 +123,38 @@ pub fn parse_with_plugins(
     }
 }
 
+/// Validates a datamodel that was constructed programmatically, using core attributes only.
+/// The datamodel is lowered to an AST first, so it passes exactly the same validation as a parsed one.
+pub fn validate(datamodel: &dml::Datamodel) -> Result<Datamodel, errors::ErrorCollection> {
+    let lowered = dml::validator::LowerDmlToAst::new().lower(datamodel)?;
+    validate_with_plugins(&lowered, vec![])
+}
+
+/// Parses and validates a datamodel string against the capabilities of a connector, using core attributes only.
+/// Useful to check a datamodel for a connector before a datasource is declared, e.g. `@@schema` fails for `sqlite`.
+pub fn validate_with_capabilities(
+    datamodel_string: &str,
+    connector_type: &str,
+    capabilities: ConnectorCapabilities,
+) -> Result<Datamodel, errors::ErrorCollection> {
+    let ast = parser::parse(datamodel_string)?;
+    ValidationPipeline::with_capabilities(connector_type, capabilities).validate(&ast)
+}
+
+/// Parses and validates a datamodel string, using core attributes only, without returning the datamodel.
+/// Useful for callers that only care whether a datamodel is valid.
+pub fn validate_str(datamodel_string: &str) -> Result<(), errors::ErrorCollection> {
+    parse(datamodel_string).map(|_| ())
+}
+
+/// Parses and validates a datamodel string and checks it for style issues, like naming conventions.
+/// The warnings do not affect validation: a datamodel with warnings is still valid.
+pub fn lint(datamodel_string: &str) -> Result<Vec<errors::DatamodelWarning>, errors::ErrorCollection> {
+    let ast = parser::parse(datamodel_string)?;
+    let datamodel = validate_with_plugins(&ast, vec![])?;
+    Ok(dml::validator::Linter::new().lint(&ast, &datamodel))
+}
+
 /// Loads all source configuration blocks from a datamodel using the given source definitions.
 #[deprecated(note = "please use `load_configuration_with_plugins` instead")]
 pub fn load_data_source_configuration_with_plugins(
@@ -165,6 +214,19 @@ pub fn parse(datamodel_string: &str) -> Result<Datamodel, errors::ErrorCollectio
     parse_with_plugins(datamodel_string, vec![])
 }
 
+/// Parses and validates a datamodel string, using core attributes only.
+/// The AST is returned along with the datamodel, e.g. for tooling that needs the location of every element.
+pub fn parse_to_ast_and_dml(datamodel_string: &str) -> Result<(ast::Datamodel, Datamodel), errors::ErrorCollection> {
+    let ast = parser::parse(datamodel_string)?;
+    let datamodel = validate_with_plugins(&ast, vec![])?;
+    Ok((ast, datamodel))
+}
+
+/// Parses and validates a datamodel read from the given reader, using core attributes only.
+pub fn parse_reader<R: std::io::Read>(reader: R) -> Result<Datamodel, errors::ErrorCollection> {
+    parse_reader_with_plugins(reader, vec![])
+}
+
 /// Parses and validates a datamodel string, using core attributes only.
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_with_formatted_error(datamodel_string: &str, file_name: &str) -> Result<Datamodel, String> {
@@ -31,6 +31,48 @@ fn parse_scalar_types() {
         .assert_base_type(&PrismaType::Float);
 }
 
+#[test]
+fn parse_xml_type_with_string_default() {
+    let dml = r#"
+    model Document {
+        id Int @id
+        content Xml @default("<root/>")
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("Document")
+        .assert_has_field("content")
+        .assert_base_type(&PrismaType::Xml)
+        .assert_default_value(dml::Value::String(String::from("<root/>")));
+}
+
+#[test]
+fn parse_big_int_type_with_auto_id_and_large_default() {
+    let dml = r#"
+    model Tweet {
+        id BigInt @id @default(auto())
+        retweets BigInt @default(9007199254740993)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let tweet_model = schema.assert_has_model("Tweet");
+    tweet_model
+        .assert_has_field("id")
+        .assert_base_type(&PrismaType::BigInt)
+        .assert_default_value(dml::Value::Expression(
+            String::from("auto"),
+            PrismaType::BigInt,
+            Vec::new(),
+        ));
+    tweet_model
+        .assert_has_field("retweets")
+        .assert_base_type(&PrismaType::BigInt)
+        .assert_default_value(dml::Value::BigInt(9007199254740993));
+}
+
 #[test]
 fn parse_field_arity() {
     let dml = r#"
@@ -57,3 +99,24 @@ fn parse_field_arity() {
         .assert_base_type(&PrismaType::String)
         .assert_arity(&dml::FieldArity::List);
 }
+
+#[test]
+fn scalar_type_names_must_round_trip() {
+    let scalar_types = vec![
+        dml::ScalarType::Int,
+        dml::ScalarType::BigInt,
+        dml::ScalarType::Float,
+        dml::ScalarType::Decimal,
+        dml::ScalarType::Boolean,
+        dml::ScalarType::String,
+        dml::ScalarType::DateTime,
+        dml::ScalarType::Xml,
+    ];
+
+    for scalar_type in scalar_types {
+        assert_eq!(dml::ScalarType::from_str(scalar_type.as_str()), Some(scalar_type));
+    }
+
+    assert_eq!(dml::ScalarType::from_str("int"), None);
+    assert_eq!(dml::ScalarType::from_str("Unknown"), None);
+}
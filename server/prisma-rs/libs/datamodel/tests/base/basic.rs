@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::common::PrismaType;
+use datamodel::{ast::Span, common::PrismaType, errors::ValidationError};
 
 #[test]
 fn parse_basic_model() {
@@ -67,3 +67,72 @@ fn parse_comments() {
         .assert_has_field("firstName")
         .assert_with_documentation("The first name.\nCan be multi-line.");
 }
+
+#[test]
+fn partition_scalar_and_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String
+        role Role
+        posts Post[]
+        profile Profile?
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+
+    model Profile {
+        id Int @id
+        user User
+    }
+
+    enum Role {
+        ADMIN
+        USER
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    let scalar_fields: Vec<&str> = user_model.scalar_fields().map(|f| f.name.as_str()).collect();
+    let relation_fields: Vec<&str> = user_model.relation_fields().map(|f| f.name.as_str()).collect();
+
+    assert_eq!(scalar_fields, vec!["id", "name", "role"]);
+    assert_eq!(relation_fields, vec!["posts", "profile"]);
+    assert_eq!(scalar_fields.len() + relation_fields.len(), user_model.fields().count());
+}
+
+#[test]
+fn validate_str_must_accept_a_valid_datamodel() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    assert!(datamodel::validate_str(dml).is_ok());
+}
+
+#[test]
+fn validate_str_must_return_the_errors_of_an_invalid_datamodel() {
+    let dml = r#"
+    model User {
+        id Int @id
+        profile Profile
+    }
+    "#;
+
+    let errors = datamodel::validate_str(dml).unwrap_err();
+
+    errors.assert_is(ValidationError::new_type_not_found_error("Profile", Span::new(53, 60)));
+}
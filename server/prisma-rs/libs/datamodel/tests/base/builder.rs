@@ -0,0 +1,51 @@
+use crate::common::*;
+use datamodel::{common::PrismaType, dml};
+
+fn id_info() -> Option<dml::IdInfo> {
+    Some(dml::IdInfo {
+        strategy: dml::IdStrategy::Auto,
+        sequence: None,
+    })
+}
+
+#[test]
+fn build_and_validate_a_relation_programmatically() {
+    let mut user = dml::Model::new("User");
+    let user_id = user.add_scalar_field("id", PrismaType::Int, dml::FieldArity::Required);
+    user_id.id_info = id_info();
+    user.add_relation_field("posts", "Post", dml::FieldArity::List);
+
+    let mut post = dml::Model::new("Post");
+    let post_id = post.add_scalar_field("id", PrismaType::Int, dml::FieldArity::Required);
+    post_id.id_info = id_info();
+    post.add_scalar_field("title", PrismaType::String, dml::FieldArity::Optional);
+    post.add_relation_field("author", "User", dml::FieldArity::Required);
+
+    let mut datamodel = dml::Datamodel::empty();
+    datamodel.add_model(user);
+    datamodel.add_model(post);
+    let role = dml::Enum::new("Role", vec![String::from("USER"), String::from("ADMIN")]);
+    datamodel.add_enum(role);
+
+    let schema = datamodel::validate(&datamodel).unwrap();
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("author")
+        .assert_relation_to("User")
+        .assert_relation_name("PostToUser")
+        .assert_relation_to_fields(&["id"])
+        .assert_arity(&dml::FieldArity::Required);
+    schema
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_to("Post")
+        .assert_relation_name("PostToUser")
+        .assert_arity(&dml::FieldArity::List);
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("title")
+        .assert_base_type(&PrismaType::String)
+        .assert_arity(&dml::FieldArity::Optional);
+    schema.assert_has_enum("Role").assert_has_value("ADMIN");
+}
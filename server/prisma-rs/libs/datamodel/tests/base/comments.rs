@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::common::PrismaType;
+use datamodel::{ast::Span, common::PrismaType, errors::ValidationError};
 
 #[test]
 fn parse_comments_without_crasing_or_loosing_info() {
@@ -39,3 +39,138 @@ fn accept_a_comment_at_the_end() {
     user_model.assert_is_embedded(false);
     user_model.assert_has_field("id").assert_base_type(&PrismaType::Int);
 }
+
+#[test]
+fn parse_documentation_comments_of_models_fields_and_enums() {
+    let dml = r#"
+    /// The user model.
+    model User {
+        id Int @id
+        /// The first name.
+        /// It may contain spaces.
+        firstName String
+        lastName String
+    }
+
+    /// All the roles.
+    enum Role {
+        ADMIN
+        USER
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_with_documentation("The user model.");
+    user_model
+        .assert_has_field("firstName")
+        .assert_with_documentation("The first name.\nIt may contain spaces.");
+    assert_eq!(user_model.assert_has_field("lastName").documentation, None);
+    assert_eq!(
+        schema.assert_has_enum("Role").documentation,
+        Some(String::from("All the roles."))
+    );
+}
+
+#[test]
+fn parse_empty_documentation_lines() {
+    let dml = "/// First paragraph.\n///\n/// Second paragraph.\r\nmodel User {\n  id Int @id\n}\n";
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("User")
+        .assert_with_documentation("First paragraph.\n\nSecond paragraph.");
+}
+
+#[test]
+fn comments_in_any_position_must_not_change_the_datamodel() {
+    let dml_without_comments = r#"
+    model User {
+        id Int @id
+        name String @default("Bob")
+        posts Post[]
+        @@unique([name])
+    }
+
+    model Post {
+        id Int @id
+        author User @relation(references: [id])
+    }
+
+    enum Role {
+        ADMIN
+        USER
+    }
+    "#;
+
+    let dml_with_comments = r#"
+    // Before the first declaration.
+    model User { // After the opening brace.
+        // Before the first field.
+        id Int @id // After a directive.
+        // Between fields.
+        name String @default("Bob") // After a directive with arguments.
+        posts Post[] // After a list type.
+        @@unique([name]) // After a model directive.
+        // Before the closing brace.
+    } // After the closing brace.
+    // Between declarations.
+
+    model Post {
+        id Int @id
+        author User @relation(references: [id]) // After a relation.
+    }
+
+    // Before an enum.
+    enum Role { // After the opening brace of an enum.
+        ADMIN // After an enum value.
+        // Between enum values.
+        USER
+    }
+    // At the end of the file, without a trailing newline."#;
+
+    assert_eq!(parse(dml_with_comments), parse(dml_without_comments));
+}
+
+#[test]
+fn comments_between_documentation_and_declarations_must_be_ignored() {
+    let dml_without_comments = r#"
+    /// The user model.
+    model User {
+        /// The id.
+        id Int @id
+    }
+    "#;
+
+    let dml_with_comments = r#"
+    /// The user model.
+    // Not part of the documentation.
+    model User {
+        /// The id.
+        // Not part of the documentation either.
+        id Int @id
+    }
+    "#;
+
+    assert_eq!(parse(dml_with_comments), parse(dml_without_comments));
+}
+
+#[test]
+fn comments_must_not_shift_the_spans_of_errors() {
+    let dml = r#"
+    // A comment before the model.
+    model User { // A comment after the opening brace.
+        id Int @id // A comment after the id.
+        // A comment between fields.
+        age Int @default("one") // A comment after the faulty default.
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a numeric value, but received string value \"one\".",
+        "default",
+        Span::new(199, 204),
+    ));
+}
@@ -0,0 +1,159 @@
+use crate::common::*;
+use datamodel::{DatamodelChange, FieldChange, FieldType, ScalarType};
+
+#[test]
+fn diffing_a_datamodel_with_itself_must_be_empty() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert!(schema.diff(&schema).is_empty());
+}
+
+#[test]
+fn diffing_must_report_added_fields() {
+    let previous = parse(
+        r#"
+    model User {
+        id Int @id
+    }
+    "#,
+    );
+    let next = parse(
+        r#"
+    model User {
+        id Int @id
+        name String
+    }
+    "#,
+    );
+
+    let diff = previous.diff(&next);
+
+    assert_eq!(
+        diff.changes,
+        vec![DatamodelChange::FieldAdded {
+            model: "User".to_string(),
+            field: "name".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn diffing_must_report_field_type_changes() {
+    let previous = parse(
+        r#"
+    model User {
+        id Int @id
+        age Int
+    }
+    "#,
+    );
+    let next = parse(
+        r#"
+    model User {
+        id Int @id
+        age Float
+    }
+    "#,
+    );
+
+    let diff = previous.diff(&next);
+
+    assert_eq!(
+        diff.changes,
+        vec![DatamodelChange::FieldChanged {
+            model: "User".to_string(),
+            field: "age".to_string(),
+            changes: vec![FieldChange::Type {
+                previous: FieldType::Base(ScalarType::Int),
+                next: FieldType::Base(ScalarType::Float),
+            }],
+        }]
+    );
+}
+
+#[test]
+fn diffing_must_report_added_enum_values() {
+    let previous = parse(
+        r#"
+    enum Role {
+        USER
+        ADMIN
+    }
+    "#,
+    );
+    let next = parse(
+        r#"
+    enum Role {
+        USER
+        ADMIN
+        OWNER
+    }
+    "#,
+    );
+
+    let diff = previous.diff(&next);
+
+    assert_eq!(
+        diff.changes,
+        vec![DatamodelChange::EnumValuesChanged {
+            enm: "Role".to_string(),
+            added: vec!["OWNER".to_string()],
+            removed: vec![],
+        }]
+    );
+}
+
+#[test]
+fn diffing_must_report_added_models_and_relations() {
+    let previous = parse(
+        r#"
+    model User {
+        id Int @id
+    }
+    "#,
+    );
+    let next = parse(
+        r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#,
+    );
+
+    let diff = previous.diff(&next);
+
+    assert_eq!(
+        diff.changes,
+        vec![
+            DatamodelChange::FieldAdded {
+                model: "User".to_string(),
+                field: "posts".to_string(),
+            },
+            DatamodelChange::ModelAdded {
+                model: "Post".to_string(),
+            },
+            DatamodelChange::RelationAdded {
+                name: "PostToUser".to_string(),
+                models: ("Post".to_string(), "User".to_string()),
+            },
+        ]
+    );
+}
@@ -119,6 +119,42 @@ fn fail_on_duplicate_enum_value() {
     ));
 }
 
+#[test]
+fn fail_on_duplicate_enum_value_among_others() {
+    let dml = r#"
+    enum Role {
+        Admin
+        Moderator
+        Admin
+        Guest
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_duplicate_enum_value_error(
+        "Role",
+        "Admin",
+        Span::new(57, 62),
+    ));
+}
+
+#[test]
+fn fail_on_empty_enum() {
+    let dml = r#"
+    enum Role {
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_enum_validation_error(
+        "An enum must have at least one value",
+        "Role",
+        Span::new(5, 22),
+    ));
+}
+
 #[test]
 fn fail_on_reserved_name_for_enum() {
     let dml = r#"
@@ -0,0 +1,44 @@
+use datamodel::{common::PrismaType, dml};
+
+fn relation_info() -> dml::RelationInfo {
+    dml::RelationInfo::new("User")
+}
+
+#[test]
+fn is_relation_must_only_hold_for_relation_fields() {
+    assert!(dml::FieldType::Relation(relation_info()).is_relation());
+    assert!(!dml::FieldType::Base(PrismaType::Int).is_relation());
+    assert!(!dml::FieldType::Enum("Role".to_string()).is_relation());
+    assert!(!dml::FieldType::Unsupported("cidr".to_string()).is_relation());
+}
+
+#[test]
+fn is_scalar_must_hold_for_base_and_connector_specific_types() {
+    assert!(dml::FieldType::Base(PrismaType::String).is_scalar());
+    assert!(dml::FieldType::ConnectorSpecific {
+        base_type: PrismaType::String,
+        connector_type: Some("citext".to_string()),
+    }
+    .is_scalar());
+    assert!(!dml::FieldType::Enum("Role".to_string()).is_scalar());
+    assert!(!dml::FieldType::Relation(relation_info()).is_scalar());
+    assert!(!dml::FieldType::Unsupported("cidr".to_string()).is_scalar());
+}
+
+#[test]
+fn is_enum_must_only_hold_for_enum_fields() {
+    assert!(dml::FieldType::Enum("Role".to_string()).is_enum());
+    assert!(!dml::FieldType::Base(PrismaType::String).is_enum());
+    assert!(!dml::FieldType::Relation(relation_info()).is_enum());
+    assert!(!dml::FieldType::Unsupported("cidr".to_string()).is_enum());
+}
+
+#[test]
+fn as_relation_must_return_the_relation_info_of_relation_fields() {
+    assert_eq!(
+        dml::FieldType::Relation(relation_info()).as_relation(),
+        Some(&relation_info())
+    );
+    assert_eq!(dml::FieldType::Base(PrismaType::Int).as_relation(), None);
+    assert_eq!(dml::FieldType::Enum("Role".to_string()).as_relation(), None);
+}
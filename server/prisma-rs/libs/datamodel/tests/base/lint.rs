@@ -0,0 +1,211 @@
+use datamodel::{ast::Span, errors::DatamodelWarning};
+
+#[test]
+fn lint_must_be_silent_on_a_conforming_datamodel() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        posts Post[]
+        role Role
+        bestFriend User? @relation(name: "BestFriends")
+        bestFriendOf User? @relation("BestFriends")
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+
+    enum Role {
+        ADMIN
+        SUPER_USER2
+    }
+    "#;
+
+    assert_eq!(datamodel::lint(dml).unwrap(), vec![]);
+}
+
+#[test]
+fn lint_must_warn_about_model_names_not_in_pascal_case() {
+    let dml = r#"
+    model user_account {
+        id Int @id
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![DatamodelWarning::new(
+            "The model name `user_account` should be written in PascalCase.",
+            Span::new(11, 23)
+        )]
+    );
+}
+
+#[test]
+fn lint_must_warn_about_field_names_not_in_camel_case() {
+    let dml = r#"
+    model User {
+        id Int @id
+        FirstName String
+        last_name String
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![
+            DatamodelWarning::new(
+                "The field name `FirstName` on model `User` should be written in camelCase.",
+                Span::new(45, 54)
+            ),
+            DatamodelWarning::new(
+                "The field name `last_name` on model `User` should be written in camelCase.",
+                Span::new(70, 79)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn lint_must_warn_about_enum_values_not_in_screaming_case() {
+    let dml = r#"
+    model User {
+        id Int @id
+        role Role
+    }
+
+    enum Role {
+        ADMIN
+        SuperUser
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![DatamodelWarning::new(
+            "The value `SuperUser` of enum `Role` should be written in SCREAMING_CASE.",
+            Span::new(100, 109)
+        )]
+    );
+}
+
+#[test]
+fn lint_must_warn_about_unnamed_relations_between_models_with_multiple_relations() {
+    let dml = r#"
+    model User {
+        id Int @id
+        friend User?
+        friendOf User?
+    }
+    "#;
+
+    let warnings = datamodel::lint(dml).unwrap();
+    let messages: Vec<String> = warnings.iter().map(|w| w.description()).collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "The relation field `friend` on model `User` has no explicit relation name, but there are other relation fields between the same models. Name the relation with `@relation(name: \"...\")` to keep it unambiguous.",
+            "The relation field `friendOf` on model `User` has no explicit relation name, but there are other relation fields between the same models. Name the relation with `@relation(name: \"...\")` to keep it unambiguous.",
+        ]
+    );
+}
+
+#[test]
+fn lint_must_warn_about_embedded_models_that_are_not_referenced() {
+    let dml = r#"
+    model Todo {
+        id Int @id
+    }
+
+    model Comment {
+        id Int @id
+
+        @@embedded
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![DatamodelWarning::new(
+            "The embedded model `Comment` is not reachable from any model which is not embedded, so it is never stored.",
+            Span::new(54, 61)
+        )]
+    );
+}
+
+#[test]
+fn lint_must_accept_embedded_models_referenced_by_embedded_models() {
+    let dml = r#"
+    model Todo {
+        id Int @id
+        comment Comment
+    }
+
+    model Comment {
+        id Int @id
+        author Author @relation(references: [id])
+
+        @@embedded
+    }
+
+    model Author {
+        id Int @id
+
+        @@embedded
+    }
+    "#;
+
+    assert_eq!(datamodel::lint(dml).unwrap(), vec![]);
+}
+
+#[test]
+fn lint_must_warn_about_embedded_models_that_only_reference_each_other() {
+    let dml = r#"
+    model Todo {
+        id Int @id
+    }
+
+    model Comment {
+        id Int @id
+        author Author @relation(references: [id])
+
+        @@embedded
+    }
+
+    model Author {
+        id Int @id
+
+        @@embedded
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![
+            DatamodelWarning::new(
+                "The embedded model `Comment` is not reachable from any model which is not embedded, so it is never stored.",
+                Span::new(54, 61)
+            ),
+            DatamodelWarning::new(
+                "The embedded model `Author` is not reachable from any model which is not embedded, so it is never stored.",
+                Span::new(170, 176)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn lint_must_not_affect_validation() {
+    let dml = r#"
+    model user_account {
+        id Int @id
+        Last_Name String
+    }
+    "#;
+
+    assert!(datamodel::parse(dml).is_ok());
+    assert_eq!(datamodel::lint(dml).unwrap().len(), 2);
+}
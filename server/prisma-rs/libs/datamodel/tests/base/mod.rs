@@ -1,5 +1,11 @@
 pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
+pub mod builder;
 pub mod comments;
+pub mod diff;
 pub mod duplicates;
+pub mod field_type_predicates;
+pub mod relation_model;
+pub mod lint;
+pub mod validate_model;
@@ -0,0 +1,116 @@
+use crate::common::*;
+
+#[test]
+fn a_model_with_only_two_required_relations_is_a_relation_model() {
+    let dml = r#"
+    model Post {
+        id Int @id
+    }
+
+    model Category {
+        id Int @id
+    }
+
+    model PostToCategory {
+        post Post
+        category Category
+    }
+    "#;
+
+    let schema = parse(dml);
+    let relation_model = schema.assert_has_model("PostToCategory");
+    assert!(relation_model.is_relation_model());
+    assert!(relation_model.is_pure_relation_model());
+}
+
+#[test]
+fn a_join_model_with_a_composite_key_is_a_relation_model() {
+    let dml = r#"
+    model Post {
+        id Int @id
+    }
+
+    model Category {
+        id Int @id
+    }
+
+    model PostToCategory {
+        postId Int
+        categoryId Int
+        post Post @relation(fields: [postId], references: [id])
+        category Category @relation(fields: [categoryId], references: [id])
+
+        @@id([postId, categoryId])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let relation_model = schema.assert_has_model("PostToCategory");
+    assert!(relation_model.is_relation_model());
+    assert!(!relation_model.is_pure_relation_model());
+}
+
+#[test]
+fn a_normal_model_is_not_a_relation_model() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    let schema = parse(dml);
+    assert!(!schema.assert_has_model("User").is_relation_model());
+    assert!(!schema.assert_has_model("Post").is_relation_model());
+}
+
+#[test]
+fn a_join_model_with_extra_scalar_fields_is_not_a_relation_model() {
+    let dml = r#"
+    model Post {
+        id Int @id
+    }
+
+    model Category {
+        id Int @id
+    }
+
+    model PostToCategory {
+        id Int @id
+        post Post
+        category Category
+        createdAt DateTime
+    }
+    "#;
+
+    let schema = parse(dml);
+    assert!(!schema.assert_has_model("PostToCategory").is_relation_model());
+}
+
+#[test]
+fn a_model_with_two_optional_relations_is_not_a_relation_model() {
+    let dml = r#"
+    model Post {
+        id Int @id
+    }
+
+    model Category {
+        id Int @id
+    }
+
+    model Link {
+        id Int @id
+        post Post?
+        category Category?
+    }
+    "#;
+
+    let schema = parse(dml);
+    assert!(!schema.assert_has_model("Link").is_relation_model());
+}
@@ -0,0 +1,46 @@
+use crate::common::*;
+use datamodel::{ast::Span, dml::validator::*, errors::ValidationError};
+
+const DATAMODEL: &str = r#"
+    model Valid {
+        id Int @id
+    }
+
+    model Invalid {
+        id String
+    }
+    "#;
+
+#[test]
+fn validating_a_single_model_must_report_the_same_error_as_the_full_validation() {
+    let ast = datamodel::parse_to_ast(DATAMODEL).unwrap();
+    let schema = LiftAstToDml::new().lift(&ast).unwrap();
+
+    let full_errors = parse_error(DATAMODEL);
+    let model_errors = Validator::new().validate_model(&ast, &schema, "Invalid").unwrap_err();
+
+    model_errors.assert_is(ValidationError::new_model_validation_error(
+        "Exactly one field must be marked as the id field with the `@id` directive.",
+        "Invalid",
+        Span::new(49, 88),
+    ));
+    assert_eq!(model_errors.errors, full_errors.errors);
+}
+
+#[test]
+fn validating_a_single_valid_model_must_ignore_the_other_models() {
+    let ast = datamodel::parse_to_ast(DATAMODEL).unwrap();
+    let schema = LiftAstToDml::new().lift(&ast).unwrap();
+
+    assert!(Validator::new().validate_model(&ast, &schema, "Valid").is_ok());
+}
+
+#[test]
+fn validating_an_unknown_model_must_fail() {
+    let ast = datamodel::parse_to_ast(DATAMODEL).unwrap();
+    let schema = LiftAstToDml::new().lift(&ast).unwrap();
+
+    let errors = Validator::new().validate_model(&ast, &schema, "Missing").unwrap_err();
+
+    errors.assert_is(ValidationError::new_type_not_found_error("Missing", Span::empty()));
+}
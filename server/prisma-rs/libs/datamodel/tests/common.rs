@@ -8,7 +8,10 @@ pub trait FieldAsserts {
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
     fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_update_strategy(&self, t: dml::OnUpdateStrategy) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
+    fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
+    fn assert_relation_fk_name(&self, t: Option<&str>) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
@@ -93,6 +96,26 @@ impl FieldAsserts for dml::Field {
         return self;
     }
 
+    fn assert_relation_update_strategy(&self, t: dml::OnUpdateStrategy) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.on_update, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        return self;
+    }
+
+    fn assert_relation_fk_name(&self, t: Option<&str>) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.fk_name.as_ref().map(String::as_str), t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        return self;
+    }
+
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.to_fields, t);
@@ -103,6 +126,16 @@ impl FieldAsserts for dml::Field {
         return self;
     }
 
+    fn assert_relation_base_fields(&self, t: &[&str]) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.fields, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        return self;
+    }
+
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self {
         assert_eq!(self.arity, *arity);
 
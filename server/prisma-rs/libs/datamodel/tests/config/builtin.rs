@@ -152,6 +152,48 @@ fn enable_disable_source_flag_from_env() {
     assert_eq!(source.url().value, "file:../db/staging.db");
 }
 
+#[test]
+fn source_urls_must_resolve_env_vars() {
+    std::env::set_var("SOURCE_URL_FROM_ENV", "postgresql://localhost/from_env");
+
+    let config = datamodel::load_configuration(
+        r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("SOURCE_URL_FROM_ENV")
+        }
+    "#,
+    )
+    .unwrap();
+
+    let url = config.datasources[0].url();
+    assert_eq!(url.value, "postgresql://localhost/from_env");
+    assert_eq!(url.from_env_var, Some("SOURCE_URL_FROM_ENV".to_string()));
+}
+
+#[test]
+fn source_urls_must_fail_on_unset_env_vars() {
+    std::env::remove_var("SOURCE_URL_NOT_SET");
+
+    let res = datamodel::load_configuration(
+        r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("SOURCE_URL_NOT_SET")
+        }
+    "#,
+    );
+
+    if let Err(error) = res {
+        error.assert_is(ValidationError::new_environment_functional_evaluation_error(
+            "SOURCE_URL_NOT_SET",
+            datamodel::ast::Span::new(83, 103),
+        ));
+    } else {
+        panic!("Expected error.")
+    }
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
@@ -0,0 +1,151 @@
+use crate::common::*;
+use datamodel::{ast::Span, errors::ValidationError, ConnectorCapabilities};
+
+fn with_source(provider: &str, models: &str) -> String {
+    format!(
+        r#"
+    datasource db {{
+        provider = "{}"
+        url = "file:dev.db"
+    }}
+    {}"#,
+        provider, models
+    )
+}
+
+const ARRAY_MODEL: &str = r#"
+    model User {
+        id Int @id
+        tags String[] @scalarList(strategy: EMBEDDED)
+    }
+    "#;
+
+const SCHEMA_MODEL: &str = r#"
+    model AuditEntry {
+        id Int @id
+
+        @@schema("audit")
+    }
+    "#;
+
+#[test]
+fn sources_must_expose_the_capabilities_of_their_connector() {
+    let config = datamodel::load_configuration(&with_source("mysql", "")).unwrap();
+    let capabilities = config.datasources[0].capabilities();
+
+    assert_eq!(capabilities, ConnectorCapabilities::for_connector_type("mysql"));
+    assert!(!capabilities.scalar_list_arrays);
+    assert!(!capabilities.multiple_schemas);
+    assert!(capabilities.fulltext_indexes);
+    assert!(!capabilities.check_constraints);
+}
+
+#[test]
+fn scalar_list_arrays_must_work_on_postgres() {
+    let schema = parse(&with_source("postgresql", ARRAY_MODEL));
+
+    schema.assert_has_model("User").assert_has_field("tags");
+}
+
+#[test]
+fn scalar_list_arrays_must_error_on_mysql() {
+    let errors = parse_error(&with_source("mysql", ARRAY_MODEL));
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Scalar lists stored as arrays are not supported by the `mysql` connector.",
+        "scalarList",
+        Span::new(146, 176),
+    ));
+}
+
+#[test]
+fn schemas_must_work_on_postgres() {
+    let schema = parse(&with_source("postgresql", SCHEMA_MODEL));
+
+    assert_eq!(
+        schema.assert_has_model("AuditEntry").schema,
+        Some(String::from("audit"))
+    );
+}
+
+#[test]
+fn schemas_must_error_on_sqlite() {
+    let errors = parse_error(&with_source("sqlite", SCHEMA_MODEL));
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Schemas are not supported by the `sqlite` connector.",
+        "schema",
+        Span::new(141, 156),
+    ));
+}
+
+#[test]
+fn scalar_list_arrays_must_be_validated_against_the_given_capabilities() {
+    let postgres = ConnectorCapabilities::for_connector_type("postgresql");
+    let mysql = ConnectorCapabilities::for_connector_type("mysql");
+
+    assert!(datamodel::validate_with_capabilities(ARRAY_MODEL, "postgresql", postgres).is_ok());
+
+    let errors = datamodel::validate_with_capabilities(ARRAY_MODEL, "mysql", mysql).unwrap_err();
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Scalar lists stored as arrays are not supported by the `mysql` connector.",
+        "scalarList",
+        Span::new(60, 90),
+    ));
+}
+
+#[test]
+fn schemas_must_be_validated_against_the_given_capabilities() {
+    let sqlite = ConnectorCapabilities::for_connector_type("sqlite");
+
+    let errors = datamodel::validate_with_capabilities(SCHEMA_MODEL, "sqlite", sqlite).unwrap_err();
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Schemas are not supported by the `sqlite` connector.",
+        "schema",
+        Span::new(54, 69),
+    ));
+}
+
+#[test]
+fn fulltext_indexes_must_be_validated_against_the_given_capabilities() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        title String
+
+        @@fulltext([title])
+    }
+    "#;
+    let mysql = ConnectorCapabilities::for_connector_type("mysql");
+    let sqlite = ConnectorCapabilities::for_connector_type("sqlite");
+
+    assert!(datamodel::validate_with_capabilities(dml, "mysql", mysql).is_ok());
+
+    let errors = datamodel::validate_with_capabilities(dml, "sqlite", sqlite).unwrap_err();
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fulltext indexes are not supported by the `sqlite` connector.",
+        "fulltext",
+        Span::new(69, 86),
+    ));
+}
+
+#[test]
+fn check_constraints_must_error_on_mysql() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @check("age >= 0")
+    }
+    "#;
+
+    let errors = parse_error(&with_source("mysql", dml));
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Check constraints are not supported by the `mysql` connector.",
+        "check",
+        Span::new(140, 157),
+    ));
+
+    let schema = parse(&with_source("sqlite", dml));
+    assert!(schema.assert_has_model("User").assert_has_field("age").check.is_some());
+}
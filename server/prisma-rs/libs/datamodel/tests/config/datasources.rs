@@ -0,0 +1,103 @@
+use crate::common::*;
+use datamodel::{ast::Span, errors::ValidationError};
+
+const TWO_SOURCES: &str = r#"
+    datasource main {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432/main"
+    }
+
+    datasource analytics {
+        provider = "mysql"
+        url = "mysql://localhost:3306/analytics"
+    }
+"#;
+
+#[test]
+fn models_must_be_associated_with_their_datasource() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id Int @id
+    }}
+
+    model PageView {{
+        id Int @id
+
+        @@datasource("analytics")
+    }}
+    "#,
+        TWO_SOURCES
+    );
+
+    let schema = parse(&dml);
+    let config = datamodel::load_configuration(&dml).unwrap();
+
+    let user = schema.assert_has_model("User");
+    let page_view = schema.assert_has_model("PageView");
+    assert_eq!(user.datasource, None);
+    assert_eq!(page_view.datasource, Some(String::from("analytics")));
+
+    // Models without `@@datasource` are stored in the first datasource.
+    assert_eq!(
+        config.datasource_for_model(user).map(|s| s.name().as_str()),
+        Some("main")
+    );
+    assert_eq!(
+        config.datasource_for_model(page_view).map(|s| s.connector_type()),
+        Some("mysql")
+    );
+
+    let rendered = datamodel::render(&schema).unwrap();
+    assert!(rendered.contains(r#"@@datasource("analytics")"#));
+}
+
+#[test]
+fn should_fail_on_undefined_datasources() {
+    let dml = format!(
+        r#"{}
+    model PageView {{
+        id Int @id
+
+        @@datasource("reporting")
+    }}
+    "#,
+        TWO_SOURCES
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The datasource `reporting` is not defined.",
+        "datasource",
+        Span::new(272, 295),
+    ));
+}
+
+#[test]
+fn should_fail_on_relations_between_datasources() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id Int @id
+        pageViews PageView[]
+    }}
+
+    model PageView {{
+        id Int @id
+        user User
+
+        @@datasource("analytics")
+    }}
+    "#,
+        TWO_SOURCES
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The relation field `user` relates models of different datasources. Model `PageView` is stored in `analytics`, model `User` in `main`.",
+        "PageView",
+        Span::new(341, 350),
+    ));
+}
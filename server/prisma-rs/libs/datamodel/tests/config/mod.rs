@@ -1,3 +1,5 @@
 pub mod builtin;
+pub mod capabilities;
+pub mod datasources;
 pub mod generators;
 pub mod source_plugin;
@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::{ast::Span, errors::ValidationError};
+use datamodel::{ast::Span, common::PrismaValue, errors::ValidationError};
 
 #[test]
 fn fail_on_duplicate_directive() {
@@ -48,3 +48,66 @@ fn fail_on_extra_argument() {
 
     errors.assert_is(ValidationError::new_unused_argument_error("unused", Span::new(81, 98)));
 }
+
+#[test]
+fn fail_on_numeric_value_for_string_argument() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String @map(name: 123)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a String value, but received numeric value \"123\".",
+        "map",
+        Span::new(73, 76),
+    ));
+}
+
+#[test]
+fn fail_on_numeric_value_for_string_argument_of_model_directive() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+
+        @@unique([firstName], name: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a String value, but received numeric value \"1\".",
+        "unique",
+        Span::new(99, 100),
+    ));
+}
+
+#[test]
+fn parse_negative_numeric_default_values() {
+    let dml = r#"
+    model Counter {
+        id Int @id
+        count Int @default(-1)
+        ratio Float @default(-0.5)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let counter_model = datamodel.assert_has_model("Counter");
+    counter_model
+        .assert_has_field("count")
+        .assert_default_value(PrismaValue::Int(-1));
+    counter_model
+        .assert_has_field("ratio")
+        .assert_default_value(PrismaValue::Float(-0.5));
+
+    let rendered = datamodel::render(&datamodel).unwrap();
+    assert!(rendered.contains("@default(-1)"));
+    assert!(rendered.contains("@default(-0.5)"));
+    assert_eq!(parse(&rendered), datamodel);
+}
@@ -0,0 +1,96 @@
+use crate::common::*;
+use datamodel::{ast::Span, errors::ValidationError};
+
+#[test]
+fn should_fail_on_unique_list_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        emails String[] @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fields that are marked as unique can not be lists.",
+        "unique",
+        Span::new(62, 68),
+    ));
+}
+
+#[test]
+fn should_fail_on_id_list_field() {
+    let dml = r#"
+    model User {
+        ids Int[] @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fields that are marked as id must be required.",
+        "id",
+        Span::new(37, 39),
+    ));
+}
+
+#[test]
+fn should_fail_on_updated_at_list_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        seenAt DateTime[] @updatedAt
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fields that are marked with @updatedAt can not be lists.",
+        "updatedAt",
+        Span::new(64, 73),
+    ));
+}
+
+#[test]
+fn should_fail_on_inline_relation_on_the_list_side() {
+    let dml = r#"
+    model User {
+        id Int @id
+        postIds Int[]
+        posts Post[] @relation(fields: [postIds])
+    }
+
+    model Post {
+        id Int @id
+        user User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The relation field `posts` is a list and can therefore not hold the foreign key. Declare `fields` on the other side of the relation.",
+        "relation",
+        Span::new(81, 108),
+    ));
+}
+
+#[test]
+fn should_fail_on_optional_list_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        emails String[]?
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_parser_error(
+        &vec!["End of block (\"}\")", "field declaration"],
+        Span::new(60, 60),
+    ));
+}
@@ -29,6 +29,61 @@ fn db_directive() {
     post_model.assert_has_field("text").assert_with_db_name("post_text");
 }
 
+#[test]
+fn db_directive_should_error_on_empty_names() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String @map("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The database name given in `map` cannot be empty.",
+        "map",
+        ast::Span::new(67, 69),
+    ));
+}
+
+#[test]
+fn db_directive_should_error_on_the_value_of_named_arguments() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String @map(name: "")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The database name given in `map` cannot be empty.",
+        "map",
+        ast::Span::new(73, 75),
+    ));
+}
+
+#[test]
+fn db_directive_should_error_on_names_with_whitespace() {
+    let dml = r#"
+    model User {
+        id Int @id
+
+        @@map(name: "has spaces")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The database name `has spaces` is not a valid identifier. It must not contain whitespace or quotes.",
+        "map",
+        ast::Span::new(58, 70),
+    ));
+}
+
 #[test]
 fn unique_directive() {
     let dml = r#"
@@ -72,3 +127,27 @@ fn duplicate_directives_should_error() {
         ValidationError::new_duplicate_directive_error("unique", ast::Span::new(83, 89)),
     );
 }
+
+#[test]
+fn schema_directive() {
+    let dml = r#"
+    model AuditEntry {
+        id Int @id
+        message String
+
+        @@schema("audit")
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert_eq!(
+        schema.assert_has_model("AuditEntry").schema,
+        Some(String::from("audit"))
+    );
+    assert_eq!(schema.assert_has_model("User").schema, None);
+}
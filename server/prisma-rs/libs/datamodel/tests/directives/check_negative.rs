@@ -0,0 +1,61 @@
+use crate::common::*;
+use datamodel::{ast::Span, errors::ValidationError};
+
+#[test]
+fn should_fail_if_the_expression_is_empty() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @check("  ")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The expression of a check constraint must not be empty.",
+        "check",
+        Span::new(60, 64),
+    ));
+}
+
+#[test]
+fn should_fail_if_the_expression_is_not_a_string() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @check(1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a String value, but received numeric value \"1\".",
+        "check",
+        Span::new(60, 61),
+    ));
+}
+
+#[test]
+fn should_fail_on_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[] @check("posts > 0")
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Relation fields cannot have a check constraint.",
+        "check",
+        Span::new(59, 77),
+    ));
+}
@@ -0,0 +1,32 @@
+use crate::common::*;
+
+#[test]
+fn should_apply_check_directive() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @check("age >= 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    assert_eq!(user_model.assert_has_field("age").check, Some(String::from("age >= 0")));
+    assert_eq!(user_model.assert_has_field("id").check, None);
+}
+
+#[test]
+fn should_render_check_directive() {
+    let dml = r#"
+    model User {
+        id Int @id
+        age Int @check("age >= 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render(&schema).unwrap();
+
+    assert!(rendered.contains(r#"age Int @check("age >= 0")"#));
+    assert_eq!(parse(&rendered), schema);
+}
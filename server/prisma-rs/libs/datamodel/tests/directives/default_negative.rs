@@ -24,8 +24,49 @@ fn should_error_if_default_value_for_related() {
 }
 
 #[test]
-fn should_error_if_default_value_for_list() {
+fn should_error_if_default_value_for_list_is_not_an_array() {
     let dml = r#"
+    model Model {
+        id Int @id
+        rel String[] @default("hello")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The default value of a list field must be an array literal, e.g. `@default([...])`.",
+        "default",
+        Span::new(68, 75),
+    ));
+}
+
+#[test]
+fn should_error_if_default_value_for_list_has_wrong_element_type() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        rel Int[] @default([1, "two"])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a numeric value, but received string value \"two\".",
+        "default",
+        Span::new(69, 74),
+    ));
+}
+
+#[test]
+fn should_error_if_default_value_for_list_on_side_table_connector() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
     model Model {
         id Int @id
         rel String[] @default(["hello"])
@@ -35,9 +76,32 @@ fn should_error_if_default_value_for_list() {
     let errors = parse_error(dml);
 
     errors.assert_is(ValidationError::new_directive_validation_error(
-        "Cannot set a default value on list field.",
+        "The list field `rel` cannot have a default value, because the `sqlite` connector stores scalar lists in a separate table.",
         "default",
-        Span::new(60, 78),
+        Span::new(151, 160),
+    ));
+}
+
+#[test]
+fn should_error_if_default_value_for_list_is_not_stored_as_an_array() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+    }
+
+    model Model {
+        id Int @id
+        rel String[] @default(["hello"])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The list field `rel` cannot have a default value, because it is stored in a separate table. Only lists stored as an array (`@scalarList(strategy: EMBEDDED)`) support default values.",
+        "default",
+        Span::new(166, 175),
     ));
 }
 
@@ -76,3 +140,67 @@ fn should_error_if_default_value_parser_error() {
         Span::new(68, 74),
     ));
 }
+
+#[test]
+fn should_error_if_a_datetime_default_is_not_a_full_timestamp() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        createdAt DateTime @default("2020-01-01")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a datetime value, but failed while parsing \"2020-01-01\": premature end of input.",
+        "default",
+        Span::new(74, 86),
+    ));
+}
+
+#[test]
+fn should_error_if_enum_default_value_is_not_an_enum_value() {
+    let dml = r#"
+    model User {
+        id Int @id
+        role Role @default(NOPE)
+    }
+
+    enum Role {
+        ADMIN
+        USER
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The default value `NOPE` is not a value of enum `Role`.",
+        "default",
+        Span::new(64, 68),
+    ));
+}
+
+#[test]
+fn should_error_on_the_element_if_an_enum_list_default_value_is_not_an_enum_value() {
+    let dml = r#"
+    model User {
+        id Int @id
+        roles Role[] @default([USER, NOPE]) @scalarList(strategy: EMBEDDED)
+    }
+
+    enum Role {
+        ADMIN
+        USER
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The default value `NOPE` is not a value of enum `Role`.",
+        "default",
+        Span::new(74, 78),
+    ));
+}
@@ -68,3 +68,28 @@ fn should_set_default_an_enum_type() {
         .assert_enum_type("Role")
         .assert_default_value(PrismaValue::ConstantLiteral(String::from("A_VARIANT_WITH_UNDERSCORES")));
 }
+
+#[test]
+fn should_set_array_default_for_scalar_lists() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        roles String[] @default(["user", "admin"]) @scalarList(strategy: EMBEDDED)
+        numbers Int[] @default([]) @scalarList(strategy: EMBEDDED)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("roles")
+        .assert_base_type(&PrismaType::String)
+        .assert_default_value(PrismaValue::List(vec![
+            PrismaValue::String(String::from("user")),
+            PrismaValue::String(String::from("admin")),
+        ]));
+    user_model
+        .assert_has_field("numbers")
+        .assert_base_type(&PrismaType::Int)
+        .assert_default_value(PrismaValue::List(vec![]));
+}
@@ -75,7 +75,7 @@ fn id_should_error_multiple_ids_are_provided() {
 }
 
 const ID_TYPE_ERROR: &str =
-    "Invalid ID field. ID field must be one of: Int @id, String @id @default(cuid()), String @id @default(uuid()).";
+    "Invalid ID field. ID field must be one of: Int @id, BigInt @id, Int @id @default(auto()), BigInt @id @default(auto()), String @id @default(cuid()), String @id @default(uuid()), String @id @default(auto()).";
 
 #[test]
 fn id_should_error_if_the_id_field_is_not_of_valid_type() {
@@ -153,3 +153,225 @@ fn id_should_error_if_string_id_field_has_incorrect_default_value() {
         ValidationError::new_model_validation_error(ID_TYPE_ERROR, "Model3", Span::new(142, 172)),
     );
 }
+
+#[test]
+fn id_should_error_if_field_level_and_model_level_id_are_both_present() {
+    let dml = r#"
+    model Model {
+        a Int @id
+        b String
+
+        @@id([a, b])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "A model must be identified either by one field marked with `@id` or by one `@@id` directive, but not both. The field(s) `a` are marked with `@id` while `@@id([a, b])` is declared as well.",
+        "Model",
+        Span::new(5, 81),
+    ));
+}
+
+#[test]
+fn id_should_error_if_model_level_id_is_declared_twice() {
+    let dml = r#"
+    model Model {
+        a String
+        b String
+
+        @@id([a, b])
+        @@id([b])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_at(
+        0,
+        ValidationError::new_duplicate_directive_error("id", Span::new(64, 66)),
+    );
+    errors.assert_is_at(
+        1,
+        ValidationError::new_duplicate_directive_error("id", Span::new(85, 87)),
+    );
+}
+
+#[test]
+fn id_should_error_if_model_level_id_references_unknown_field() {
+    let dml = r#"
+    model Model {
+        a String
+
+        @@id([a, b])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The id field `b` does not exist on model `Model`.",
+        "Model",
+        Span::new(5, 63),
+    ));
+}
+
+#[test]
+fn id_should_error_if_model_level_id_references_an_optional_field() {
+    let dml = r#"
+    model Model {
+        a String
+        b String?
+
+        @@id([a, b])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The field `b` cannot be part of the `@@id` of model `Model`. Only required scalar fields can identify a model.",
+        "Model",
+        Span::new(72, 73),
+    ));
+}
+
+#[test]
+fn id_should_error_if_model_level_id_references_a_relation_field() {
+    let dml = r#"
+    model Post {
+        author User
+        slug String
+
+        @@id([author, slug])
+    }
+
+    model User {
+        id Int @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The field `author` cannot be part of the `@@id` of model `Post`. Only required scalar fields can identify a model.",
+        "Post",
+        Span::new(73, 79),
+    ));
+}
+
+#[test]
+fn id_errors_in_multiple_models_must_be_returned_in_source_order() {
+    let dml = r#"
+    model Post {
+        title String
+    }
+
+    model Comment {
+        text String
+    }
+
+    model User {
+        name String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert_eq!(errors.errors.len(), 3);
+    errors.assert_is_at(
+        0,
+        ValidationError::new_model_validation_error(
+            "Exactly one field must be marked as the id field with the `@id` directive.",
+            "Post",
+            Span::new(5, 44),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        ValidationError::new_model_validation_error(
+            "Exactly one field must be marked as the id field with the `@id` directive.",
+            "Comment",
+            Span::new(50, 91),
+        ),
+    );
+    errors.assert_is_at(
+        2,
+        ValidationError::new_model_validation_error(
+            "Exactly one field must be marked as the id field with the `@id` directive.",
+            "User",
+            Span::new(97, 135),
+        ),
+    );
+}
+
+#[test]
+fn auto_default_should_error_on_fields_that_are_not_ids() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        counter Int @default(auto())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The `auto()` default can only be used on id fields.",
+        "default",
+        Span::new(67, 73),
+    ));
+}
+
+#[test]
+fn auto_default_should_error_on_unsupported_types() {
+    let dml = r#"
+    model Model {
+        id Float @id @default(auto())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a Float value, but received Int value \"Function<Int>\".",
+        "default",
+        Span::new(49, 55),
+    ));
+}
+
+#[test]
+fn id_should_error_if_a_sequence_is_set_without_the_sequence_strategy() {
+    let dml = r#"
+    model Model {
+        id Int @id(sequence: "model_id_seq")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "A sequence can only be set for the `SEQUENCE` id strategy.",
+        "id",
+        Span::new(48, 62),
+    ));
+}
+
+#[test]
+fn id_should_error_if_the_sequence_strategy_is_used_on_a_non_int_field() {
+    let dml = r#"
+    model Model {
+        id String @id(strategy: SEQUENCE)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The `SEQUENCE` id strategy is only supported on fields of type Int.",
+        "id",
+        Span::new(38, 60),
+    ));
+}
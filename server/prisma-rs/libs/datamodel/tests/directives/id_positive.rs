@@ -21,6 +21,24 @@ fn id_without_strategy_should_use_defaults() {
         .assert_id_strategy(IdStrategy::Auto);
 }
 
+#[test]
+fn big_int_ids_without_strategy_should_use_defaults() {
+    let dml = r#"
+    model Model {
+        id BigInt @id
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("id")
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_is_id(true)
+        .assert_id_sequence(None)
+        .assert_id_strategy(IdStrategy::Auto);
+}
+
 #[test]
 fn id_with_explicit_auto_strategy() {
     let dml = r#"
@@ -55,6 +73,36 @@ fn id_with_explicit_none_strategy() {
         .assert_id_strategy(IdStrategy::None);
 }
 
+#[test]
+fn id_with_explicit_sequence_strategy() {
+    let dml = r#"
+    model Model {
+        id Int @id(strategy: SEQUENCE, sequence: "model_id_seq")
+    }
+
+    model Other {
+        id Int @id(strategy: SEQUENCE)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    datamodel
+        .assert_has_model("Model")
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_id_sequence(Some(Sequence {
+            name: String::from("model_id_seq"),
+            initial_value: 1,
+            allocation_size: 1,
+        }))
+        .assert_id_strategy(IdStrategy::Sequence);
+    datamodel
+        .assert_has_model("Other")
+        .assert_has_field("id")
+        .assert_id_sequence(None)
+        .assert_id_strategy(IdStrategy::Sequence);
+}
+
 #[test]
 fn id_should_also_work_on_embedded_types() {
     let dml = r#"
@@ -107,3 +155,50 @@ fn should_allow_string_ids_with_uuid() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(Value::Expression(String::from("uuid"), ScalarType::String, Vec::new()));
 }
+
+#[test]
+fn model_level_id_should_set_the_compound_id_fields() {
+    let dml = r#"
+    model Model {
+        a String
+        b Int
+
+        @@id([a, b])
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model.assert_has_field("a").assert_is_id(false);
+    assert_eq!(
+        user_model.compound_id_fields,
+        vec![String::from("a"), String::from("b")]
+    );
+}
+
+#[test]
+fn id_with_auto_default_should_use_the_external_strategy() {
+    let dml = r#"
+    model IntModel {
+        id Int @id @default(auto())
+    }
+
+    model StringModel {
+        id String @default(auto()) @id
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    datamodel
+        .assert_has_model("IntModel")
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_default_value(Value::Expression(String::from("auto"), ScalarType::Int, Vec::new()))
+        .assert_id_strategy(IdStrategy::External);
+    datamodel
+        .assert_has_model("StringModel")
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_default_value(Value::Expression(String::from("auto"), ScalarType::String, Vec::new()))
+        .assert_id_strategy(IdStrategy::External);
+}
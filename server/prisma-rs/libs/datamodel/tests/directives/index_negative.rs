@@ -0,0 +1,229 @@
+use crate::common::*;
+use datamodel::{ast::Span, errors::ValidationError};
+
+#[test]
+fn should_fail_on_duplicate_index_names() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        lastName String
+
+        @@unique([firstName, lastName], name: "my_index")
+        @@index([lastName], name: "my_index")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The index name `my_index` is declared multiple times.",
+        "index",
+        Span::new(155, 190),
+    ));
+}
+
+#[test]
+fn should_fail_on_duplicate_index_names_across_models() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String
+
+        @@index([name], name: "name_index")
+    }
+
+    model Post {
+        id Int @id
+        name String
+
+        @@index([name], name: "name_index")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The index name `name_index` is declared multiple times.",
+        "index",
+        Span::new(176, 209),
+    ));
+}
+
+#[test]
+fn should_fail_on_index_with_unknown_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        name String
+
+        @@unique([name, email])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The index field `email` does not exist on model `User`.",
+        "unique",
+        Span::new(68, 89),
+    ));
+}
+
+#[test]
+fn should_fail_on_empty_index_predicate() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String
+
+        @@index([email], where: "  ")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The `where` predicate of a partial index must not be empty.",
+        "index",
+        Span::new(91, 95),
+    ));
+}
+
+#[test]
+fn should_fail_on_index_predicate_that_is_not_a_string() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String
+
+        @@index([email], where: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Expected a String value, but received numeric value \"1\".",
+        "index",
+        Span::new(91, 92),
+    ));
+}
+
+#[test]
+fn should_fail_on_unique_with_predicate() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String
+
+        @@unique([email], where: "email IS NOT NULL")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_unused_argument_error("where", Span::new(85, 111)));
+}
+
+#[test]
+fn should_fail_on_fulltext_index_on_non_string_field() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The field `views` cannot be part of a fulltext index, because it is not of type String.",
+        "fulltext",
+        Span::new(87, 111),
+    ));
+}
+
+#[test]
+fn should_fail_on_fulltext_index_on_sqlite() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model Post {
+        id Int @id
+        title String
+
+        @@fulltext([title])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fulltext indexes are not supported by the `sqlite` connector.",
+        "fulltext",
+        Span::new(152, 169),
+    ));
+}
+
+#[test]
+fn should_fail_on_unknown_index_sort_order() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        createdAt DateTime
+
+        @@index([createdAt(sort: Down)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_literal_parser_error(
+        "sort order",
+        "Down",
+        Span::new(98, 102),
+    ));
+}
+
+#[test]
+fn should_fail_on_unknown_arguments_of_index_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        createdAt DateTime
+
+        @@index([createdAt(order: Desc)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_unused_argument_error("order", Span::new(92, 103)));
+}
+
+#[test]
+fn should_fail_on_sort_orders_outside_of_normal_indexes() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        createdAt DateTime
+
+        @@unique([createdAt(sort: Desc)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "A sort order can only be declared on the fields of an `@@index`.",
+        "unique",
+        Span::new(83, 104),
+    ));
+}
@@ -0,0 +1,186 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn should_apply_named_unique_and_index_directives() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        lastName String
+        email String
+
+        @@unique([firstName, lastName], name: "uq_first_last")
+        @@index([email], name: "idx_email")
+        @@index([lastName])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    assert_eq!(
+        user_model.indices,
+        vec![
+            dml::IndexDefinition {
+                name: Some(String::from("uq_first_last")),
+                fields: vec![String::from("firstName"), String::from("lastName")],
+                tpe: dml::IndexType::Unique,
+                predicate: None,
+                sort_orders: vec![],
+            },
+            dml::IndexDefinition {
+                name: Some(String::from("idx_email")),
+                fields: vec![String::from("email")],
+                tpe: dml::IndexType::Normal,
+                predicate: None,
+                sort_orders: vec![],
+            },
+            dml::IndexDefinition {
+                name: None,
+                fields: vec![String::from("lastName")],
+                tpe: dml::IndexType::Normal,
+                predicate: None,
+                sort_orders: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_render_named_indexes() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        lastName String
+
+        @@unique([firstName, lastName], name: "uq_first_last")
+        @@index([lastName], name: "idx_last")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render(&schema).unwrap();
+    let reparsed = parse(&rendered);
+
+    let user_model = schema.assert_has_model("User");
+    let reparsed_user_model = reparsed.assert_has_model("User");
+
+    assert_eq!(reparsed_user_model.indices.len(), 2);
+    assert_eq!(
+        reparsed_user_model.find_index("uq_first_last"),
+        user_model.find_index("uq_first_last")
+    );
+    assert_eq!(
+        reparsed_user_model.find_index("idx_last"),
+        user_model.find_index("idx_last")
+    );
+}
+
+#[test]
+fn should_apply_index_predicates() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String
+        deleted Boolean
+
+        @@index([email], name: "idx_active_email", where: "deleted = false")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    assert_eq!(
+        user_model.indices,
+        vec![dml::IndexDefinition {
+            name: Some(String::from("idx_active_email")),
+            fields: vec![String::from("email")],
+            tpe: dml::IndexType::Normal,
+            predicate: Some(String::from("deleted = false")),
+            sort_orders: vec![],
+        }]
+    );
+
+    let rendered = datamodel::render(&schema).unwrap();
+    let reparsed = parse(&rendered);
+
+    assert_eq!(reparsed.assert_has_model("User").indices, user_model.indices);
+}
+
+#[test]
+fn should_apply_and_render_fulltext_indexes() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        title String
+        body String
+
+        @@fulltext([title, body], name: "post_search")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+
+    assert_eq!(
+        post_model.indices,
+        vec![dml::IndexDefinition {
+            name: Some(String::from("post_search")),
+            fields: vec![String::from("title"), String::from("body")],
+            tpe: dml::IndexType::Fulltext,
+            predicate: None,
+            sort_orders: vec![],
+        }]
+    );
+
+    let rendered = datamodel::render(&schema).unwrap();
+    let reparsed = parse(&rendered);
+
+    assert_eq!(reparsed.assert_has_model("Post").indices, post_model.indices);
+}
+
+#[test]
+fn should_apply_and_render_index_sort_orders() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        title String
+        createdAt DateTime
+
+        @@index([title, createdAt(sort: Desc)])
+        @@index([createdAt(sort: Asc)], name: "idx_created_at")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+
+    assert_eq!(
+        post_model.indices,
+        vec![
+            dml::IndexDefinition {
+                name: None,
+                fields: vec![String::from("title"), String::from("createdAt")],
+                tpe: dml::IndexType::Normal,
+                predicate: None,
+                sort_orders: vec![dml::SortOrder::Asc, dml::SortOrder::Desc],
+            },
+            dml::IndexDefinition {
+                name: Some(String::from("idx_created_at")),
+                fields: vec![String::from("createdAt")],
+                tpe: dml::IndexType::Normal,
+                predicate: None,
+                sort_orders: vec![],
+            }
+        ]
+    );
+
+    let rendered = datamodel::render(&schema).unwrap();
+    let reparsed = parse(&rendered);
+
+    assert!(rendered.contains("@@index([title, createdAt(sort: Desc)])"));
+    assert_eq!(reparsed.assert_has_model("Post").indices, post_model.indices);
+}
@@ -1,13 +1,19 @@
+pub mod arity_negative;
 pub mod builtin_directives;
+pub mod check_negative;
+pub mod check_positive;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
 pub mod id_positive;
+pub mod index_negative;
+pub mod index_positive;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_positive;
+pub mod unknown;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
 
@@ -106,3 +106,33 @@ fn fail_if_ambigous_relation_fields_do_not_specify_a_name() {
         ValidationError::new_model_validation_error("Ambiguous relation detected.", "Todo", Span::new(41, 59)),
     );
 }
+
+#[test]
+fn fail_if_relation_of_embedded_type_has_no_opposite_field() {
+    let dml = r#"
+    model Todo {
+      id Int @id
+      comments Comment[]
+    }
+
+    model User {
+      id Int @id
+    }
+
+    model Comment {
+      id Int @id
+      text String
+      author User
+
+      @@embedded
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The relation field `author` of the embedded model `Comment` has no opposite relation field on model `User`. Use `@relation(references: [...])` to reference the related model from an embedded model.",
+        "Comment",
+        Span::new(169, 180),
+    ));
+}
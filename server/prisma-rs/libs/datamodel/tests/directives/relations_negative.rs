@@ -127,12 +127,35 @@ fn should_fail_on_conflicting_back_relation_field_name() {
     let errors = parse_error(dml);
 
     errors.assert_is(ValidationError::new_model_validation_error(
-        "Automatic opposite related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
+        "Automatic opposite related field generation would cause a naming conflict with the field `user` of model `Post`. Please add an explicit opposite relation field or rename the conflicting field.",
         "User",
         Span::new(90, 107),
     ));
 }
 
+#[test]
+fn should_fail_on_back_relation_field_name_taken_by_a_scalar_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        post Post
+    }
+
+    model Post {
+        post_id Int @id
+        user String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "Automatic opposite related field generation would cause a naming conflict with the field `user` of model `Post`. Please add an explicit opposite relation field or rename the conflicting field.",
+        "User",
+        Span::new(45, 54),
+    ));
+}
+
 #[test]
 #[ignore]
 // This case is caught by the requirement that named relations
@@ -155,7 +178,7 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
     let errors = parse_error(dml);
 
     errors.assert_is_at(0, ValidationError::new_model_validation_error(
-        "Automatic opposite related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
+        "Automatic opposite related field generation would cause a naming conflict with the field `todo` of model `Owner`. Please add an explicit opposite relation field or rename the conflicting field.",
         "Todo",
         Span::new(98, 152),
     ));
@@ -186,3 +209,361 @@ fn should_fail_on_named_generated_back_relation_fields() {
         ),
     );
 }
+
+#[test]
+fn should_fail_if_foreign_key_field_does_not_exist() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The field `userId` does not exist on model `Post`.",
+        "relation",
+        Span::new(138, 144),
+    ));
+}
+
+#[test]
+fn should_fail_if_referenced_field_does_not_exist() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(references: [doesNotExist])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The referenced field `doesNotExist` does not exist on model `User`.",
+        "relation",
+        Span::new(142, 154),
+    ));
+}
+
+#[test]
+fn should_fail_if_number_of_fields_does_not_match_the_referenced_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        userId Int
+        userName String
+        user User @relation(fields: [userId, userName], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The number of fields must match the number of referenced fields.",
+        "relation",
+        Span::new(180, 198),
+    ));
+}
+
+#[test]
+fn should_fail_if_foreign_key_field_type_does_not_match() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        userId String
+        user User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The type of the field `userId` does not match the type of the referenced field `User.id`.",
+        "relation",
+        Span::new(160, 166),
+    ));
+}
+
+#[test]
+fn should_fail_if_composite_foreign_key_does_not_reference_a_unique() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        lastName String
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        authorFirstName String
+        authorLastName String
+        author User @relation(fields: [authorFirstName, authorLastName], references: [firstName, lastName])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The referenced fields `firstName, lastName` must be the `@@id` or a `@@unique` of model `User`.",
+        "relation",
+        Span::new(296, 317),
+    ));
+}
+
+#[test]
+fn should_fail_on_set_null_for_required_relation() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onDelete: SET_NULL)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The onDelete strategy SET_NULL requires the relation field `user` and its foreign key fields to be optional.",
+        "relation",
+        Span::new(120, 148),
+    ));
+}
+
+#[test]
+fn should_fail_on_set_default_without_default_value() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        userId Int
+        user User @relation(fields: [userId], references: [id], onDelete: SET_DEFAULT)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The onDelete strategy SET_DEFAULT requires the foreign key fields of the relation field `user` to be declared explicitly and to have a default value.",
+        "relation",
+        Span::new(139, 206),
+    ));
+}
+
+#[test]
+fn should_fail_on_update_set_null_for_required_relation() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onUpdate: SET_NULL)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The onUpdate strategy SET_NULL requires the relation field `user` and its foreign key fields to be optional.",
+        "relation",
+        Span::new(120, 148),
+    ));
+}
+
+#[test]
+fn should_fail_on_unknown_on_update_strategy() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onUpdate: SOMETIMES)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_literal_parser_error(
+        "onUpdate strategy",
+        "SOMETIMES",
+        Span::new(139, 148),
+    ));
+}
+
+#[test]
+fn should_fail_on_empty_foreign_key_name() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(map: "")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The name of a foreign key cannot be empty.",
+        "relation",
+        Span::new(134, 136),
+    ));
+}
+
+#[test]
+fn should_fail_on_duplicate_foreign_key_names_in_a_model() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[] @relation("Author")
+        reviewedPosts Post[] @relation("Reviewer")
+    }
+
+    model Post {
+        id Int @id
+        author User @relation("Author", map: "fk_post_user")
+        reviewer User @relation("Reviewer", map: "fk_post_user")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The foreign key name `fk_post_user` is used more than once in model `Post`.",
+        "relation",
+        Span::new(256, 297),
+    ));
+}
+
+#[test]
+fn should_fail_on_references_on_the_list_side_of_a_one_to_many_relation() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[] @relation(references: [id])
+    }
+
+    model Post {
+        id Int @id
+        user User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The relation field `posts` on model `User` is a list and can therefore not declare `references`. The foreign key of this one-to-many relation must be declared on `Post.user`.",
+        "relation",
+        Span::new(59, 85),
+    ));
+}
+
+#[test]
+fn should_fail_on_references_in_a_many_to_many_relation() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        categories Category[] @relation(references: [id])
+    }
+
+    model Category {
+        id Int @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The relation field `categories` on model `Post` can not declare `references`. Both sides of the relation are lists, so it is a many-to-many relation which is stored in a separate relation table.",
+        "relation",
+        Span::new(68, 94),
+    ));
+}
+
+#[test]
+fn should_fail_if_both_sides_of_a_one_to_one_relation_declare_the_foreign_key() {
+    let dml = r#"
+    model User {
+        id Int @id
+        profile Profile @relation(references: [id])
+    }
+
+    model Profile {
+        id Int @id
+        user User? @relation(references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The relation fields `user` on model `Profile` and `profile` on model `User` both declare the foreign key of the relation. The foreign key of a one-to-one relation can only be declared on one side.",
+        "relation",
+        Span::new(155, 181),
+    ));
+}
+
+#[test]
+fn should_fail_on_implicit_references_to_a_compound_id() {
+    let dml = r#"
+    model User {
+        firstName String
+        lastName String
+        posts Post[]
+
+        @@id([firstName, lastName])
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_model_validation_error(
+        "The relation field `author` references multiple fields of model `User`, but a foreign key on a single column cannot hold them. Please specify the fields and the referenced fields explicitly with `@relation(fields: [...], references: [...])`.",
+        "Post",
+        Span::new(176, 187),
+    ));
+}
@@ -67,3 +67,184 @@ fn allow_complicated_self_relations() {
     user_model.assert_has_field("husband").assert_relation_to("User");
     user_model.assert_has_field("wife").assert_relation_to("User");
 }
+
+#[test]
+fn allow_explicit_foreign_key_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        userId Int
+        user User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("user")
+        .assert_relation_to("User")
+        .assert_relation_base_fields(&["userId"])
+        .assert_relation_to_fields(&["id"]);
+}
+
+#[test]
+fn allow_composite_foreign_keys_referencing_a_compound_unique() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String
+        lastName String
+        posts Post[]
+
+        @@unique([firstName, lastName])
+    }
+
+    model Post {
+        id Int @id
+        authorFirstName String
+        authorLastName String
+        author User @relation(fields: [authorFirstName, authorLastName], references: [firstName, lastName])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("author")
+        .assert_relation_to("User")
+        .assert_relation_base_fields(&["authorFirstName", "authorLastName"])
+        .assert_relation_to_fields(&["firstName", "lastName"]);
+}
+
+#[test]
+fn allow_all_on_delete_strategies() {
+    let dml = r#"
+    model User {
+        id Int @id
+        cascadePosts Post[] @relation("Cascade")
+        setNullPosts Post[] @relation("SetNull")
+        setDefaultPosts Post[] @relation("SetDefault")
+        restrictPosts Post[] @relation("Restrict")
+    }
+
+    model Post {
+        id Int @id
+        cascadeUser User @relation("Cascade", onDelete: CASCADE)
+        setNullUser User? @relation("SetNull", onDelete: SET_NULL)
+        setDefaultUserId Int @default(1)
+        setDefaultUser User @relation("SetDefault", fields: [setDefaultUserId], references: [id], onDelete: SET_DEFAULT)
+        restrictUser User @relation("Restrict", onDelete: RESTRICT)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("cascadeUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
+    post_model
+        .assert_has_field("setNullUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::SetNull);
+    post_model
+        .assert_has_field("setDefaultUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::SetDefault);
+    post_model
+        .assert_has_field("restrictUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Restrict);
+}
+
+#[test]
+fn on_delete_strategies_must_default_based_on_the_arity() {
+    let dml = r#"
+    model User {
+        id Int @id
+        requiredPosts Post[] @relation("Required")
+        optionalPosts Post[] @relation("Optional")
+        restrictedPosts Post[] @relation("Restricted")
+    }
+
+    model Post {
+        id Int @id
+        requiredUser User @relation("Required")
+        optionalUser User? @relation("Optional")
+        restrictedUser User @relation("Restricted", onDelete: RESTRICT)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    let on_delete = |name: &str| post_model.find_field(name).unwrap().on_delete_strategy();
+
+    assert_eq!(on_delete("requiredUser"), Some(dml::OnDeleteStrategy::Cascade));
+    assert_eq!(on_delete("optionalUser"), Some(dml::OnDeleteStrategy::SetNull));
+    assert_eq!(on_delete("restrictedUser"), Some(dml::OnDeleteStrategy::Restrict));
+    assert_eq!(on_delete("id"), None);
+
+    // The inferred strategy is not part of the datamodel itself.
+    post_model
+        .assert_has_field("requiredUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::None);
+}
+
+#[test]
+fn allow_on_update_strategies() {
+    let dml = r#"
+    model User {
+        id Int @id
+        cascadePosts Post[] @relation("Cascade")
+        setNullPosts Post[] @relation("SetNull")
+        defaultPosts Post[] @relation("Default")
+    }
+
+    model Post {
+        id Int @id
+        cascadeUser User @relation("Cascade", onDelete: CASCADE, onUpdate: CASCADE)
+        setNullUser User? @relation("SetNull", onUpdate: SET_NULL)
+        defaultUser User @relation("Default")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("cascadeUser")
+        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade)
+        .assert_relation_update_strategy(dml::OnUpdateStrategy::Cascade);
+    post_model
+        .assert_has_field("setNullUser")
+        .assert_relation_update_strategy(dml::OnUpdateStrategy::SetNull);
+    post_model
+        .assert_has_field("defaultUser")
+        .assert_relation_update_strategy(dml::OnUpdateStrategy::None);
+}
+
+#[test]
+fn allow_naming_the_foreign_key() {
+    let dml = r#"
+    model Blog {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        blog Blog @relation(references: [id], map: "fk_post_blog")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("blog")
+        .assert_relation_to("Blog")
+        .assert_relation_fk_name(Some("fk_post_blog"));
+    schema
+        .assert_has_model("Blog")
+        .assert_has_field("posts")
+        .assert_relation_fk_name(None);
+}
@@ -0,0 +1,85 @@
+use crate::common::*;
+use datamodel::{
+    ast::Span,
+    dml::UnknownDirective,
+    errors::{DatamodelWarning, ValidationError},
+};
+
+#[test]
+fn unknown_field_directives_must_survive_a_round_trip() {
+    let dml = r#"model User {
+  id    Int    @id
+  email String @unique @myTool.mail(strict: true, fields: [a, b])
+}"#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    let email_field = user_model.assert_has_field("email").assert_is_unique(true);
+
+    assert_eq!(
+        email_field.unknown_directives,
+        vec![UnknownDirective {
+            name: String::from("myTool.mail"),
+            arguments: vec![
+                (String::from("strict"), String::from("true")),
+                (String::from("fields"), String::from("[a, b]")),
+            ],
+        }]
+    );
+
+    let rendered = datamodel::render(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn unknown_field_directives_must_produce_a_warning() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String @myTool.mail
+    }
+    "#;
+
+    assert_eq!(
+        datamodel::lint(dml).unwrap(),
+        vec![DatamodelWarning::new(
+            "The directive `@myTool.mail` on field `email` of model `User` is not known. It is kept, but has no effect.",
+            Span::new(59, 70),
+        )]
+    );
+}
+
+#[test]
+fn unknown_field_directives_without_namespace_must_error() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String @unqiue
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_not_known_error(
+        "unqiue",
+        Span::new(59, 65),
+    ));
+}
+
+#[test]
+fn unknown_field_directives_of_a_known_namespace_must_error() {
+    let dml = r#"
+    model User {
+        id Int @id
+        email String @db.Text
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_not_known_error(
+        "db.Text",
+        Span::new(59, 66),
+    ));
+}
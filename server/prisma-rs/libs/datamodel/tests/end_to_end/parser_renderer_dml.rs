@@ -13,7 +13,7 @@ const DATAMODEL_STRING: &str = r#"model User {
 
 model Profile {
   id   Int    @id
-  user User
+  user User   @relation(map: "fk_profile_user")
   bio  String
 
   @@map("profile")
@@ -130,3 +130,58 @@ fn test_parser_renderer_model_with_comments_via_dml() {
 
     assert_eq!(rendered, DATAMODEL_STRING_WITH_COMMENTS);
 }
+
+const DATAMODEL_STRING_WITH_DOCUMENTED_ENTITIES: &str = r#"/// A blog.
+///
+/// It has many posts.
+model Blog {
+  /// The id of the blog.
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id   Int  @id
+  /// The blog this post belongs to.
+  blog Blog
+  /// The publication status.
+  tag  Tag
+}
+
+/// Tags which can be added to a post.
+enum Tag {
+  DRAFT
+  PUBLISHED
+}"#;
+
+#[test]
+fn test_parser_renderer_documentation_round_trip_via_dml() {
+    let dml = datamodel::parse(DATAMODEL_STRING_WITH_DOCUMENTED_ENTITIES).unwrap();
+    let rendered = datamodel::render(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_STRING_WITH_DOCUMENTED_ENTITIES);
+
+    let reparsed = datamodel::parse(&rendered).unwrap();
+
+    assert_eq!(reparsed, dml);
+}
+
+const DATAMODEL_STRING_WITH_SCHEMA: &str = r#"model AuditEntry {
+  id      Int    @id
+  message String
+
+  @@map("audit_entries")
+  @@schema("audit")
+}"#;
+
+#[test]
+fn test_parser_renderer_schema_round_trip_via_dml() {
+    let dml = datamodel::parse(DATAMODEL_STRING_WITH_SCHEMA).unwrap();
+    let rendered = datamodel::render(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_STRING_WITH_SCHEMA);
+}
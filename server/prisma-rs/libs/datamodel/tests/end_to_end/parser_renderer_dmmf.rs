@@ -69,7 +69,7 @@ enum CategoryEnum {
 fn test_dmmf_roundtrip() {
     let dml = datamodel::parse(&DATAMODEL_STRING).unwrap();
     let dmmf = datamodel::dmmf::render_to_dmmf(&dml);
-    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf);
+    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf).unwrap();
     let rendered = datamodel::render(&dml2).unwrap();
 
     println!("{}", rendered);
@@ -87,7 +87,7 @@ const DATAMODEL_STRING_WITH_FUNCTIONS: &str = r#"model User {
 fn test_dmmf_roundtrip_with_functions() {
     let dml = datamodel::parse(&DATAMODEL_STRING_WITH_FUNCTIONS).unwrap();
     let dmmf = datamodel::dmmf::render_to_dmmf(&dml);
-    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf);
+    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf).unwrap();
     let rendered = datamodel::render(&dml2).unwrap();
 
     println!("{}", rendered);
@@ -250,7 +250,7 @@ model Post {
 
 #[test]
 fn should_serialize_dmmf_without_relation_name_correctly() {
-    let dml = datamodel::dmmf::parse_from_dmmf(DMFF_WITHOUT_RELATION_NAME);
+    let dml = datamodel::dmmf::parse_from_dmmf(DMFF_WITHOUT_RELATION_NAME).unwrap();
     let rendered = datamodel::render(&dml).unwrap();
 
     assert_eq!(DML_WITHOUT_RELATION_NAME, rendered);
@@ -263,7 +263,7 @@ fn dmmf_roundtrip(input: &str) -> String {
     let dmmf = datamodel::dmmf::render_to_dmmf(&dml);
     let mcf = datamodel::config_to_mcf_json(&config);
 
-    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf);
+    let dml2 = datamodel::dmmf::parse_from_dmmf(&dmmf).unwrap();
     let config = datamodel::config_from_mcf_json(&mcf);
 
     let rendered = datamodel::render_with_config(&dml2, &config).unwrap();
@@ -272,3 +272,167 @@ fn dmmf_roundtrip(input: &str) -> String {
 
     rendered
 }
+
+const DMMF_WITH_INVALID_LIST_DEFAULT: &str = r#"
+{
+  "enums": [],
+  "models": [
+    {
+      "name": "User",
+      "isEmbedded": false,
+      "dbName": null,
+      "fields": [
+        {
+          "name": "id",
+          "kind": "scalar",
+          "dbName": null,
+          "isList": false,
+          "isRequired": true,
+          "isUnique": false,
+          "isId": true,
+          "type": "Int",
+          "isGenerated": false,
+          "isUpdatedAt": false
+        },
+        {
+          "name": "numbers",
+          "kind": "scalar",
+          "dbName": null,
+          "isList": true,
+          "isRequired": false,
+          "isUnique": false,
+          "isId": false,
+          "type": "Int",
+          "default": [1, "two"],
+          "isGenerated": false,
+          "isUpdatedAt": false
+        }
+      ],
+      "isGenerated": false
+    }
+  ]
+}
+"#;
+
+#[test]
+fn should_return_an_error_for_an_invalid_list_default_in_dmmf() {
+    let error = datamodel::dmmf::parse_from_dmmf(DMMF_WITH_INVALID_LIST_DEFAULT).unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field numbers: Invalid default value \"two\" for a field of type Int."
+    );
+}
+
+const DMMF_WITH_A_DEFAULT: &str = r#"
+{
+  "enums": [],
+  "models": [
+    {
+      "name": "User",
+      "isEmbedded": false,
+      "dbName": null,
+      "fields": [
+        {
+          "name": "id",
+          "kind": "scalar",
+          "dbName": null,
+          "isList": false,
+          "isRequired": true,
+          "isUnique": false,
+          "isId": true,
+          "type": "Int",
+          "isGenerated": false,
+          "isUpdatedAt": false
+        },
+        {
+          "name": "value",
+          "kind": "scalar",
+          "dbName": null,
+          "isList": false,
+          "isRequired": true,
+          "isUnique": false,
+          "isId": false,
+          "type": "$TYPE",
+          "default": $DEFAULT,
+          "isGenerated": false,
+          "isUpdatedAt": false
+        }
+      ],
+      "isGenerated": false
+    }
+  ]
+}
+"#;
+
+fn parse_dmmf_with_a_default(field_type: &str, default: &str) -> Result<datamodel::Datamodel, String> {
+    let dmmf = DMMF_WITH_A_DEFAULT
+        .replace("$TYPE", field_type)
+        .replace("$DEFAULT", default);
+
+    datamodel::dmmf::parse_from_dmmf(&dmmf)
+}
+
+#[test]
+fn should_return_an_error_for_a_big_int_default_out_of_range_in_dmmf() {
+    let error = parse_dmmf_with_a_default("BigInt", "9223372036854775808").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Invalid default value 9223372036854775808 for a field of type BigInt."
+    );
+}
+
+#[test]
+fn should_return_an_error_for_a_float_default_on_an_int_field_in_dmmf() {
+    let error = parse_dmmf_with_a_default("Int", "1.5").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Invalid default value 1.5 for a field of type Int."
+    );
+}
+
+#[test]
+fn should_return_an_error_for_an_int_default_out_of_range_in_dmmf() {
+    let error = parse_dmmf_with_a_default("Int", "2147483648").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Invalid default value 2147483648 for a field of type Int."
+    );
+}
+
+#[test]
+fn should_return_an_error_for_a_date_time_default_which_is_not_rfc3339_in_dmmf() {
+    let error = parse_dmmf_with_a_default("DateTime", "\"2019-06-17\"").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Invalid default value \"2019-06-17\" for a field of type DateTime."
+    );
+}
+
+#[test]
+fn should_return_an_error_for_a_malformed_function_default_in_dmmf() {
+    let error = parse_dmmf_with_a_default("DateTime", "{ \"name\": \"now\" }").unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Invalid default value {\"name\":\"now\"} for a field of type DateTime."
+    );
+}
+
+#[test]
+fn should_return_an_error_for_a_function_default_of_the_wrong_type_in_dmmf() {
+    let error = parse_dmmf_with_a_default(
+        "DateTime",
+        "{ \"name\": \"now\", \"returnType\": \"Int\", \"args\": [] }",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        "Model User, Field value: Type missmatch during deserialization. Expected: DateTime, but got: Int."
+    );
+}
@@ -1,5 +1,9 @@
 use crate::common::*;
-use datamodel::common::{PrismaType, PrismaValue};
+use datamodel::{
+    ast::Span,
+    common::{PrismaType, PrismaValue},
+    errors::ValidationError,
+};
 
 #[test]
 fn correctly_handle_server_side_now_function() {
@@ -72,3 +76,17 @@ fn correctly_handle_server_side_uuid_function() {
             vec![],
         ));
 }
+
+#[test]
+fn fail_on_named_arguments_of_server_side_functions() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(uuid(version: 4))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_unused_argument_error("version", Span::new(73, 83)));
+}
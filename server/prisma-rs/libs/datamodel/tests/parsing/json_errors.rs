@@ -0,0 +1,32 @@
+use crate::common::*;
+
+#[test]
+fn errors_must_serialize_to_json_with_spans() {
+    let dml = r#"
+    model User {
+        id Int @id
+        profile Profile
+        role Role
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert_eq!(
+        errors.to_json(),
+        serde_json::json!([
+            {
+                "message": "Type \"Profile\" is neither a built-in type, nor refers to another model, custom type, or enum.",
+                "start": 53,
+                "end": 60,
+                "kind": "TypeNotFoundError",
+            },
+            {
+                "message": "Type \"Role\" is neither a built-in type, nor refers to another model, custom type, or enum.",
+                "start": 74,
+                "end": 78,
+                "kind": "TypeNotFoundError",
+            },
+        ])
+    );
+}
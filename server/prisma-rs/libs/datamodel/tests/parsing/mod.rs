@@ -1 +1,5 @@
+pub mod json_errors;
 pub mod nice_errors;
+pub mod pretty_errors;
+pub mod reader;
+pub mod span;
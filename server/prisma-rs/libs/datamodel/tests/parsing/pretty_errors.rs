@@ -0,0 +1,32 @@
+use crate::common::*;
+
+#[test]
+fn plain_pretty_printed_errors_must_underline_the_span() {
+    let dml = r#"
+model User {
+    id Int @id
+    profile Profile
+}
+"#;
+
+    let errors = parse_error(dml);
+    let error = errors.to_iter().next().unwrap();
+
+    assert_eq!(
+        error.pretty_print_plain(dml),
+        " --> 4:13\n  |\n4 |     profile Profile\n  |             ^^^^^^^ Type \"Profile\" is neither a built-in type, nor refers to another model, custom type, or enum.\n"
+    );
+}
+
+#[test]
+fn plain_pretty_printed_errors_must_keep_tabs_before_the_span() {
+    let dml = "model User {\n\tid Int @id\n\trole Role\n}\n";
+
+    let errors = parse_error(dml);
+    let error = errors.to_iter().next().unwrap();
+    let rendered = error.pretty_print_plain(dml);
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines[2], "3 | \trole Role");
+    assert!(lines[3].starts_with("  | \t     ^^^^ Type \"Role\""));
+}
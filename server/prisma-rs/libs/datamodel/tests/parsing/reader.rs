@@ -0,0 +1,63 @@
+use std::io::Cursor;
+
+const DATAMODEL: &str = r#"
+datasource pg {
+    provider = "postgres"
+    url = "postgresql://localhost:5432/{db}"
+}
+
+// A comment containing a brace }
+type ID = String @id @default(cuid())
+
+/// The user model.
+model User {
+    id ID
+    email String @unique @default("{}")
+    posts Post[]
+    role Role @default(USER)
+}
+
+model Post {
+    id Int @id
+    author User
+}
+
+enum Role {
+    USER
+    ADMIN
+}
+"#;
+
+#[test]
+fn parse_reader_must_produce_the_same_datamodel_as_parse() {
+    let from_string = datamodel::parse(DATAMODEL).unwrap();
+    let from_reader = datamodel::parse_reader(Cursor::new(DATAMODEL)).unwrap();
+
+    assert_eq!(from_reader, from_string);
+}
+
+#[test]
+fn parse_reader_must_report_validation_errors_with_absolute_spans() {
+    let dml = format!(
+        "{}\nmodel Comment {{\n    id Int @id\n    post Unknown\n}}\n",
+        DATAMODEL
+    );
+
+    let from_string = datamodel::parse(&dml).unwrap_err();
+    let from_reader = datamodel::parse_reader(Cursor::new(&dml)).unwrap_err();
+
+    assert_eq!(from_reader.errors, from_string.errors);
+}
+
+#[test]
+fn parse_reader_must_report_parser_errors_with_absolute_spans() {
+    let dml = format!(
+        "{}\nmodel Comment {{\n    id Int @id\n    post Post?[]\n}}\n",
+        DATAMODEL
+    );
+
+    let from_string = datamodel::parse(&dml).unwrap_err();
+    let from_reader = datamodel::parse_reader(Cursor::new(&dml)).unwrap_err();
+
+    assert_eq!(from_reader.errors, from_string.errors);
+}
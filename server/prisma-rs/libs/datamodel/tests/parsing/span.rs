@@ -0,0 +1,80 @@
+use datamodel::ast::{Span, Top};
+
+#[test]
+fn span_must_contain_its_start_but_not_its_end() {
+    let span = Span::new(10, 20);
+
+    assert!(span.contains(10));
+    assert!(span.contains(15));
+    assert!(span.contains(19));
+    assert!(!span.contains(20));
+    assert!(!span.contains(9));
+}
+
+#[test]
+fn empty_span_must_not_contain_anything() {
+    let span = Span::new(10, 10);
+
+    assert!(!span.contains(10));
+    assert!(!Span::empty().contains(0));
+}
+
+#[test]
+fn merging_overlapping_spans_must_work() {
+    let merged = Span::new(10, 20).merge(&Span::new(15, 30));
+
+    assert_eq!(merged, Span::new(10, 30));
+}
+
+#[test]
+fn merging_non_overlapping_spans_must_cover_the_gap() {
+    let first = Span::new(10, 20);
+    let second = Span::new(40, 50);
+
+    assert_eq!(first.merge(&second), Span::new(10, 50));
+    assert_eq!(second.merge(&first), Span::new(10, 50));
+}
+
+#[test]
+fn merging_a_contained_span_must_not_change_the_span() {
+    let span = Span::new(10, 50);
+
+    assert_eq!(span.merge(&Span::new(20, 30)), span);
+    assert_eq!(span.merge(&span), span);
+}
+
+#[test]
+fn ast_spans_must_line_up_with_the_parsed_datamodel() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        author User
+    }
+    "#;
+
+    let (ast, datamodel) = datamodel::parse_to_ast_and_dml(dml).unwrap();
+
+    for model in datamodel.models() {
+        let ast_model = ast
+            .models
+            .iter()
+            .find_map(|top| match top {
+                Top::Model(ast_model) if ast_model.name.name == model.name => Some(ast_model),
+                _ => None,
+            })
+            .expect("Model not found in the AST.");
+
+        assert!(dml[ast_model.span.start..ast_model.span.end].starts_with(&format!("model {}", model.name)));
+
+        for field in model.fields() {
+            let ast_field = ast_model.fields.iter().find(|f| f.name.name == field.name).unwrap();
+
+            assert!(dml[ast_field.span.start..ast_field.span.end].starts_with(&field.name));
+        }
+    }
+}
@@ -53,3 +53,25 @@ datasource pg {
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_reformat_index_sort_orders() {
+    let input = r#"
+        model Post { id Int @id
+        createdAt DateTime
+        @@index([createdAt(sort:Desc)])
+        }
+    "#;
+
+    let expected = r#"
+model Post {
+  id        Int      @id
+  createdAt DateTime
+  @@index([createdAt(sort: Desc)])
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2);
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use datamodel::{dml, validator::common::value_matches_type};
+
+fn base(scalar_type: dml::ScalarType) -> dml::FieldType {
+    dml::FieldType::Base(scalar_type)
+}
+
+fn now() -> dml::Value {
+    dml::Value::Expression(String::from("now"), dml::ScalarType::DateTime, vec![])
+}
+
+#[test]
+fn int_values_must_only_match_int_fields() {
+    let value = dml::Value::Int(1);
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::Int)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Float)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::String)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Boolean)));
+}
+
+#[test]
+fn float_values_must_only_match_float_fields() {
+    let value = dml::Value::Float(1.5);
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::Float)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Int)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Decimal)));
+}
+
+#[test]
+fn boolean_values_must_only_match_boolean_fields() {
+    let value = dml::Value::Boolean(true);
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::Boolean)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Int)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::String)));
+}
+
+#[test]
+fn string_values_must_only_match_string_fields() {
+    let value = dml::Value::String(String::from("hello"));
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::String)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::Int)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::DateTime)));
+    assert!(!value_matches_type(&value, &dml::FieldType::Enum(String::from("Role"))));
+}
+
+#[test]
+fn string_values_must_match_xml_fields() {
+    let value = dml::Value::String(String::from("<root/>"));
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::Xml)));
+    assert!(!value_matches_type(&dml::Value::Int(1), &base(dml::ScalarType::Xml)));
+}
+
+#[test]
+fn date_time_values_must_only_match_date_time_fields() {
+    let value = dml::Value::DateTime("2019-06-17T14:20:57Z".parse::<DateTime<Utc>>().unwrap());
+
+    assert!(value_matches_type(&value, &base(dml::ScalarType::DateTime)));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::String)));
+}
+
+#[test]
+fn functions_must_match_fields_of_their_return_type() {
+    let cuid = dml::Value::Expression(String::from("cuid"), dml::ScalarType::String, vec![]);
+
+    assert!(value_matches_type(&now(), &base(dml::ScalarType::DateTime)));
+    assert!(!value_matches_type(&now(), &base(dml::ScalarType::Int)));
+    assert!(value_matches_type(&cuid, &base(dml::ScalarType::String)));
+    assert!(!value_matches_type(&cuid, &base(dml::ScalarType::Int)));
+}
+
+#[test]
+fn constant_literals_must_only_match_enum_fields() {
+    let value = dml::Value::ConstantLiteral(String::from("ADMIN"));
+
+    assert!(value_matches_type(&value, &dml::FieldType::Enum(String::from("Role"))));
+    assert!(!value_matches_type(&value, &base(dml::ScalarType::String)));
+}
+
+#[test]
+fn list_values_must_match_if_all_elements_match() {
+    let ints = dml::Value::List(vec![dml::Value::Int(1), dml::Value::Int(2)]);
+    let mixed = dml::Value::List(vec![dml::Value::Int(1), dml::Value::String(String::from("two"))]);
+
+    assert!(value_matches_type(&ints, &base(dml::ScalarType::Int)));
+    assert!(!value_matches_type(&mixed, &base(dml::ScalarType::Int)));
+}
+
+#[test]
+fn connector_specific_fields_must_match_their_base_type() {
+    let field_type = dml::FieldType::ConnectorSpecific {
+        base_type: dml::ScalarType::Int,
+        connector_type: Some(String::from("SmallInt")),
+    };
+
+    assert!(value_matches_type(&dml::Value::Int(1), &field_type));
+    assert!(!value_matches_type(&dml::Value::Boolean(false), &field_type));
+}
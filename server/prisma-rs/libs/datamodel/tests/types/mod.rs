@@ -1,2 +1,5 @@
+pub mod default_values;
+pub mod native_types;
 pub mod negative;
 pub mod positive;
+pub mod unsupported;
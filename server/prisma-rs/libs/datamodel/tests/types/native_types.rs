@@ -0,0 +1,115 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::PrismaType, dml, errors::ValidationError};
+
+#[test]
+fn should_parse_native_types() {
+    let dml = r#"
+    model Event {
+        id Int @id
+        happenedAt DateTime @db.Timestamptz @default(now())
+        recordedAt DateTime
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let model = datamodel.assert_has_model("Event");
+
+    let happened_at = model
+        .assert_has_field("happenedAt")
+        .assert_base_type(&PrismaType::DateTime);
+    assert_eq!(happened_at.native_type, Some(dml::NativeType::Timestamptz));
+
+    let recorded_at = model.assert_has_field("recordedAt");
+    assert_eq!(recorded_at.native_type, None);
+}
+
+#[test]
+fn should_render_native_types() {
+    let input = r#"
+        model Event {
+            id Int @id
+            happenedAt DateTime @db.Timestamptz
+        }
+    "#;
+
+    let expected = r#"model Event {
+  id         Int      @id
+  happenedAt DateTime @db.Timestamptz
+}"#;
+
+    let rendered = datamodel::render(&parse(input)).unwrap();
+
+    assert_eq!(rendered, expected);
+    assert_eq!(parse(&rendered), parse(input));
+}
+
+#[test]
+fn should_fail_on_native_types_for_other_scalar_types() {
+    let dml = r#"
+    model Event {
+        id Int @id
+        name String @db.Timestamptz
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fields that are marked with @db.Timestamptz must be of type DateTime.",
+        "db.Timestamptz",
+        Span::new(59, 73),
+    ));
+}
+
+#[test]
+fn should_parse_uuid_native_types() {
+    let dml = r#"
+    model User {
+        id String @id @default(uuid()) @db.Uuid
+        externalId String? @db.Uuid
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let model = datamodel.assert_has_model("User");
+
+    let id = model.assert_has_field("id").assert_base_type(&PrismaType::String);
+    assert_eq!(id.native_type, Some(dml::NativeType::Uuid));
+
+    let external_id = model.assert_has_field("externalId");
+    assert_eq!(external_id.native_type, Some(dml::NativeType::Uuid));
+}
+
+#[test]
+fn should_fail_on_uuid_native_types_for_non_string_fields() {
+    let dml = r#"
+    model User {
+        id Int @id @db.Uuid
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "Fields that are marked with @db.Uuid must be of type String.",
+        "db.Uuid",
+        Span::new(38, 45),
+    ));
+}
+
+#[test]
+fn should_fail_on_uuid_native_types_with_other_generated_defaults() {
+    let dml = r#"
+    model User {
+        id String @id @default(cuid()) @db.Uuid
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(ValidationError::new_directive_validation_error(
+        "The `cuid()` default cannot be used on fields marked with @db.Uuid. Use `uuid()` instead.",
+        "default",
+        Span::new(49, 55),
+    ));
+}
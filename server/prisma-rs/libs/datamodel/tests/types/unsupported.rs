@@ -0,0 +1,49 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn should_parse_unsupported_types() {
+    let dml = r#"
+    model Host {
+        id Int @id
+        network Unsupported("cidr")
+        search Unsupported("tsvector")?
+        addresses Unsupported("inet")[]
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let model = datamodel.assert_has_model("Host");
+
+    let network = model.assert_has_field("network");
+    assert_eq!(network.field_type, dml::FieldType::Unsupported(String::from("cidr")));
+    assert_eq!(network.arity, dml::FieldArity::Required);
+
+    let search = model.assert_has_field("search");
+    assert_eq!(search.field_type, dml::FieldType::Unsupported(String::from("tsvector")));
+    assert_eq!(search.arity, dml::FieldArity::Optional);
+
+    let addresses = model.assert_has_field("addresses");
+    assert_eq!(addresses.field_type, dml::FieldType::Unsupported(String::from("inet")));
+    assert_eq!(addresses.arity, dml::FieldArity::List);
+}
+
+#[test]
+fn should_render_unsupported_types() {
+    let input = r#"
+        model Host {
+            id Int @id
+            network Unsupported("character varying(16)")?
+        }
+    "#;
+
+    let expected = r#"model Host {
+  id      Int                                   @id
+  network Unsupported("character varying(16)")?
+}"#;
+
+    let rendered = datamodel::render(&parse(input)).unwrap();
+
+    assert_eq!(rendered, expected);
+    assert_eq!(parse(&rendered), parse(input));
+}
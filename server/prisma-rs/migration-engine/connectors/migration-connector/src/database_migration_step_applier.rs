@@ -11,4 +11,7 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync + 'static {
 
     // render steps for the CLI. It will contain the raw field
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<serde_json::Value>;
+
+    // render the steps that roll back the migration for the CLI. It will contain the raw field
+    fn render_rollback_steps_pretty(&self, database_migration: &T) -> ConnectorResult<serde_json::Value>;
 }
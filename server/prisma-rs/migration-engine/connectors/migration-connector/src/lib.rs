@@ -39,6 +39,9 @@ pub trait MigrationConnector: Send + Sync + 'static {
     // this is placed here so i can use the associated type
     fn deserialize_database_migration(&self, json: serde_json::Value) -> Self::DatabaseMigration;
 
+    /// Checks that a database migration applies cleanly, without changing the database.
+    fn verify_migration(&self, database_migration: &Self::DatabaseMigration) -> ConnectorResult<()>;
+
     fn migration_applier(&self) -> Box<dyn MigrationApplier<Self::DatabaseMigration>> {
         let applier = MigrationApplierImpl {
             migration_persistence: self.migration_persistence(),
@@ -15,6 +15,75 @@ pub enum MigrationStep {
     DeleteEnum(DeleteEnum),
 }
 
+/// Whether applying a migration step can lose data that is stored in the database.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+pub enum Destructiveness {
+    Safe,
+    Destructive,
+}
+
+impl MigrationStep {
+    /// Classifies the step, given the datamodel it is applied to.
+    ///
+    /// Dropping models, fields and enum values is destructive, just like adding a required field without a
+    /// default to an existing model or changing the type of a field to one that cannot hold all of its values.
+    pub fn destructiveness(&self, previous: &Datamodel) -> Destructiveness {
+        let is_destructive = match self {
+            MigrationStep::DeleteModel(_) | MigrationStep::DeleteField(_) | MigrationStep::DeleteEnum(_) => true,
+            MigrationStep::CreateField(step) => {
+                previous.find_model(&step.model).is_some()
+                    && step.arity == FieldArity::Required
+                    && step.default.is_none()
+                    && step.id.is_none()
+            }
+            MigrationStep::UpdateField(step) => {
+                match previous.find_model(&step.model).and_then(|m| m.find_field(&step.name)) {
+                    Some(field) => update_field_is_destructive(field, step),
+                    None => false,
+                }
+            }
+            MigrationStep::UpdateEnum(step) => match (previous.find_enum(&step.name), &step.values) {
+                (Some(previous_enum), Some(values)) => previous_enum.values.iter().any(|v| !values.contains(v)),
+                _ => false,
+            },
+            // A table moved to another schema is recreated there.
+            MigrationStep::UpdateModel(step) => step.schema.is_some(),
+            MigrationStep::CreateModel(_) | MigrationStep::CreateEnum(_) => false,
+        };
+
+        if is_destructive {
+            Destructiveness::Destructive
+        } else {
+            Destructiveness::Safe
+        }
+    }
+}
+
+fn update_field_is_destructive(field: &Field, step: &UpdateField) -> bool {
+    let next_arity = step.arity.unwrap_or(field.arity);
+    let next_default = step.default.as_ref().unwrap_or(&field.default_value);
+
+    let changes_list_arity = (field.arity == FieldArity::List) != (next_arity == FieldArity::List);
+    let becomes_required = field.arity == FieldArity::Optional && next_arity == FieldArity::Required;
+    let narrows_type = match &step.tpe {
+        Some(next_type) => next_type != &field.field_type && !is_widening(&field.field_type, next_type),
+        None => false,
+    };
+
+    changes_list_arity || (becomes_required && next_default.is_none()) || narrows_type
+}
+
+/// Type changes which keep all values, e.g. from `Int` to `BigInt`.
+fn is_widening(previous: &FieldType, next: &FieldType) -> bool {
+    match (previous, next) {
+        (FieldType::Base(ScalarType::Int), FieldType::Base(ScalarType::BigInt))
+        | (FieldType::Base(ScalarType::Int), FieldType::Base(ScalarType::Float)) => true,
+        // Enum values are stored as strings.
+        (FieldType::Enum(_), FieldType::Base(ScalarType::String)) => true,
+        _ => false,
+    }
+}
+
 pub trait WithDbName {
     fn db_name(&self) -> String;
 }
@@ -37,6 +106,18 @@ pub struct CreateModel {
     pub db_name: Option<String>,
 
     pub embedded: bool,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indices: Vec<IndexDefinition>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compound_id_fields: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
@@ -52,11 +133,29 @@ pub struct UpdateModel {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub embedded: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indices: Option<Vec<IndexDefinition>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound_id_fields: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
+    pub schema: Option<Option<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
+    pub documentation: Option<Option<String>>,
 }
 
 impl UpdateModel {
     pub fn is_any_option_set(&self) -> bool {
-        self.new_name.is_some() || self.embedded.is_some() || self.db_name.is_some()
+        self.new_name.is_some()
+            || self.embedded.is_some()
+            || self.db_name.is_some()
+            || self.indices.is_some()
+            || self.compound_id_fields.is_some()
+            || self.schema.is_some()
+            || self.documentation.is_some()
     }
 }
 
@@ -97,6 +196,15 @@ pub struct CreateField {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scalar_list: Option<ScalarListStrategy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native_type: Option<NativeType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
 }
 
 impl WithDbName for CreateField {
@@ -144,6 +252,15 @@ pub struct UpdateField {
 
     #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
     pub scalar_list: Option<Option<ScalarListStrategy>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
+    pub native_type: Option<Option<NativeType>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
+    pub check: Option<Option<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "some_option")]
+    pub documentation: Option<Option<String>>,
 }
 
 impl UpdateField {
@@ -158,6 +275,9 @@ impl UpdateField {
             || self.id_info.is_some()
             || self.default.is_some()
             || self.scalar_list.is_some()
+            || self.native_type.is_some()
+            || self.check.is_some()
+            || self.documentation.is_some()
     }
 }
 
@@ -10,6 +10,10 @@ fn minimal_CreateModel_must_work() {
         name: "Blog".to_string(),
         db_name: None,
         embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -21,6 +25,61 @@ fn full_CreateModel_must_work() {
         name: "Blog".to_string(),
         db_name: Some("blog".to_string()),
         embedded: true,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_with_indices_must_work() {
+    let json = r#"{"stepType":"CreateModel","name":"Blog","embedded":false,"indices":[{"name":"idx_title","fields":["title"],"tpe":"Normal"}]}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        name: "Blog".to_string(),
+        db_name: None,
+        embedded: false,
+        indices: vec![IndexDefinition {
+            name: Some("idx_title".to_string()),
+            fields: vec!["title".to_string()],
+            tpe: IndexType::Normal,
+            predicate: None,
+            sort_orders: vec![],
+        }],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_with_schema_must_work() {
+    let json = r#"{"stepType":"CreateModel","name":"Blog","embedded":false,"schema":"audit"}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        name: "Blog".to_string(),
+        db_name: None,
+        embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: Some("audit".to_string()),
+        documentation: None,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_with_documentation_must_work() {
+    let json = r#"{"stepType":"CreateModel","name":"Blog","embedded":false,"documentation":"A blog."}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        name: "Blog".to_string(),
+        db_name: None,
+        embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: Some("A blog.".to_string()),
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -33,6 +92,10 @@ fn minimal_UpdateModel_must_work() {
         new_name: None,
         db_name: None,
         embedded: None,
+        indices: None,
+        compound_id_fields: None,
+        schema: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -45,6 +108,10 @@ fn full_UpdateModel_must_work() {
         new_name: Some("MyBlog".to_string()),
         db_name: Some(Some("blog".to_string())),
         embedded: Some(true),
+        indices: None,
+        compound_id_fields: None,
+        schema: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -74,6 +141,9 @@ fn minimal_CreateField_must_work() {
         id: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -92,7 +162,8 @@ fn full_CreateField_must_work() {
             "isUpdatedAt":true,
             "isUnique": true,
             "default":{"String":"default"},
-            "scalarList": "Embedded"
+            "scalarList": "Embedded",
+            "nativeType": "Timestamptz"
         }"#;
     let expected_struct = MigrationStep::CreateField(CreateField {
         model: "Blog".to_string(),
@@ -106,6 +177,9 @@ fn full_CreateField_must_work() {
         id: None, // TODO: adapt once added to CreateField
         default: Some(Value::String("default".to_string())),
         scalar_list: Some(ScalarListStrategy::Embedded),
+        native_type: Some(NativeType::Timestamptz),
+        check: None,
+        documentation: None,
     });
 
     assert_symmetric_serde(json, expected_struct);
@@ -127,13 +201,16 @@ fn minimal_UpdateField_must_work() {
         id_info: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
 
 #[test]
 fn full_UpdateField_must_work() {
-    let json = r#"{"stepType":"UpdateField","model":"Blog","name":"title","newName":"MyBlog","type":{"Base":"String"},"arity":"optional","dbName":"blog","isCreatedAt":true,"isUpdatedAt":true,"isUnique":true,"default":{"String":"default"},"scalarList":"Embedded"}"#;
+    let json = r#"{"stepType":"UpdateField","model":"Blog","name":"title","newName":"MyBlog","type":{"Base":"String"},"arity":"optional","dbName":"blog","isCreatedAt":true,"isUpdatedAt":true,"isUnique":true,"default":{"String":"default"},"scalarList":"Embedded","nativeType":"Timestamptz"}"#;
     let expected_struct = MigrationStep::UpdateField(UpdateField {
         model: "Blog".to_string(),
         name: "title".to_string(),
@@ -147,6 +224,9 @@ fn full_UpdateField_must_work() {
         id_info: None,
         default: Some(Some(Value::String("default".to_string()))),
         scalar_list: Some(Some(ScalarListStrategy::Embedded)),
+        native_type: Some(Some(NativeType::Timestamptz)),
+        check: None,
+        documentation: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -1,4 +1,5 @@
 use super::*;
+use datamodel::{SortOrder, Value};
 
 pub fn convert_introspected_columns(
     columns: Vec<IntrospectedColumn>,
@@ -15,20 +16,147 @@ pub fn convert_introspected_columns(
                     name: Some(fk.name.clone()),
                     table: fk.referenced_table.clone(),
                     column: fk.referenced_column.clone(),
-                    on_delete: OnDelete::NoAction, // TODO:: fix this hardcoded value
+                    schema: fk.referenced_schema.clone(),
+                    on_delete: fk.on_delete,
+                    on_update: fk.on_update,
+                    deferrable: fk.deferrable,
+                    composite_columns: Vec::new(),
                 });
+            let tpe = column_type(c);
+            // The defaults of array columns are array literals, which are not understood yet.
+            let default = c
+                .default
+                .as_ref()
+                .filter(|_| !c.is_list)
+                .and_then(|d| parse_default(&tpe, d));
             Column {
                 name: c.name.clone(),
-                tpe: column_type(c),
+                tpe,
                 is_required: c.is_required,
+                is_list: c.is_list,
                 foreign_key,
                 sequence: None,
-                default: None,
+                default,
+                comment: None,
+                check: None,
             }
         })
         .collect()
 }
 
+/// Groups the introspected index columns by index name. The columns must be ordered by their position in the index.
+pub fn convert_introspected_indexes(index_columns: Vec<IntrospectedIndexColumn>) -> Vec<Index> {
+    let mut indexes: Vec<Index> = Vec::new();
+
+    for index_column in index_columns {
+        let sort_order = if index_column.is_descending {
+            SortOrder::Desc
+        } else {
+            SortOrder::Asc
+        };
+
+        match indexes.iter_mut().find(|i| i.name == index_column.name) {
+            Some(index) => {
+                index.columns.push(index_column.column);
+                index.sort_orders.push(sort_order);
+            }
+            None => indexes.push(Index {
+                name: index_column.name,
+                columns: vec![index_column.column],
+                tpe: if index_column.is_unique {
+                    IndexType::Unique
+                } else if index_column.is_fulltext {
+                    IndexType::Fulltext
+                } else {
+                    IndexType::Normal
+                },
+                predicate: index_column.predicate,
+                sort_orders: vec![sort_order],
+            }),
+        }
+    }
+
+    // Like in the datamodel, the sort orders are only kept if any column is sorted descending.
+    for index in indexes.iter_mut() {
+        if index.sort_orders.iter().all(|sort_order| *sort_order == SortOrder::Asc) {
+            index.sort_orders.clear();
+        }
+    }
+
+    indexes
+}
+
+/// Parses the default of a column as reported by the database. Only literal defaults of booleans, numbers and
+/// strings are understood yet. Every family reports those differently, e.g. booleans as `true` on Postgres, as `1`
+/// on MySQL and as written in the table definition on SQLite. MySQL and SQLite may also quote them, and Postgres
+/// may add a cast, e.g. `'1'::boolean`. MySQL does not quote string defaults, so unquoted defaults that look like
+/// a function call, e.g. `gen_random_uuid()`, are not taken for strings.
+pub fn parse_default(tpe: &ColumnType, default: &str) -> Option<Value> {
+    let default = default.trim();
+    let (literal, is_quoted) = match default.rfind('\'') {
+        Some(end) if default.starts_with('\'') && end > 0 => (&default[1..end], true),
+        _ => (default.split("::").next().unwrap_or(default).trim(), false),
+    };
+
+    match tpe {
+        ColumnType::Boolean => match literal.to_lowercase().as_ref() {
+            "true" | "t" | "1" => Some(Value::Boolean(true)),
+            "false" | "f" | "0" => Some(Value::Boolean(false)),
+            _ => None,
+        },
+        ColumnType::Int => literal.parse().ok().map(Value::Int),
+        ColumnType::BigInt => literal.parse().ok().map(Value::BigInt),
+        ColumnType::Float => literal.parse().ok().map(Value::Float),
+        ColumnType::String if is_quoted || !literal.contains('(') => Some(Value::String(literal.to_string())),
+        _ => None,
+    }
+}
+
+/// Sets the comments of the table and its columns.
+pub fn apply_introspected_comments(table: &mut Table, comments: Vec<IntrospectedComment>) {
+    for comment in comments.into_iter().filter(|c| !c.comment.is_empty()) {
+        match comment.column {
+            Some(column_name) => {
+                if let Some(column) = table.columns.iter_mut().find(|c| c.name == column_name) {
+                    column.comment = Some(comment.comment);
+                }
+            }
+            None => table.comment = Some(comment.comment),
+        }
+    }
+}
+
+/// Sets the check constraints of the columns of the table.
+pub fn apply_introspected_checks(table: &mut Table, checks: Vec<IntrospectedCheck>) {
+    for check in checks {
+        if let Some(column) = table.columns.iter_mut().find(|c| c.name == check.column) {
+            column.check = Some(check.expression);
+        }
+    }
+}
+
+/// Parses the referential action reported by the database, e.g. `SET NULL`.
+pub fn parse_on_delete(action: &str) -> OnDelete {
+    match action.to_uppercase().as_ref() {
+        "CASCADE" => OnDelete::Cascade,
+        "SET NULL" => OnDelete::SetNull,
+        "SET DEFAULT" => OnDelete::SetDefault,
+        "RESTRICT" => OnDelete::Restrict,
+        _ => OnDelete::NoAction,
+    }
+}
+
+/// Parses the referential update action reported by the database, e.g. `CASCADE`.
+pub fn parse_on_update(action: &str) -> OnUpdate {
+    match action.to_uppercase().as_ref() {
+        "CASCADE" => OnUpdate::Cascade,
+        "SET NULL" => OnUpdate::SetNull,
+        "SET DEFAULT" => OnUpdate::SetDefault,
+        "RESTRICT" => OnUpdate::Restrict,
+        _ => OnUpdate::NoAction,
+    }
+}
+
 #[derive(Debug)]
 pub struct IntrospectedForeignKey {
     pub name: String,
@@ -36,6 +164,11 @@ pub struct IntrospectedForeignKey {
     pub column: String,
     pub referenced_table: String,
     pub referenced_column: String,
+    /// The schema of the referenced table, if it is not the introspected schema.
+    pub referenced_schema: Option<String>,
+    pub on_delete: OnDelete,
+    pub on_update: OnUpdate,
+    pub deferrable: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -45,5 +178,30 @@ pub struct IntrospectedColumn {
     pub tpe: String,
     pub default: Option<String>,
     pub is_required: bool,
+    pub is_list: bool,
     pub pk: u32,
 }
+
+#[derive(Debug, Clone)]
+pub struct IntrospectedIndexColumn {
+    pub name: String,
+    pub column: String,
+    pub is_unique: bool,
+    pub is_fulltext: bool,
+    pub predicate: Option<String>,
+    pub is_descending: bool,
+}
+
+/// The comment of a table or, if the column is set, of one of its columns. Empty comments are ignored.
+#[derive(Debug, Clone)]
+pub struct IntrospectedComment {
+    pub column: Option<String>,
+    pub comment: String,
+}
+
+/// A check constraint on a single column. Constraints spanning several columns are not introspected.
+#[derive(Debug)]
+pub struct IntrospectedCheck {
+    pub column: String,
+    pub expression: String,
+}
@@ -1,4 +1,5 @@
-use datamodel::Value;
+use crate::database_schema_calculator::FieldExtensions;
+use datamodel::{Datamodel, FieldType, SortOrder, Value};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct DatabaseSchema {
@@ -24,11 +25,64 @@ impl DatabaseSchema {
     pub fn empty() -> DatabaseSchema {
         DatabaseSchema { tables: Vec::new() }
     }
+
+    /// Marks the tables of a schema that was introspected from another database schema than the one of the connector.
+    /// The inspector reports the schemas of referenced tables relative to the introspected schema, so they are made
+    /// relative to the schema of the connector.
+    pub fn qualify(&mut self, schema: &str, connector_schema: &str) {
+        for table in self.tables.iter_mut() {
+            table.schema = Some(schema.to_string());
+
+            for foreign_key in table.columns.iter_mut().filter_map(|c| c.foreign_key.as_mut()) {
+                foreign_key.schema = match foreign_key.schema.take() {
+                    None => Some(schema.to_string()),
+                    Some(ref referenced_schema) if referenced_schema == connector_schema => None,
+                    referenced_schema => referenced_schema,
+                };
+            }
+        }
+    }
+
+    /// Enums are stored as strings, so the inspector can not tell enum columns apart from string columns.
+    /// This marks the string columns that back an enum field of the given datamodel as enum columns.
+    pub fn annotate_enum_columns(&mut self, datamodel: &Datamodel) {
+        for model in datamodel.models() {
+            for field in model.fields() {
+                let enum_name = match &field.field_type {
+                    FieldType::Enum(enum_name) => enum_name,
+                    _ => continue,
+                };
+
+                // The values of scalar lists may live in a separate table.
+                let candidates = vec![
+                    (model.db_name().to_string(), field.db_name()),
+                    (format!("{}_{}", model.db_name(), field.db_name()), "value".to_string()),
+                ];
+
+                for (table_name, column_name) in candidates {
+                    let column = self
+                        .tables
+                        .iter_mut()
+                        .filter(|t| t.name == table_name)
+                        .flat_map(|t| t.columns.iter_mut())
+                        .find(|c| c.name == column_name && c.tpe == ColumnType::String);
+
+                    if let Some(column) = column {
+                        column.tpe = ColumnType::Enum(enum_name.clone());
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Table {
     pub name: String,
+    /// The schema the table lives in, if it is not the schema of the connector. Only used on Postgres.
+    pub schema: Option<String>,
+    /// The comment of the table, taken from the documentation of its model. Not supported on SQLite.
+    pub comment: Option<String>,
     pub columns: Vec<Column>,
     pub indexes: Vec<Index>,
     pub primary_key_columns: Vec<String>,
@@ -54,18 +108,48 @@ pub struct Column {
     pub name: String,
     pub tpe: ColumnType,
     pub is_required: bool,
+    /// Whether this is an array column holding a scalar list.
+    pub is_list: bool,
     pub foreign_key: Option<ForeignKey>,
     pub sequence: Option<Sequence>,
     pub default: Option<Value>,
+    /// The comment of the column, taken from the documentation of its field. Not supported on SQLite.
+    /// Comments are changed separately from the column, see `SetComment`.
+    pub comment: Option<String>,
+    /// The expression of the `CHECK` constraint of the column, taken from `@check`. Not supported on MySQL.
+    pub check: Option<String>,
 }
 
 impl Column {
+    /// Defaults and checks can be changed without recreating the column, see `differs_in_check`.
     pub fn differs_in_something_except_default(&self, other: &Column) -> bool {
         self.name != other.name
-            || self.tpe != other.tpe
+            || !self.tpe.is_stored_like(&other.tpe)
             || self.is_required != other.is_required
+            || self.is_list != other.is_list
             || self.foreign_key != other.foreign_key
-            || self.sequence != other.sequence
+            || self.sequence.as_ref().map(|s| &s.name) != other.sequence.as_ref().map(|s| &s.name)
+    }
+
+    pub fn differs_in_check(&self, other: &Column) -> bool {
+        self.check.as_ref().map(|c| normalize_check(c)) != other.check.as_ref().map(|c| normalize_check(c))
+    }
+
+    /// The default of the column, if the database reports it in a form that can be compared, see `parse_default`.
+    /// Defaults are only rendered for required columns, so the defaults of optional columns are ignored.
+    pub fn comparable_default(&self) -> Option<Value> {
+        if self.sequence.is_some() || self.is_list || !self.is_required {
+            return None;
+        }
+
+        match (&self.tpe, self.default.as_ref()?) {
+            (ColumnType::Boolean, Value::Boolean(x)) => Some(Value::Boolean(*x)),
+            (ColumnType::Int, Value::Int(x)) => Some(Value::Int(*x)),
+            (ColumnType::BigInt, Value::BigInt(x)) => Some(Value::BigInt(*x)),
+            (ColumnType::Float, Value::Float(x)) | (ColumnType::Float, Value::Decimal(x)) => Some(Value::Float(*x)),
+            (ColumnType::String, Value::String(x)) => Some(Value::String(x.clone())),
+            _ => None,
+        }
     }
 }
 
@@ -75,9 +159,12 @@ impl Column {
             name,
             tpe,
             is_required,
+            is_list: false,
             foreign_key: None,
             sequence: None,
             default: None,
+            comment: None,
+            check: None,
         }
     }
 
@@ -86,20 +173,67 @@ impl Column {
             name,
             tpe,
             is_required,
+            is_list: false,
             foreign_key: Some(foreign_key),
             sequence: None,
             default: None,
+            comment: None,
+            check: None,
         }
     }
 }
 
-#[derive(Debug, Copy, PartialEq, Clone)]
+/// Databases report check constraints in their own normalized form, e.g. Postgres wraps the expression in
+/// parentheses and adds casts like `::text`. Expressions are therefore compared ignoring those differences.
+fn normalize_check(expression: &str) -> String {
+    let mut normalized = String::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            while chars.peek().map(|c| c.is_alphanumeric() || *c == '_').unwrap_or(false) {
+                chars.next();
+            }
+        } else if !c.is_whitespace() && !"()\"`".contains(c) {
+            normalized.extend(c.to_lowercase());
+        }
+    }
+
+    normalized
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum ColumnType {
     Int,
+    /// A 64 bit integer. SQLite integers are 64 bit wide anyway, so it is only used on Postgres and MySQL.
+    BigInt,
     Float,
     Boolean,
     String,
     DateTime,
+    /// A timezone aware timestamp. Only used on Postgres, other families store all timestamps as `DateTime`.
+    DateTimeTz,
+    Xml,
+    /// A native UUID. Only used on Postgres, other families store UUIDs as `String`.
+    Uuid,
+    /// A column holding the values of the enum with the given name.
+    /// The database itself stores those as strings, see `DatabaseSchema::annotate_enum_columns`.
+    Enum(String),
+    /// A column of a type Prisma cannot model, e.g. `cidr`, holding the raw database type.
+    /// Prisma never changes or drops such columns.
+    Unsupported(String),
+}
+
+impl ColumnType {
+    /// Whether the database stores values of both types the same way. Enums are stored as strings,
+    /// so a column can change from one enum to another without changing its data type.
+    pub fn is_stored_like(&self, other: &ColumnType) -> bool {
+        match (self, other) {
+            (ColumnType::Enum(_), ColumnType::Enum(_)) => true,
+            _ => self == other,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -107,19 +241,45 @@ pub struct ForeignKey {
     pub name: Option<String>,
     pub table: String,
     pub column: String,
+    /// The schema of the referenced table, if it is not the schema of the connector. Only used on Postgres.
+    pub schema: Option<String>,
     pub on_delete: OnDelete,
+    pub on_update: OnUpdate,
+    /// Whether the constraint is only checked at the end of the transaction.
+    pub deferrable: bool,
+    /// For a foreign key spanning several columns: all of its columns, each paired with the column it references.
+    /// Every column of the constraint carries the same pairs. The database reports the constraint per column,
+    /// so the pairs are not compared.
+    pub composite_columns: Vec<(String, String)>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OnDelete {
     NoAction,
     SetNull,
+    SetDefault,
+    Restrict,
+    Cascade,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnUpdate {
+    NoAction,
+    SetNull,
+    SetDefault,
+    Restrict,
     Cascade,
 }
 
 impl PartialEq for ForeignKey {
     fn eq(&self, other: &ForeignKey) -> bool {
-        self.table == other.table && self.column == other.column
+        // Unnamed foreign keys get a name chosen by the database, so names are only compared if both sides have one.
+        let names_match = match (&self.name, &other.name) {
+            (Some(name), Some(other_name)) if !name.is_empty() && !other_name.is_empty() => name == other_name,
+            _ => true,
+        };
+
+        self.table == other.table && self.schema == other.schema && self.column == other.column && names_match
     }
 }
 
@@ -129,7 +289,11 @@ impl ForeignKey {
             name: None,
             table,
             column,
+            schema: None,
             on_delete,
+            on_update: OnUpdate::NoAction,
+            deferrable: false,
+            composite_columns: Vec::new(),
         }
     }
 
@@ -138,15 +302,23 @@ impl ForeignKey {
             name: Some(name),
             table,
             column,
+            schema: None,
             on_delete,
+            on_update: OnUpdate::NoAction,
+            deferrable: false,
+            composite_columns: Vec::new(),
         }
     }
 }
 
+/// The sequence providing the default of an id column with the `SEQUENCE` strategy. Only used on Postgres.
+/// Sequences always live in the schema of the connector, even if their table does not.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Sequence {
     pub name: String,
-    pub current: u32,
+    /// The value the sequence starts at. Columns only compare the names of their sequences, as a changed
+    /// initial value does not require to recreate the column.
+    pub initial_value: u32,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -154,11 +326,16 @@ pub struct Index {
     pub name: String,
     pub columns: Vec<String>,
     pub tpe: IndexType,
+    /// The predicate of a partial index, as reported by the database.
+    pub predicate: Option<String>,
+    /// The sort order of each column, in the order of `columns`. Empty if all columns are sorted ascending.
+    pub sort_orders: Vec<SortOrder>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum IndexType {
-    // can later add fulltext or custom ones
+    // can later add custom ones
     Unique,
     Normal,
+    Fulltext,
 }
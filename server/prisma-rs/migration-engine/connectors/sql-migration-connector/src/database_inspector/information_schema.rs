@@ -36,7 +36,8 @@ impl InformationSchema {
                 cols.column_name,
                 cols.{} AS data_type,
                 cols.column_default,
-                cols.is_nullable = 'YES' as is_nullable
+                cols.is_nullable = 'YES' as is_nullable,
+                cols.data_type = 'ARRAY' as is_list
             FROM
                 information_schema.columns AS cols
             WHERE
@@ -60,6 +61,7 @@ impl InformationSchema {
                     table: table.to_string(),
                     tpe: row["data_type"].to_string().unwrap(),
                     is_required: !row["is_nullable"].as_bool().unwrap(),
+                    is_list: row["is_list"].as_bool().unwrap(),
                     default: default_value,
                     pk: 1 as u32, // TODO: implement foreign key llokup
                 }
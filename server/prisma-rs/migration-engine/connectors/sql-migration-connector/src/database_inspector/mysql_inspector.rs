@@ -1,4 +1,7 @@
-use super::database_inspector_impl::{convert_introspected_columns, IntrospectedForeignKey};
+use super::database_inspector_impl::{
+    apply_introspected_comments, convert_introspected_columns, convert_introspected_indexes, parse_on_delete,
+    parse_on_update, IntrospectedComment, IntrospectedForeignKey, IntrospectedIndexColumn,
+};
 use super::information_schema::InformationSchema;
 use super::*;
 use std::sync::Arc;
@@ -36,16 +39,32 @@ impl MysqlInspector {
         let introspected_columns = self.information_schema.get_columns(&schema, &table);
         let introspected_foreign_keys = self.get_foreign_key_constraints(&schema, &table);
         let primary_key_columns = self.information_schema.get_primary_keys(&schema, &table);
-        Table {
+        // MySQL implicitly creates an index for each foreign key. It is named like the constraint or, if the
+        // constraint is unnamed, like the column.
+        let introspected_indexes = self
+            .get_index_columns(&schema, &table)
+            .into_iter()
+            .filter(|i| {
+                !introspected_foreign_keys
+                    .iter()
+                    .any(|fk| fk.name == i.name || fk.column == i.name)
+            })
+            .collect();
+        let mut result = Table {
             name: table.to_string(),
+            schema: None,
+            comment: None,
             columns: convert_introspected_columns(
                 introspected_columns,
                 introspected_foreign_keys,
                 Box::new(column_type),
             ),
-            indexes: Vec::new(),
+            indexes: convert_introspected_indexes(introspected_indexes),
             primary_key_columns,
-        }
+        };
+        apply_introspected_comments(&mut result, self.get_comments(&schema, &table));
+
+        result
     }
 
     fn get_foreign_key_constraints(&self, schema: &String, table: &String) -> Vec<IntrospectedForeignKey> {
@@ -56,9 +75,15 @@ impl MysqlInspector {
                 kcu.table_name AS fkTableName,
                 kcu.column_name AS fkColumnName,
                 kcu.referenced_table_name AS referencedTableName,
-                kcu.referenced_column_name AS referencedColumnName
+                kcu.referenced_column_name AS referencedColumnName,
+                rc.delete_rule AS deleteRule,
+                rc.update_rule AS updateRule
             FROM
                 information_schema.key_column_usage kcu
+            INNER JOIN
+                information_schema.referential_constraints AS rc
+                ON rc.constraint_schema = kcu.constraint_schema
+                AND rc.constraint_name = kcu.constraint_name
             WHERE
                 kcu.table_schema  = '{}'
                 AND kcu.table_name = '{}'
@@ -77,6 +102,83 @@ impl MysqlInspector {
                 column: row["fkColumnName"].to_string().unwrap(),
                 referenced_table: row["referencedTableName"].to_string().unwrap(),
                 referenced_column: row["referencedColumnName"].to_string().unwrap(),
+                referenced_schema: None,
+                on_delete: parse_on_delete(&row["deleteRule"].to_string().unwrap()),
+                on_update: parse_on_update(&row["updateRule"].to_string().unwrap()),
+                deferrable: false,
+            })
+            .collect()
+    }
+
+    /// MySQL reports an empty comment for tables and columns without one.
+    fn get_comments(&self, schema: &String, table: &String) -> Vec<IntrospectedComment> {
+        let sql = format!(
+            r#"
+            SELECT
+                NULL AS columnName,
+                table_comment AS comment
+            FROM
+                information_schema.tables
+            WHERE
+                table_schema = '{}' AND
+                table_name = '{}'
+            UNION ALL
+            SELECT
+                column_name AS columnName,
+                column_comment AS comment
+            FROM
+                information_schema.columns
+            WHERE
+                table_schema = '{}' AND
+                table_name = '{}'
+        "#,
+            schema, table, schema, table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedComment {
+                column: row["columnName"].to_string(),
+                comment: row["comment"].to_string().unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    fn get_index_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedIndexColumn> {
+        let sql = format!(
+            r#"
+            SELECT
+                stats.index_name AS index_name,
+                stats.column_name AS column_name,
+                stats.non_unique = 0 AS is_unique,
+                stats.index_type = 'FULLTEXT' AS is_fulltext
+            FROM
+                information_schema.statistics stats
+            WHERE
+                stats.table_schema = '{}'
+                AND stats.table_name = '{}'
+                AND stats.index_name <> 'PRIMARY'
+            ORDER BY
+                stats.index_name,
+                stats.seq_in_index
+            "#,
+            schema, table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedIndexColumn {
+                name: row["index_name"].to_string().unwrap(),
+                column: row["column_name"].to_string().unwrap(),
+                is_unique: row["is_unique"].as_bool().unwrap(),
+                is_fulltext: row["is_fulltext"].as_bool().unwrap(),
+                predicate: None,
+                // MySQL before 8.0 parses but ignores the sort order of index columns.
+                is_descending: false,
             })
             .collect()
     }
@@ -85,15 +187,14 @@ impl MysqlInspector {
 fn column_type(column: &IntrospectedColumn) -> ColumnType {
     // https://dev.mysql.com/doc/refman/8.0/en/data-types.html
     match column.tpe.as_ref() {
+        // MySQL has no boolean type, `boolean` is an alias of `tinyint(1)`.
         "tinyint" => ColumnType::Boolean,
         s if s.contains("char") => ColumnType::String,
         s if s.contains("text") => ColumnType::String,
+        "bigint" => ColumnType::BigInt,
         s if s.contains("int") => ColumnType::Int,
         "decimal" | "numeric" | "float" | "double" => ColumnType::Float,
         "datetime" | "timestamp" | "date" => ColumnType::DateTime,
-        x => panic!(format!(
-            "type {} is not supported here yet. Column was: {}",
-            x, column.name
-        )),
+        x => ColumnType::Unsupported(x.to_string()),
     }
 }
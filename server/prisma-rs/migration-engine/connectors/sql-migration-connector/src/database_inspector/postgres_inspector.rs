@@ -1,4 +1,8 @@
-use super::database_inspector_impl::{convert_introspected_columns, IntrospectedForeignKey};
+use super::database_inspector_impl::{
+    apply_introspected_checks, apply_introspected_comments, convert_introspected_columns, convert_introspected_indexes,
+    parse_on_delete, parse_on_update, IntrospectedCheck, IntrospectedComment, IntrospectedForeignKey,
+    IntrospectedIndexColumn,
+};
 use super::information_schema::InformationSchema;
 use super::*;
 use std::sync::Arc;
@@ -34,44 +38,111 @@ impl Postgres {
 
     fn get_table(&self, schema: &String, table: &String) -> Table {
         let introspected_columns = self.information_schema.get_columns(&schema, &table);
+        let column_sequences: Vec<(String, String)> = introspected_columns
+            .iter()
+            .filter_map(|c| {
+                let sequence_name = c.default.as_ref().and_then(|d| parse_sequence_name(d))?;
+                Some((c.name.clone(), sequence_name))
+            })
+            .collect();
         let introspected_foreign_keys = self.get_foreign_key_constraints(&schema, &table);
         let primary_key_columns = self.information_schema.get_primary_keys(&schema, &table);
-        Table {
+        let mut introspected_indexes = self.get_index_columns(&schema, &table);
+        introspected_indexes.append(&mut self.get_fulltext_index_columns(&schema, &table));
+        let mut result = Table {
             name: table.to_string(),
+            schema: None,
+            comment: None,
             columns: convert_introspected_columns(
                 introspected_columns,
                 introspected_foreign_keys,
                 Box::new(column_type),
             ),
-            indexes: Vec::new(),
+            indexes: convert_introspected_indexes(introspected_indexes),
             primary_key_columns,
+        };
+        apply_introspected_comments(&mut result, self.get_comments(&schema, &table));
+        apply_introspected_checks(&mut result, self.get_checks(&schema, &table));
+
+        if !column_sequences.is_empty() {
+            let sequences = self.get_sequences(&schema);
+
+            for (column_name, sequence_name) in column_sequences {
+                if let Some(column) = result.columns.iter_mut().find(|c| c.name == column_name) {
+                    column.sequence = sequences.iter().find(|s| s.name == sequence_name).cloned();
+                }
+            }
         }
+
+        result
+    }
+
+    fn get_sequences(&self, schema: &String) -> Vec<Sequence> {
+        let sql = format!(
+            r#"
+            SELECT
+                sequence_name,
+                start_value
+            FROM
+                information_schema.sequences
+            WHERE
+                sequence_schema = '{}'
+        "#,
+            schema
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| Sequence {
+                name: row["sequence_name"].to_string().unwrap(),
+                // The start value is reported as text.
+                initial_value: row["start_value"].to_string().unwrap().parse().unwrap_or(1),
+            })
+            .collect()
     }
 
     fn get_foreign_key_constraints(&self, schema: &String, table: &String) -> Vec<IntrospectedForeignKey> {
+        // The information schema can not pair the columns of composite foreign keys with the columns they
+        // reference, so the constraints are read from the catalog. `conkey` and `confkey` are ordered alike.
         let sql = format!(
             r#"
             SELECT
-	            kcu.constraint_name as "fkConstraintName",
-                kcu.table_name as "fkTableName",
-                kcu.column_name as "fkColumnName",
-                ccu.table_name as "referencedTableName",
-                ccu.column_name as "referencedColumnName"
+                con.conname::text as "fkConstraintName",
+                cl.relname::text as "fkTableName",
+                att.attname::text as "fkColumnName",
+                fcl.relname::text as "referencedTableName",
+                fns.nspname::text as "referencedSchemaName",
+                fatt.attname::text as "referencedColumnName",
+                CASE con.confdeltype
+                    WHEN 'r' THEN 'RESTRICT'
+                    WHEN 'c' THEN 'CASCADE'
+                    WHEN 'n' THEN 'SET NULL'
+                    WHEN 'd' THEN 'SET DEFAULT'
+                    ELSE 'NO ACTION'
+                END as "deleteRule",
+                CASE con.confupdtype
+                    WHEN 'r' THEN 'RESTRICT'
+                    WHEN 'c' THEN 'CASCADE'
+                    WHEN 'n' THEN 'SET NULL'
+                    WHEN 'd' THEN 'SET DEFAULT'
+                    ELSE 'NO ACTION'
+                END as "updateRule",
+                CASE WHEN con.condeferrable THEN 'YES' ELSE 'NO' END as "isDeferrable"
             FROM
-                information_schema.key_column_usage kcu
-            INNER JOIN
-                information_schema.constraint_column_usage AS ccu
-                ON ccu.constraint_catalog = kcu.constraint_catalog
-                AND ccu.constraint_schema = kcu.constraint_schema
-                AND ccu.constraint_name = kcu.constraint_name
-            INNER JOIN
-                information_schema.referential_constraints as rc
-                ON rc.constraint_catalog = kcu.constraint_catalog
-                AND rc.constraint_schema = kcu.constraint_schema
-                AND rc.constraint_name = kcu.constraint_name
+                pg_constraint con
+            INNER JOIN pg_class cl ON cl.oid = con.conrelid
+            INNER JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            INNER JOIN pg_class fcl ON fcl.oid = con.confrelid
+            INNER JOIN pg_namespace fns ON fns.oid = fcl.relnamespace
+            CROSS JOIN LATERAL unnest(con.conkey, con.confkey) AS cols(col, fcol)
+            INNER JOIN pg_attribute att ON att.attrelid = con.conrelid AND att.attnum = cols.col
+            INNER JOIN pg_attribute fatt ON fatt.attrelid = con.confrelid AND fatt.attnum = cols.fcol
             WHERE
-                kcu.table_schema = '{}' AND
-                kcu.table_name = '{}'
+                con.contype = 'f' AND
+                ns.nspname = '{}' AND
+                cl.relname = '{}'
         "#,
             schema, table
         );
@@ -86,13 +157,213 @@ impl Postgres {
                 column: row["fkColumnName"].to_string().unwrap(),
                 referenced_table: row["referencedTableName"].to_string().unwrap(),
                 referenced_column: row["referencedColumnName"].to_string().unwrap(),
+                referenced_schema: Some(row["referencedSchemaName"].to_string().unwrap()).filter(|s| s != schema),
+                on_delete: parse_on_delete(&row["deleteRule"].to_string().unwrap()),
+                on_update: parse_on_update(&row["updateRule"].to_string().unwrap()),
+                deferrable: row["isDeferrable"].to_string().unwrap() == "YES",
+            })
+            .collect()
+    }
+
+    /// The comment of the table has no column, as its `objsubid` is 0.
+    fn get_comments(&self, schema: &String, table: &String) -> Vec<IntrospectedComment> {
+        let sql = format!(
+            r#"
+            SELECT
+                att.attname::text as "columnName",
+                des.description as "comment"
+            FROM
+                pg_description des
+            INNER JOIN pg_class cl ON cl.oid = des.objoid
+            INNER JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            LEFT JOIN pg_attribute att ON att.attrelid = cl.oid AND att.attnum = des.objsubid
+            WHERE
+                des.classoid = 'pg_class'::regclass AND
+                ns.nspname = '{}' AND
+                cl.relname = '{}'
+        "#,
+            schema, table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedComment {
+                column: row["columnName"].to_string(),
+                comment: row["comment"].to_string().unwrap(),
+            })
+            .collect()
+    }
+
+    /// `pg_get_expr` renders the expression the way Postgres normalized it, e.g. `(age >= 0)`.
+    fn get_checks(&self, schema: &String, table: &String) -> Vec<IntrospectedCheck> {
+        let sql = format!(
+            r#"
+            SELECT
+                att.attname::text as "columnName",
+                pg_get_expr(con.conbin, con.conrelid) as "expression"
+            FROM
+                pg_constraint con
+            INNER JOIN pg_class cl ON cl.oid = con.conrelid
+            INNER JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            INNER JOIN pg_attribute att ON att.attrelid = con.conrelid AND att.attnum = con.conkey[1]
+            WHERE
+                con.contype = 'c' AND
+                array_length(con.conkey, 1) = 1 AND
+                ns.nspname = '{}' AND
+                cl.relname = '{}'
+        "#,
+            schema, table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedCheck {
+                column: row["columnName"].to_string().unwrap(),
+                expression: row["expression"].to_string().unwrap(),
+            })
+            .collect()
+    }
+
+    fn get_index_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedIndexColumn> {
+        let sql = format!(
+            r#"
+            SELECT
+                indexInfos.relname AS index_name,
+                columnInfos.attname AS column_name,
+                rawIndex.indisunique AS is_unique,
+                COALESCE(pg_get_expr(rawIndex.indpred, rawIndex.indrelid), '') AS predicate,
+                -- The first bit of the options of an index column is set if the column is sorted descending.
+                (rawIndex.indoption::int2[])[array_position(rawIndex.indkey::int2[], columnInfos.attnum)] & 1 = 1
+                    AS is_descending
+            FROM
+                pg_class tableInfos,
+                pg_class indexInfos,
+                pg_index rawIndex,
+                pg_attribute columnInfos,
+                pg_namespace schemaInfo
+            WHERE
+                tableInfos.oid = rawIndex.indrelid
+                AND indexInfos.oid = rawIndex.indexrelid
+                AND columnInfos.attrelid = tableInfos.oid
+                AND columnInfos.attnum = ANY(rawIndex.indkey)
+                AND tableInfos.relkind = 'r'
+                AND tableInfos.relnamespace = schemaInfo.oid
+                AND rawIndex.indisprimary = false
+                AND schemaInfo.nspname = '{}'
+                AND tableInfos.relname = '{}'
+            ORDER BY
+                index_name,
+                array_position(rawIndex.indkey::int2[], columnInfos.attnum)
+        "#,
+            schema, table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedIndexColumn {
+                name: row["index_name"].to_string().unwrap(),
+                column: row["column_name"].to_string().unwrap(),
+                is_unique: row["is_unique"].as_bool().unwrap(),
+                is_fulltext: false,
+                predicate: Some(row["predicate"].to_string().unwrap()).filter(|p| !p.is_empty()),
+                is_descending: row["is_descending"].as_bool().unwrap(),
+            })
+            .collect()
+    }
+
+    /// Fulltext indexes are GIN indexes over expressions like `to_tsvector('simple'::regconfig, "title")`. GIN indexes
+    /// with any other key, e.g. over array columns, are not fulltext indexes.
+    /// Expression columns are not listed in `pg_attribute`, so the column names are taken from the index definition.
+    fn get_fulltext_index_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedIndexColumn> {
+        let sql = format!(
+            r#"
+            SELECT
+                indexInfos.relname AS index_name,
+                regexp_replace(
+                    pg_get_indexdef(rawIndex.indexrelid, keyPosition, true),
+                    '^to_tsvector\(''{0}''::regconfig, (.*)\)$',
+                    '\1'
+                ) AS column_name
+            FROM
+                pg_class tableInfos,
+                pg_class indexInfos,
+                pg_index rawIndex,
+                pg_am accessMethod,
+                pg_namespace schemaInfo,
+                generate_series(1, rawIndex.indnatts) AS keyPosition
+            WHERE
+                tableInfos.oid = rawIndex.indrelid
+                AND indexInfos.oid = rawIndex.indexrelid
+                AND indexInfos.relam = accessMethod.oid
+                AND accessMethod.amname = 'gin'
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM generate_series(1, rawIndex.indnatts) AS otherPosition
+                    WHERE pg_get_indexdef(rawIndex.indexrelid, otherPosition, true)
+                        !~ '^to_tsvector\(''{0}''::regconfig, .*\)$'
+                )
+                AND tableInfos.relnamespace = schemaInfo.oid
+                AND schemaInfo.nspname = '{1}'
+                AND tableInfos.relname = '{2}'
+            ORDER BY
+                index_name,
+                keyPosition
+        "#,
+            crate::POSTGRES_FULLTEXT_CONFIGURATION,
+            schema,
+            table
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+
+        result_set
+            .into_iter()
+            .map(|row| IntrospectedIndexColumn {
+                name: row["index_name"].to_string().unwrap(),
+                column: unquote(&row["column_name"].to_string().unwrap()),
+                is_unique: false,
+                is_fulltext: true,
+                predicate: None,
+                is_descending: false,
             })
             .collect()
     }
 }
 
+/// Postgres quotes identifiers in index definitions and column defaults if they are not all lowercase or are keywords.
+fn unquote(identifier: &str) -> String {
+    if identifier.len() > 1 && identifier.starts_with('"') && identifier.ends_with('"') {
+        identifier[1..identifier.len() - 1].replace("\"\"", "\"")
+    } else {
+        identifier.to_string()
+    }
+}
+
+/// Columns backed by a sequence default to e.g. `nextval('"Model_id_seq"'::regclass)`. The name of the sequence is
+/// qualified with its schema if that is not in the search path.
+fn parse_sequence_name(default: &str) -> Option<String> {
+    let prefix = "nextval('";
+
+    if !default.starts_with(prefix) {
+        return None;
+    }
+
+    let qualified_name = default[prefix.len()..].split('\'').next()?;
+    let name = qualified_name.rsplit('.').next()?;
+
+    Some(unquote(name))
+}
+
 fn column_type(column: &IntrospectedColumn) -> ColumnType {
-    match column.tpe.as_ref() {
+    // The udt_name of an array column is the name of its element type prefixed with an underscore, e.g. `_text`.
+    match column.tpe.trim_start_matches('_') {
+        "int8" => ColumnType::BigInt,
         s if s.starts_with("int") => ColumnType::Int,
         s if s.starts_with("float") => ColumnType::Float,
         "numeric" => ColumnType::Float,
@@ -100,9 +371,9 @@ fn column_type(column: &IntrospectedColumn) -> ColumnType {
         "text" => ColumnType::String,
         s if s.contains("char") => ColumnType::String,
         "date" | "timestamp" => ColumnType::DateTime,
-        x => panic!(format!(
-            "type {} is not supported here yet. Column was: {}",
-            x, column.name
-        )),
+        "timestamptz" => ColumnType::DateTimeTz,
+        "xml" => ColumnType::Xml,
+        "uuid" => ColumnType::Uuid,
+        x => ColumnType::Unsupported(x.to_string()),
     }
 }
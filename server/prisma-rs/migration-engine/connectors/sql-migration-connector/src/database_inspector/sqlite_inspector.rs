@@ -1,5 +1,9 @@
-use super::database_inspector_impl::{convert_introspected_columns, IntrospectedForeignKey};
+use super::database_inspector_impl::{
+    apply_introspected_checks, convert_introspected_columns, convert_introspected_indexes, parse_on_delete,
+    parse_on_update, IntrospectedCheck, IntrospectedForeignKey, IntrospectedIndexColumn,
+};
 use super::*;
+use crate::SqlFamily;
 use prisma_query::ast::ParameterizedValue;
 use std::sync::Arc;
 
@@ -34,7 +38,7 @@ impl Sqlite {
             WHERE
                 type='table'
         ",
-            format!("\"{}\"", schema)
+            SqlFamily::Sqlite.quote(schema)
         );
 
         let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
@@ -58,20 +62,29 @@ impl Sqlite {
             .map(|c| c.name)
             .collect();
 
-        Table {
+        let mut result = Table {
             name: table.to_string(),
+            schema: None,
+            comment: None,
             columns: convert_introspected_columns(
                 introspected_columns,
                 introspected_foreign_keys,
                 Box::new(column_type),
             ),
-            indexes: Vec::new(),
+            indexes: convert_introspected_indexes(self.get_index_columns(&schema, &table)),
             primary_key_columns: pk_columns,
-        }
+        };
+        apply_introspected_checks(&mut result, self.get_checks(&schema, &table));
+
+        result
     }
 
     fn get_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedColumn> {
-        let sql = format!(r#"Pragma "{}".table_info ("{}")"#, schema, table);
+        let sql = format!(
+            "Pragma {}.table_info({})",
+            SqlFamily::Sqlite.quote(schema),
+            SqlFamily::Sqlite.quote(table)
+        );
 
         let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
         let columns = result_set
@@ -87,6 +100,7 @@ impl Sqlite {
                     table: table.to_string(),
                     tpe: row["type"].to_string().unwrap(),
                     is_required: row["notnull"].as_bool().unwrap(),
+                    is_list: false,
                     default: default_value,
                     pk: row["pk"].as_i64().unwrap() as u32,
                 }
@@ -97,7 +111,11 @@ impl Sqlite {
     }
 
     fn get_foreign_constraints(&self, schema: &String, table: &String) -> Vec<IntrospectedForeignKey> {
-        let sql = format!(r#"Pragma "{}".foreign_key_list("{}");"#, schema, table);
+        let sql = format!(
+            "Pragma {}.foreign_key_list({});",
+            SqlFamily::Sqlite.quote(schema),
+            SqlFamily::Sqlite.quote(table)
+        );
 
         let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
 
@@ -109,34 +127,239 @@ impl Sqlite {
                 column: row["from"].to_string().unwrap(),
                 referenced_table: row["table"].to_string().unwrap(),
                 referenced_column: row["to"].to_string().unwrap(),
+                referenced_schema: None,
+                on_delete: parse_on_delete(&row["on_delete"].to_string().unwrap()),
+                on_update: parse_on_update(&row["on_update"].to_string().unwrap()),
+                deferrable: false,
             })
             .collect();
 
         foreign_keys
     }
 
-    #[allow(unused)]
-    fn get_sequence(&self, _schema: &String, _table: &String) -> Sequence {
-        unimplemented!()
+    fn get_index_columns(&self, schema: &String, table: &String) -> Vec<IntrospectedIndexColumn> {
+        let sql = format!(
+            "Pragma {}.index_list({});",
+            SqlFamily::Sqlite.quote(schema),
+            SqlFamily::Sqlite.quote(table)
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+        let mut index_columns = Vec::new();
+
+        // Only indexes created via CREATE INDEX are relevant. Those backing primary keys or UNIQUE constraints are skipped.
+        for index_row in result_set
+            .into_iter()
+            .filter(|row| row["origin"].to_string().unwrap() == "c")
+        {
+            let index_name = index_row["name"].to_string().unwrap();
+            let is_unique = index_row["unique"].as_bool().unwrap();
+            let predicate = if index_row["partial"].as_bool().unwrap() {
+                self.get_index_predicate(schema, &index_name)
+            } else {
+                None
+            };
+
+            // Unlike `index_info`, `index_xinfo` reports the sort order of the columns. It also lists the rowid,
+            // which is not a key column of the index.
+            let sql = format!(
+                "Pragma {}.index_xinfo({});",
+                SqlFamily::Sqlite.quote(schema),
+                SqlFamily::Sqlite.quote(&index_name)
+            );
+            let mut column_rows: Vec<_> = self
+                .database
+                .query_raw(schema, &sql, &[])
+                .unwrap()
+                .into_iter()
+                .filter(|row| row["key"].as_bool().unwrap())
+                .collect();
+            column_rows.sort_by_key(|row| row["seqno"].as_i64().unwrap());
+
+            for column_row in column_rows {
+                index_columns.push(IntrospectedIndexColumn {
+                    name: index_name.clone(),
+                    column: column_row["name"].to_string().unwrap(),
+                    is_unique,
+                    is_fulltext: false,
+                    predicate: predicate.clone(),
+                    is_descending: column_row["desc"].as_bool().unwrap(),
+                });
+            }
+        }
+
+        index_columns
+    }
+
+    /// SQLite does not expose the predicate of a partial index, so it is taken from the `CREATE INDEX` statement.
+    fn get_index_predicate(&self, schema: &String, index_name: &String) -> Option<String> {
+        let sql = format!(
+            "SELECT sql FROM {}.sqlite_master WHERE type = 'index' AND name = {}",
+            SqlFamily::Sqlite.quote(schema),
+            string_literal(index_name)
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+        let create_statement = result_set.into_iter().next()?["sql"].to_string()?;
+        let where_position = create_statement.to_ascii_uppercase().rfind(" WHERE ")?;
+
+        Some(create_statement[where_position + " WHERE ".len()..].trim().to_string())
+    }
+
+    /// SQLite does not expose check constraints either, so they are taken from the `CREATE TABLE` statement.
+    fn get_checks(&self, schema: &String, table: &String) -> Vec<IntrospectedCheck> {
+        let sql = format!(
+            "SELECT sql FROM {}.sqlite_master WHERE type = 'table' AND name = {}",
+            SqlFamily::Sqlite.quote(schema),
+            string_literal(table)
+        );
+
+        let result_set = self.database.query_raw(schema, &sql, &[]).unwrap();
+        let create_statement = match result_set.into_iter().next().and_then(|row| row["sql"].to_string()) {
+            Some(create_statement) => create_statement,
+            None => return Vec::new(),
+        };
+
+        split_definitions(&create_statement)
+            .into_iter()
+            .filter_map(|definition| {
+                Some(IntrospectedCheck {
+                    column: column_name(definition)?,
+                    expression: column_check(definition)?,
+                })
+            })
+            .collect()
     }
 
     #[allow(unused)]
-    fn get_index(&self, _schema: &String, _table: &String) -> Index {
+    fn get_sequence(&self, _schema: &String, _table: &String) -> Sequence {
         unimplemented!()
     }
 }
 
+/// Names are compared to the `name` column of `sqlite_master`, so they are rendered as escaped string literals.
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// SQLite keeps the type of a column as written in its definition, so it is compared case insensitively.
 fn column_type(column: &IntrospectedColumn) -> ColumnType {
-    match column.tpe.as_ref() {
+    match column.tpe.to_uppercase().as_ref() {
         "INTEGER" => ColumnType::Int,
         "REAL" => ColumnType::Float,
         "BOOLEAN" => ColumnType::Boolean,
         "TEXT" => ColumnType::String,
         s if s.contains("CHAR") => ColumnType::String,
         "DATE" => ColumnType::DateTime,
-        x => panic!(format!(
-            "type {} is not supported here yet. Column was: {}",
-            x, column.name
-        )),
+        _ => ColumnType::Unsupported(column.tpe.clone()),
+    }
+}
+
+/// Calls `visit` with the position, the character and the parenthesis depth of each character of `sql` that is not
+/// quoted, until it returns false. Matching parentheses are visited with the same depth.
+fn visit_unquoted(sql: &str, mut visit: impl FnMut(usize, char, usize) -> bool) {
+    let mut depth: usize = 0;
+    let mut closing_quote = None;
+
+    for (position, c) in sql.char_indices() {
+        let depth_of_char = match (closing_quote, c) {
+            (Some(quote), _) => {
+                if c == quote {
+                    closing_quote = None;
+                }
+                continue;
+            }
+            (None, '"') | (None, '\'') | (None, '`') => {
+                closing_quote = Some(c);
+                continue;
+            }
+            (None, '[') => {
+                closing_quote = Some(']');
+                continue;
+            }
+            (None, '(') => {
+                depth += 1;
+                depth - 1
+            }
+            (None, ')') => {
+                depth = depth.saturating_sub(1);
+                depth
+            }
+            _ => depth,
+        };
+
+        if !visit(position, c, depth_of_char) {
+            return;
+        }
     }
 }
+
+/// The column and constraint definitions of a `CREATE TABLE` statement.
+fn split_definitions(create_statement: &str) -> Vec<&str> {
+    let mut definitions = Vec::new();
+    let mut definition_start = None;
+
+    visit_unquoted(create_statement, |position, c, depth| {
+        match (c, depth, definition_start) {
+            ('(', 0, None) => definition_start = Some(position + 1),
+            (',', 1, Some(start)) => {
+                definitions.push(&create_statement[start..position]);
+                definition_start = Some(position + 1);
+            }
+            (')', 0, Some(start)) => {
+                definitions.push(&create_statement[start..position]);
+                return false;
+            }
+            _ => (),
+        }
+        true
+    });
+
+    definitions
+}
+
+/// The name of the column a definition declares, or `None` for table constraints.
+fn column_name(definition: &str) -> Option<String> {
+    let definition = definition.trim_start();
+    let closing_quote = match definition.chars().next()? {
+        '"' => '"',
+        '`' => '`',
+        '[' => ']',
+        _ => {
+            let name = definition.split(|c: char| c.is_whitespace() || c == '(').next()?;
+            let is_table_constraint =
+                ["CONSTRAINT", "PRIMARY", "UNIQUE", "CHECK", "FOREIGN"].contains(&name.to_ascii_uppercase().as_str());
+
+            return Some(name.to_string()).filter(|_| !is_table_constraint);
+        }
+    };
+    let name_length = definition[1..].find(closing_quote)?;
+
+    Some(definition[1..=name_length].to_string())
+}
+
+/// The expression of the `CHECK` constraint in a column definition.
+fn column_check(definition: &str) -> Option<String> {
+    let follows_check_keyword = |position: usize| {
+        definition[..position]
+            .trim_end()
+            .to_ascii_uppercase()
+            .ends_with("CHECK")
+    };
+    let mut expression_start = None;
+    let mut expression = None;
+
+    visit_unquoted(definition, |position, c, depth| {
+        match (c, depth, expression_start) {
+            ('(', 0, None) if follows_check_keyword(position) => expression_start = Some(position + 1),
+            (')', 0, Some(start)) => {
+                expression = Some(definition[start..position].trim().to_string());
+                return false;
+            }
+            _ => (),
+        }
+        true
+    });
+
+    expression
+}
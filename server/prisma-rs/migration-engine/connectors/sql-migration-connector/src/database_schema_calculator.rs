@@ -1,17 +1,35 @@
 use crate::database_inspector::*;
-use crate::SqlResult;
+// Both the database inspector and the datamodel define a `Sequence`.
+use crate::database_inspector::Sequence;
+use crate::{SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
-use prisma_models::{DatamodelConverter, TempManifestationHolder, TempRelationHolder};
+use prisma_models::{DatamodelConverter, RelationTableNaming, TempManifestationHolder, TempRelationHolder};
+
+/// The suffix of the indexes created for foreign keys, see `add_foreign_key_indexes`.
+pub const FOREIGN_KEY_INDEX_SUFFIX: &str = "._FOREIGN_KEY_INDEX";
 
 pub struct DatabaseSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
+    relation_table_naming: RelationTableNaming,
+    foreign_key_indexes: bool,
 }
 
 impl<'a> DatabaseSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<DatabaseSchema> {
-        let calculator = DatabaseSchemaCalculator { data_model };
+    pub fn calculate(
+        data_model: &Datamodel,
+        sql_family: SqlFamily,
+        relation_table_naming: RelationTableNaming,
+        foreign_key_indexes: bool,
+    ) -> SqlResult<DatabaseSchema> {
+        let calculator = DatabaseSchemaCalculator {
+            data_model,
+            sql_family,
+            relation_table_naming,
+            foreign_key_indexes,
+        };
         calculator.calculate_internal()
     }
 
@@ -19,6 +37,8 @@ impl<'a> DatabaseSchemaCalculator<'a> {
         let mut tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables()?;
         let mut model_tables = self.add_inline_relations_to_model_tables(model_tables_without_inline_relations)?;
+        self.defer_required_foreign_key_cycles(&mut model_tables)?;
+        self.add_foreign_key_indexes(&mut model_tables);
         let mut scalar_list_tables = self.calculate_scalar_list_tables()?;
         let mut relation_tables = self.calculate_relation_tables()?;
 
@@ -39,22 +59,46 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                         (FieldType::Base(_), arity) | (FieldType::Enum(_), arity) if arity != &FieldArity::List => {
                             Some(Column {
                                 name: f.db_name(),
-                                tpe: column_type(f),
+                                tpe: self.column_type(f),
                                 is_required: arity == &FieldArity::Required,
+                                is_list: false,
                                 foreign_key: None,
-                                sequence: None,
-                                default: Some(f.migration_value(&self.data_model)),
+                                sequence: self.sequence(model, f),
+                                default: self.default(f),
+                                comment: self.comment(&f.documentation),
+                                check: self.check(f),
                             })
                         }
+                        (FieldType::Base(_), _) | (FieldType::Enum(_), _) if self.is_array_column(f) => Some(Column {
+                            name: f.db_name(),
+                            tpe: self.column_type(f),
+                            is_required: false,
+                            is_list: true,
+                            foreign_key: None,
+                            sequence: None,
+                            default: f.default_value.clone(),
+                            comment: self.comment(&f.documentation),
+                            check: self.check(f),
+                        }),
                         _ => None,
                     })
                     .collect();
 
+                // An unnamed `@@unique` on a single `@unique` field duplicates the index of that field.
+                let indexes = model
+                    .indices
+                    .iter()
+                    .filter(|index_definition| !model.duplicates_field_unique_index(index_definition))
+                    .map(|index_definition| calculate_index(model, index_definition, self.sql_family))
+                    .collect::<SqlResult<Vec<Index>>>()?;
+
                 let table = Table {
-                    name: model.db_name(),
+                    name: model.db_name().to_string(),
+                    schema: model.table_schema(self.sql_family),
+                    comment: self.comment(&model.documentation),
                     columns,
-                    indexes: Vec::new(),
-                    primary_key_columns: vec![model.id_field()?.db_name()],
+                    indexes,
+                    primary_key_columns: model.primary_key_columns()?,
                 };
                 Ok(ModelTable {
                     model: model.clone(),
@@ -70,21 +114,32 @@ impl<'a> DatabaseSchemaCalculator<'a> {
         for model in self.data_model.models() {
             let list_fields: Vec<&Field> = model
                 .fields()
-                .filter(|f| f.arity == FieldArity::List && is_scalar(f))
+                .filter(|f| f.arity == FieldArity::List && is_scalar(f) && !self.is_array_column(f))
                 .collect();
             for field in list_fields {
+                if field.default_value.is_some() {
+                    return Err(format!(
+                        "The list field {} of Model {} cannot have a default value, because its values are stored in a separate table. Only lists stored as an array column on Postgres (`@scalarList(strategy: EMBEDDED)`) support default values.",
+                        field.name, model.name
+                    )
+                    .into());
+                }
+
                 let id_field = model.id_field()?;
+                let mut foreign_key = ForeignKey::new(
+                    model.db_name().to_string(),
+                    model.id_field()?.db_name(),
+                    OnDelete::Cascade,
+                );
+                foreign_key.schema = model.table_schema(self.sql_family);
                 let table = Table {
                     name: format!("{}_{}", model.db_name(), field.db_name()),
+                    schema: model.table_schema(self.sql_family),
+                    comment: None,
                     columns: vec![
-                        Column::with_foreign_key(
-                            "nodeId".to_string(),
-                            column_type(&id_field),
-                            true,
-                            ForeignKey::new(model.db_name(), model.id_field()?.db_name(), OnDelete::Cascade),
-                        ),
+                        Column::with_foreign_key("nodeId".to_string(), self.column_type(&id_field), true, foreign_key),
                         Column::new("position".to_string(), ColumnType::Int, true),
-                        Column::new("value".to_string(), column_type(&field), true),
+                        Column::new("value".to_string(), self.column_type(&field), true),
                     ],
                     indexes: Vec::new(),
                     primary_key_columns: vec!["nodeId".to_string(), "position".to_string()],
@@ -105,24 +160,65 @@ impl<'a> DatabaseSchemaCalculator<'a> {
                     TempManifestationHolder::Inline {
                         in_table_of_model,
                         column,
+                        composite_columns,
                     } if in_table_of_model == &model_table.model.name => {
                         let (model, related_model) = if model_table.model == relation.model_a {
                             (&relation.model_a, &relation.model_b)
                         } else {
                             (&relation.model_b, &relation.model_a)
                         };
+                        // The columns of a composite foreign key are always declared explicitly.
+                        if !composite_columns.is_empty() {
+                            let relation_field = match &relation.field_a.field_type {
+                                FieldType::Relation(info) if info.fields.len() > 1 => &relation.field_a,
+                                _ => &relation.field_b,
+                            };
+
+                            for (column, referenced_column) in composite_columns {
+                                let mut foreign_key = ForeignKey::new(
+                                    related_model.db_name().to_string(),
+                                    referenced_column.to_string(),
+                                    on_delete(relation_field),
+                                );
+                                foreign_key.on_update = on_update(relation_field);
+                                foreign_key.name = fk_name(relation_field);
+                                foreign_key.schema = related_model.table_schema(self.sql_family);
+                                foreign_key.composite_columns = composite_columns.clone();
+
+                                if let Some(existing_column) =
+                                    model_table.table.columns.iter_mut().find(|c| &c.name == column)
+                                {
+                                    existing_column.foreign_key = Some(foreign_key);
+                                }
+                            }
+
+                            continue;
+                        }
+
                         let field = model.fields().find(|f| &f.db_name() == column).unwrap();
-                        let column = Column::with_foreign_key(
-                            column.to_string(),
-                            column_type(related_model.id_field()?),
-                            field.is_required(),
-                            ForeignKey::new(
-                                related_model.db_name(),
-                                related_model.id_field()?.db_name(),
-                                OnDelete::SetNull,
-                            ),
+                        let referenced_field = referenced_field(relation, related_model)?;
+                        let mut foreign_key = ForeignKey::new(
+                            related_model.db_name().to_string(),
+                            referenced_field.db_name(),
+                            on_delete(field),
                         );
-                        model_table.table.columns.push(column);
+                        foreign_key.on_update = on_update(field);
+                        foreign_key.name = fk_name(field);
+                        foreign_key.schema = related_model.table_schema(self.sql_family);
+
+                        // An explicitly declared foreign key field already has a column.
+                        match model_table.table.columns.iter_mut().find(|c| &c.name == column) {
+                            Some(existing_column) => existing_column.foreign_key = Some(foreign_key),
+                            None => {
+                                let column = Column::with_foreign_key(
+                                    column.to_string(),
+                                    self.column_type(referenced_field),
+                                    field.is_required(),
+                                    foreign_key,
+                                );
+                                model_table.table.columns.push(column);
+                            }
+                        }
                     }
                     _ => {}
                 }
@@ -132,33 +228,126 @@ impl<'a> DatabaseSchemaCalculator<'a> {
         Ok(result)
     }
 
+    /// MySQL implicitly creates an index for every foreign key, which Postgres does not. Without one, joins over
+    /// the relation and deletes of referenced rows have to scan the whole table. So the foreign keys of inline
+    /// relations get an index on Postgres, unless an existing index or the primary key already covers them.
+    fn add_foreign_key_indexes(&self, tables: &mut [Table]) {
+        if !self.foreign_key_indexes || self.sql_family != SqlFamily::Postgres {
+            return;
+        }
+
+        for table in tables.iter_mut() {
+            let mut foreign_keys: Vec<Vec<String>> = Vec::new();
+
+            for column in table.columns.iter() {
+                let columns = match &column.foreign_key {
+                    Some(foreign_key) if foreign_key.composite_columns.is_empty() => vec![column.name.clone()],
+                    Some(foreign_key) => foreign_key.composite_columns.iter().map(|(c, _)| c.clone()).collect(),
+                    None => continue,
+                };
+
+                if !foreign_keys.contains(&columns) {
+                    foreign_keys.push(columns);
+                }
+            }
+
+            for columns in foreign_keys {
+                let is_covered = table
+                    .indexes
+                    .iter()
+                    .map(|index| &index.columns)
+                    .chain(std::iter::once(&table.primary_key_columns))
+                    .any(|covering| covering.starts_with(&columns));
+
+                if !is_covered {
+                    table.indexes.push(Index {
+                        name: format!("{}.{}{}", table.name, columns.join("_"), FOREIGN_KEY_INDEX_SUFFIX),
+                        columns,
+                        tpe: crate::database_inspector::IndexType::Normal,
+                        predicate: None,
+                        sort_orders: Vec::new(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Rows of tables whose required foreign keys form a cycle can only be created if the foreign keys
+    /// are checked at the end of the transaction. Self references are fine, as a row can reference itself.
+    fn defer_required_foreign_key_cycles(&self, tables: &mut [Table]) -> SqlResult<()> {
+        let required_references: Vec<(String, String)> = tables
+            .iter()
+            .flat_map(|table| {
+                table.columns.iter().filter(|c| c.is_required).filter_map(move |c| {
+                    c.foreign_key
+                        .as_ref()
+                        .filter(|fk| fk.table != table.name)
+                        .map(|fk| (table.name.clone(), fk.table.clone()))
+                })
+            })
+            .collect();
+
+        for table in tables.iter_mut() {
+            for column in table.columns.iter_mut().filter(|c| c.is_required) {
+                if let Some(foreign_key) = column.foreign_key.as_mut() {
+                    if foreign_key.table == table.name
+                        || !reaches(&required_references, &foreign_key.table, &table.name)
+                    {
+                        continue;
+                    }
+
+                    if self.sql_family != SqlFamily::Postgres {
+                        return Err(format!(
+                            "The required relations between the tables {} and {} form a cycle, so neither row can be created first. Deferred foreign keys are not supported on {}. Make one of the relation fields optional.",
+                            table.name,
+                            foreign_key.table,
+                            self.sql_family.connector_type_string()
+                        )
+                        .into());
+                    }
+
+                    foreign_key.deferrable = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn calculate_relation_tables(&self) -> SqlResult<Vec<Table>> {
         let mut result = Vec::new();
         for relation in self.calculate_relations().iter() {
             match &relation.manifestation {
                 TempManifestationHolder::Table => {
+                    let mut foreign_key_a = ForeignKey::new(
+                        relation.model_a.db_name().to_string(),
+                        relation.model_a.id_field()?.db_name(),
+                        OnDelete::Cascade,
+                    );
+                    foreign_key_a.schema = relation.model_a.table_schema(self.sql_family);
+                    let mut foreign_key_b = ForeignKey::new(
+                        relation.model_b.db_name().to_string(),
+                        relation.model_b.id_field()?.db_name(),
+                        OnDelete::Cascade,
+                    );
+                    foreign_key_b.schema = relation.model_b.table_schema(self.sql_family);
+                    // Relation tables always live in the schema of the connector.
                     let table = Table {
                         name: relation.table_name(),
+                        schema: None,
+                        comment: None,
                         columns: vec![
                             Column::with_foreign_key(
                                 relation.model_a_column(),
-                                column_type(relation.model_a.id_field()?),
+                                self.column_type(relation.model_a.id_field()?),
                                 true,
-                                ForeignKey::new(
-                                    relation.model_a.db_name(),
-                                    relation.model_a.id_field()?.db_name(),
-                                    OnDelete::Cascade,
-                                ),
+                                foreign_key_a,
                             ),
                             Column::with_foreign_key(
                                 relation.model_b_column(),
-                                column_type(relation.model_b.id_field()?),
+                                self.column_type(relation.model_b.id_field()?),
                                 true,
-                                ForeignKey::new(
-                                    relation.model_b.db_name(),
-                                    relation.model_b.id_field()?.db_name(),
-                                    OnDelete::Cascade,
-                                ),
+                                foreign_key_b,
                             ),
                         ],
                         indexes: Vec::new(),
@@ -173,10 +362,212 @@ impl<'a> DatabaseSchemaCalculator<'a> {
     }
 
     fn calculate_relations(&self) -> Vec<TempRelationHolder> {
-        DatamodelConverter::calculate_relations(&self.data_model)
+        DatamodelConverter::calculate_relations(&self.data_model, self.relation_table_naming)
+    }
+
+    /// Scalar lists are stored in an array column if the family supports it and the field opts into the embedded strategy.
+    /// Otherwise they are stored in a separate table.
+    fn is_array_column(&self, field: &Field) -> bool {
+        self.sql_family.capabilities().scalar_list_arrays
+            && field.scalar_list_strategy == Some(ScalarListStrategy::Embedded)
+    }
+
+    /// Ids with the `SEQUENCE` strategy take their values from a sequence, named after the table and column unless
+    /// the datamodel names it. Only Postgres creates sequences, other families treat those ids like `AUTO` ones.
+    fn sequence(&self, model: &Model, field: &Field) -> Option<Sequence> {
+        let id_info = field
+            .id_info
+            .as_ref()
+            .filter(|id_info| id_info.strategy == IdStrategy::Sequence)?;
+
+        if self.sql_family != SqlFamily::Postgres {
+            return None;
+        }
+
+        let sequence = match &id_info.sequence {
+            Some(sequence) => Sequence {
+                name: sequence.name.clone(),
+                initial_value: sequence.initial_value as u32,
+            },
+            None => Sequence {
+                name: format!("{}_{}_seq", model.db_name(), field.db_name()),
+                initial_value: 1,
+            },
+        };
+
+        Some(sequence)
+    }
+
+    /// Documentation is stored as a comment in the database. SQLite does not support comments.
+    fn comment(&self, documentation: &Option<String>) -> Option<String> {
+        match self.sql_family {
+            SqlFamily::Sqlite => None,
+            _ => documentation.clone(),
+        }
+    }
+
+    /// Fields without a default fall back to a migration value, so required columns can be added to tables with rows.
+    /// Native uuid columns cannot hold the empty string, so they only get the default of their field. The `text`
+    /// columns of xml fields on MySQL cannot have a default at all.
+    fn default(&self, field: &Field) -> Option<Value> {
+        match self.column_type(field) {
+            ColumnType::Uuid => field.default_value.clone(),
+            ColumnType::Xml if self.sql_family == SqlFamily::Mysql => None,
+            _ => Some(field.migration_value(&self.data_model)),
+        }
+    }
+
+    fn check(&self, field: &Field) -> Option<String> {
+        field
+            .check
+            .clone()
+            .filter(|_| self.sql_family.capabilities().check_constraints)
+    }
+
+    /// Native types fall back to the column type of their scalar type on families that do not support them.
+    fn column_type(&self, field: &Field) -> ColumnType {
+        match field.native_type {
+            Some(NativeType::Timestamptz) if self.sql_family == SqlFamily::Postgres => ColumnType::DateTimeTz,
+            Some(NativeType::Uuid) if self.sql_family == SqlFamily::Postgres => ColumnType::Uuid,
+            _ => match column_type(field) {
+                ColumnType::BigInt if self.sql_family == SqlFamily::Sqlite => ColumnType::Int,
+                column_type => column_type,
+            },
+        }
     }
 }
 
+/// See `Field::on_delete_strategy` for the defaults.
+fn on_delete(field: &Field) -> OnDelete {
+    match field.on_delete_strategy() {
+        Some(OnDeleteStrategy::Cascade) => OnDelete::Cascade,
+        Some(OnDeleteStrategy::SetDefault) => OnDelete::SetDefault,
+        Some(OnDeleteStrategy::Restrict) => OnDelete::Restrict,
+        Some(OnDeleteStrategy::SetNull) | Some(OnDeleteStrategy::None) | None => OnDelete::SetNull,
+    }
+}
+
+fn fk_name(field: &Field) -> Option<String> {
+    match &field.field_type {
+        FieldType::Relation(relation_info) => relation_info.fk_name.clone(),
+        _ => None,
+    }
+}
+
+/// The field an inline foreign key points to: the field given in `references`, or the id of the related model.
+/// The foreign key uses its database name, which differs from the field name if the field is mapped.
+fn referenced_field<'a>(relation: &TempRelationHolder, related_model: &'a Model) -> Result<&'a Field, String> {
+    let referenced = [&relation.field_a, &relation.field_b]
+        .iter()
+        .find_map(|field| match &field.field_type {
+            FieldType::Relation(info) if info.to == related_model.name && info.to_fields.len() == 1 => {
+                related_model.find_field(&info.to_fields[0])
+            }
+            _ => None,
+        });
+
+    match referenced {
+        Some(field) => Ok(field),
+        None => related_model.id_field(),
+    }
+}
+
+fn on_update(field: &Field) -> OnUpdate {
+    match &field.field_type {
+        FieldType::Relation(relation_info) => match relation_info.on_update {
+            OnUpdateStrategy::Cascade => OnUpdate::Cascade,
+            OnUpdateStrategy::SetNull => OnUpdate::SetNull,
+            OnUpdateStrategy::SetDefault => OnUpdate::SetDefault,
+            OnUpdateStrategy::Restrict => OnUpdate::Restrict,
+            OnUpdateStrategy::None => OnUpdate::NoAction,
+        },
+        _ => OnUpdate::NoAction,
+    }
+}
+
+/// Checks whether `to` can be reached from `from` by following the given references.
+fn reaches(references: &[(String, String)], from: &str, to: &str) -> bool {
+    let mut visited: Vec<&str> = Vec::new();
+    let mut stack = vec![from];
+
+    while let Some(table) = stack.pop() {
+        if table == to {
+            return true;
+        }
+
+        if visited.contains(&table) {
+            continue;
+        }
+
+        visited.push(table);
+        stack.extend(references.iter().filter(|(a, _)| a == table).map(|(_, b)| b.as_str()));
+    }
+
+    false
+}
+
+fn calculate_index(model: &Model, index_definition: &IndexDefinition, sql_family: SqlFamily) -> SqlResult<Index> {
+    if index_definition.predicate.is_some() && sql_family == SqlFamily::Mysql {
+        return Err(format!(
+            "The index on the fields {} of Model {} declares a `where` predicate. Partial indexes are not supported on {}.",
+            index_definition.fields.join(", "),
+            model.name,
+            sql_family.connector_type_string()
+        )
+        .into());
+    }
+
+    if index_definition.tpe == datamodel::IndexType::Fulltext && sql_family == SqlFamily::Sqlite {
+        return Err(format!(
+            "The fulltext index on the fields {} of Model {} is not supported on {}.",
+            index_definition.fields.join(", "),
+            model.name,
+            sql_family.connector_type_string()
+        )
+        .into());
+    }
+
+    let mut columns = Vec::new();
+
+    for field_name in &index_definition.fields {
+        match model.find_field(field_name) {
+            Some(field) => columns.push(field.db_name()),
+            None => return Err(format!("Field {} for index not found in Model {}", field_name, model.name).into()),
+        }
+    }
+
+    let tpe = match index_definition.tpe {
+        datamodel::IndexType::Unique => crate::database_inspector::IndexType::Unique,
+        datamodel::IndexType::Normal => crate::database_inspector::IndexType::Normal,
+        datamodel::IndexType::Fulltext => crate::database_inspector::IndexType::Fulltext,
+    };
+
+    // Unnamed indexes get a name derived from the table and columns.
+    // It must not end with `._UNIQUE`, as that suffix is reserved for the indexes of fields marked with `@unique`.
+    let name = index_definition.name.clone().unwrap_or_else(|| {
+        let suffix = match tpe {
+            crate::database_inspector::IndexType::Unique => "UNIQUE_INDEX",
+            crate::database_inspector::IndexType::Normal => "INDEX",
+            crate::database_inspector::IndexType::Fulltext => "FULLTEXT_INDEX",
+        };
+        format!("{}.{}._{}", model.db_name(), columns.join("_"), suffix)
+    });
+
+    // MySQL before 8.0 parses but ignores the sort order of index columns, so it could never be introspected.
+    let sort_orders = match sql_family {
+        SqlFamily::Mysql => Vec::new(),
+        _ => index_definition.sort_orders.clone(),
+    };
+
+    Ok(Index {
+        name,
+        columns,
+        tpe,
+        predicate: index_definition.predicate.clone(),
+        sort_orders,
+    })
+}
+
 #[derive(PartialEq, Debug)]
 struct ModelTable {
     table: Table,
@@ -184,22 +575,79 @@ struct ModelTable {
 }
 
 pub trait ModelExtensions {
+    /// The single id field of the model. Models identified by `@@id` have none, see `primary_key_columns`.
     fn id_field(&self) -> Result<&Field, String>;
 
-    fn db_name(&self) -> String;
+    /// The columns of the id fields of the model, in the order of the `@@id` directive.
+    fn primary_key_columns(&self) -> Result<Vec<String>, String>;
+
+    /// The schema the table of the model lives in, if it is not the schema of the connector.
+    /// Families without support for multiple schemas ignore `@@schema`.
+    fn table_schema(&self, sql_family: SqlFamily) -> Option<String>;
+
+    /// Whether the column of a `@unique` field gets its own `._UNIQUE` index. A field that is also the only field
+    /// of a named `@@unique` just gets the named index.
+    fn has_field_unique_index(&self, field: &Field) -> bool;
+
+    /// Whether the index is an unnamed `@@unique` on a single `@unique` field, which already has its own index.
+    fn duplicates_field_unique_index(&self, index_definition: &IndexDefinition) -> bool;
 }
 
 impl ModelExtensions for Model {
     fn id_field(&self) -> Result<&Field, String> {
         match self.fields().find(|f| f.is_id()) {
             Some(f) => Ok(f),
+            None if !self.compound_id_fields.is_empty() => Err(format!(
+                "Model {} is identified by `@@id([{}])`. Relations and scalar lists cannot reference models with compound ids yet.",
+                self.name,
+                self.compound_id_fields.join(", ")
+            )),
             None => Err(format!("Model {} does not have an id field", self.name)),
         }
     }
 
-    fn db_name(&self) -> String {
-        self.database_name.clone().unwrap_or_else(|| self.name.clone())
+    fn primary_key_columns(&self) -> Result<Vec<String>, String> {
+        if self.compound_id_fields.is_empty() {
+            return Ok(vec![self.id_field()?.db_name()]);
+        }
+
+        self.compound_id_fields
+            .iter()
+            .map(|name| match self.find_field(name) {
+                Some(field) => Ok(field.db_name()),
+                None => Err(format!("The id field {} does not exist on model {}", name, self.name)),
+            })
+            .collect()
+    }
+
+    fn table_schema(&self, sql_family: SqlFamily) -> Option<String> {
+        if sql_family.capabilities().multiple_schemas {
+            self.schema.clone()
+        } else {
+            None
+        }
     }
+
+    fn has_field_unique_index(&self, field: &Field) -> bool {
+        field.is_unique
+            && !self
+                .indices
+                .iter()
+                .any(|index| index.name.is_some() && is_single_field_unique(index, field))
+    }
+
+    fn duplicates_field_unique_index(&self, index_definition: &IndexDefinition) -> bool {
+        index_definition.name.is_none()
+            && self
+                .fields()
+                .any(|field| field.is_unique && is_single_field_unique(index_definition, field))
+    }
+}
+
+fn is_single_field_unique(index_definition: &IndexDefinition, field: &Field) -> bool {
+    index_definition.tpe == datamodel::IndexType::Unique
+        && index_definition.predicate.is_none()
+        && index_definition.fields == [field.name.clone()]
 }
 
 pub trait FieldExtensions {
@@ -235,8 +683,10 @@ impl FieldExtensions for Field {
         self.default_value.clone().unwrap_or_else(|| match self.field_type {
             FieldType::Base(PrismaType::Boolean) => Value::Boolean(false),
             FieldType::Base(PrismaType::Int) => Value::Int(0),
+            FieldType::Base(PrismaType::BigInt) => Value::BigInt(0),
             FieldType::Base(PrismaType::Float) => Value::Float(0.0),
             FieldType::Base(PrismaType::String) => Value::String("".to_string()),
+            FieldType::Base(PrismaType::Xml) => Value::String("".to_string()),
             FieldType::Base(PrismaType::Decimal) => Value::Decimal(0.0),
             FieldType::Base(PrismaType::DateTime) => {
                 let naive = NaiveDateTime::from_timestamp(0, 0);
@@ -269,7 +719,7 @@ fn is_scalar(field: &Field) -> bool {
 fn column_type(field: &Field) -> ColumnType {
     match &field.field_type {
         FieldType::Base(ref scalar) => column_type_for_scalar_type(&scalar),
-        FieldType::Enum(_) => ColumnType::String,
+        FieldType::Enum(enum_name) => ColumnType::Enum(enum_name.clone()),
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
             x, field.name
@@ -280,10 +730,12 @@ fn column_type(field: &Field) -> ColumnType {
 fn column_type_for_scalar_type(scalar_type: &ScalarType) -> ColumnType {
     match scalar_type {
         ScalarType::Int => ColumnType::Int,
+        ScalarType::BigInt => ColumnType::BigInt,
         ScalarType::Float => ColumnType::Float,
         ScalarType::Boolean => ColumnType::Boolean,
         ScalarType::String => ColumnType::String,
         ScalarType::DateTime => ColumnType::DateTime,
+        ScalarType::Xml => ColumnType::Xml,
         ScalarType::Decimal => unimplemented!(),
     }
 }
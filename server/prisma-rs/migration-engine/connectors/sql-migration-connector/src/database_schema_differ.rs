@@ -1,11 +1,16 @@
-use crate::database_inspector::{Column, DatabaseSchema, Table};
+use crate::database_inspector::{Column, DatabaseSchema, Index, Sequence, Table};
 use crate::*;
 
 const MIGRATION_TABLE_NAME: &str = "_Migration";
+/// Indexes for fields marked with `@unique` are still inferred based on the datamodel diff.
+const FIELD_UNIQUE_INDEX_SUFFIX: &str = "._UNIQUE";
+/// Postgres silently truncates longer identifiers, e.g. the generated names of indexes over several columns.
+const POSTGRES_IDENTIFIER_MAX_LENGTH: usize = 63;
 
 pub struct DatabaseSchemaDiffer<'a> {
     previous: &'a DatabaseSchema,
     next: &'a DatabaseSchema,
+    sql_family: SqlFamily,
 }
 
 #[derive(Clone)]
@@ -13,25 +18,48 @@ pub struct DatabaseSchemaDiff {
     pub drop_tables: Vec<DropTable>,
     pub create_tables: Vec<CreateTable>,
     pub alter_tables: Vec<AlterTable>,
+    pub create_indexes: Vec<CreateIndex>,
+    pub drop_indexes: Vec<DropIndex>,
+    pub set_comments: Vec<SetComment>,
+    pub create_sequences: Vec<CreateSequence>,
+    pub drop_sequences: Vec<DropSequence>,
 }
 
 impl DatabaseSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
         let mut steps = Vec::new();
+        steps.append(&mut wrap_as_step(self.drop_indexes, |x| SqlMigrationStep::DropIndex(x)));
         steps.append(&mut wrap_as_step(self.drop_tables, |x| SqlMigrationStep::DropTable(x)));
+        steps.append(&mut wrap_as_step(self.create_sequences, |x| {
+            SqlMigrationStep::CreateSequence(x)
+        }));
         steps.append(&mut wrap_as_step(self.create_tables, |x| {
             SqlMigrationStep::CreateTable(x)
         }));
         steps.append(&mut wrap_as_step(self.alter_tables, |x| {
             SqlMigrationStep::AlterTable(x)
         }));
+        steps.append(&mut wrap_as_step(self.set_comments, |x| {
+            SqlMigrationStep::SetComment(x)
+        }));
+        steps.append(&mut wrap_as_step(self.create_indexes, |x| {
+            SqlMigrationStep::CreateIndex(x)
+        }));
+        // Sequences are only dropped once no column uses them anymore.
+        steps.append(&mut wrap_as_step(self.drop_sequences, |x| {
+            SqlMigrationStep::DropSequence(x)
+        }));
         steps
     }
 }
 
 impl<'a> DatabaseSchemaDiffer<'a> {
-    pub fn diff(previous: &DatabaseSchema, next: &DatabaseSchema) -> DatabaseSchemaDiff {
-        let differ = DatabaseSchemaDiffer { previous, next };
+    pub fn diff(previous: &DatabaseSchema, next: &DatabaseSchema, sql_family: SqlFamily) -> DatabaseSchemaDiff {
+        let differ = DatabaseSchemaDiffer {
+            previous,
+            next,
+            sql_family,
+        };
         differ.diff_internal()
     }
 
@@ -40,15 +68,21 @@ impl<'a> DatabaseSchemaDiffer<'a> {
             drop_tables: self.drop_tables(),
             create_tables: self.create_tables(),
             alter_tables: self.alter_tables(),
+            create_indexes: self.create_indexes(),
+            drop_indexes: self.drop_indexes(),
+            set_comments: self.set_comments(),
+            create_sequences: self.create_sequences(),
+            drop_sequences: self.drop_sequences(),
         }
     }
 
     fn create_tables(&self) -> Vec<CreateTable> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
-            if !self.previous.has_table(&next_table.name) && next_table.name != MIGRATION_TABLE_NAME {
+            if Self::find_table(self.previous, next_table).is_none() && next_table.name != MIGRATION_TABLE_NAME {
                 let create = CreateTable {
                     name: next_table.name.clone(),
+                    schema: next_table.schema.clone(),
                     columns: Self::column_descriptions(&next_table.columns),
                     primary_columns: next_table.primary_key_columns.clone(),
                 };
@@ -61,9 +95,10 @@ impl<'a> DatabaseSchemaDiffer<'a> {
     fn drop_tables(&self) -> Vec<DropTable> {
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if !self.next.has_table(&previous_table.name) && previous_table.name != MIGRATION_TABLE_NAME {
+            if Self::find_table(self.next, previous_table).is_none() && previous_table.name != MIGRATION_TABLE_NAME {
                 let drop = DropTable {
                     name: previous_table.name.clone(),
+                    schema: previous_table.schema.clone(),
                 };
                 result.push(drop);
             }
@@ -75,7 +110,7 @@ impl<'a> DatabaseSchemaDiffer<'a> {
         // TODO: this does not diff primary key columns yet
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if let Ok(next_table) = self.next.table(&previous_table.name) {
+            if let Some(next_table) = Self::find_table(self.next, previous_table) {
                 let mut changes = Vec::new();
                 changes.append(&mut Self::drop_columns(&previous_table, &next_table));
                 changes.append(&mut Self::add_columns(&previous_table, &next_table));
@@ -84,6 +119,7 @@ impl<'a> DatabaseSchemaDiffer<'a> {
                 if !changes.is_empty() {
                     let update = AlterTable {
                         table: previous_table.name.clone(),
+                        schema: previous_table.schema.clone(),
                         changes,
                     };
                     result.push(update);
@@ -93,6 +129,190 @@ impl<'a> DatabaseSchemaDiffer<'a> {
         result
     }
 
+    fn create_indexes(&self) -> Vec<CreateIndex> {
+        let mut result = Vec::new();
+        for next_table in &self.next.tables {
+            let previous_table = Self::find_table(self.previous, next_table);
+            for index in Self::diffable_indexes(next_table) {
+                let previous_index = previous_table
+                    .and_then(|t| t.indexes.iter().find(|i| self.index_names_match(&i.name, &index.name)));
+                if !previous_index.map_or(false, |previous| self.indexes_match(previous, index)) {
+                    result.push(Self::create_index(next_table, index));
+                }
+            }
+        }
+        result
+    }
+
+    fn drop_indexes(&self) -> Vec<DropIndex> {
+        let mut result = Vec::new();
+        for previous_table in &self.previous.tables {
+            // Indexes of dropped tables are dropped together with the table.
+            if let Some(next_table) = Self::find_table(self.next, previous_table) {
+                for index in Self::diffable_indexes(previous_table) {
+                    let next_index = next_table
+                        .indexes
+                        .iter()
+                        .find(|i| self.index_names_match(&index.name, &i.name));
+                    if !next_index.map_or(false, |next| self.indexes_match(index, next)) {
+                        result.push(DropIndex {
+                            table: previous_table.name.clone(),
+                            schema: previous_table.schema.clone(),
+                            name: index.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Created tables and columns are commented separately as well. Altered columns are recreated, which drops
+    /// their comment, so it is set again.
+    fn set_comments(&self) -> Vec<SetComment> {
+        let mut result = Vec::new();
+        for next_table in self.next.tables.iter().filter(|t| t.name != MIGRATION_TABLE_NAME) {
+            let previous_table = Self::find_table(self.previous, next_table);
+
+            if previous_table.and_then(|t| t.comment.as_ref()) != next_table.comment.as_ref() {
+                result.push(SetComment {
+                    table: next_table.name.clone(),
+                    schema: next_table.schema.clone(),
+                    column: None,
+                    comment: next_table.comment.clone(),
+                });
+            }
+
+            for next_column in next_table.columns.iter() {
+                let previous_comment = previous_table
+                    .and_then(|t| t.column(&next_column.name))
+                    .filter(|previous_column| !previous_column.differs_in_something_except_default(next_column))
+                    .and_then(|previous_column| previous_column.comment.as_ref());
+
+                if previous_comment != next_column.comment.as_ref() {
+                    result.push(SetComment {
+                        table: next_table.name.clone(),
+                        schema: next_table.schema.clone(),
+                        column: Some(Self::column_description(next_column)),
+                        comment: next_column.comment.clone(),
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// Sequences are matched by name. They are created before the tables whose columns take their values from them.
+    fn create_sequences(&self) -> Vec<CreateSequence> {
+        let previous_sequences = Self::sequences(self.previous);
+
+        Self::sequences(self.next)
+            .into_iter()
+            .filter(|next| !previous_sequences.iter().any(|previous| previous.name == next.name))
+            .map(|sequence| CreateSequence {
+                name: sequence.name.clone(),
+                initial_value: sequence.initial_value,
+            })
+            .collect()
+    }
+
+    /// A sequence with a different initial value is not recreated, as that would reset it.
+    fn drop_sequences(&self) -> Vec<DropSequence> {
+        let next_sequences = Self::sequences(self.next);
+
+        Self::sequences(self.previous)
+            .into_iter()
+            .filter(|previous| !next_sequences.iter().any(|next| previous.name == next.name))
+            .map(|sequence| DropSequence {
+                name: sequence.name.clone(),
+            })
+            .collect()
+    }
+
+    fn sequences(schema: &DatabaseSchema) -> Vec<&Sequence> {
+        schema
+            .tables
+            .iter()
+            .flat_map(|t| t.columns.iter())
+            .filter_map(|c| c.sequence.as_ref())
+            .collect()
+    }
+
+    /// Tables are matched by name and schema, so a table moved to another schema is dropped and created anew.
+    fn find_table<'b>(schema: &'b DatabaseSchema, table: &Table) -> Option<&'b Table> {
+        schema
+            .tables
+            .iter()
+            .find(|t| t.name == table.name && t.schema == table.schema)
+    }
+
+    pub fn diffable_indexes(table: &Table) -> impl Iterator<Item = &Index> {
+        table
+            .indexes
+            .iter()
+            .filter(|i| !i.name.ends_with(FIELD_UNIQUE_INDEX_SUFFIX))
+    }
+
+    pub fn create_index(table: &Table, index: &Index) -> CreateIndex {
+        CreateIndex {
+            table: table.name.clone(),
+            schema: table.schema.clone(),
+            name: index.name.clone(),
+            tpe: Self::convert_index_type(&index.tpe),
+            columns: index.columns.clone(),
+            predicate: index.predicate.clone(),
+            sort_orders: index.sort_orders.clone(),
+        }
+    }
+
+    /// Indexes match if they cover the same columns, in the same order, and have the same type, so a unique index
+    /// never matches a normal one. Databases report columns and the predicate of a partial index in their own
+    /// normalized form, e.g. Postgres wraps predicates in parentheses and quotes identifiers. Both are therefore
+    /// compared ignoring those differences.
+    fn indexes_match(&self, previous: &Index, next: &Index) -> bool {
+        let normalize_columns = |index: &Index| {
+            index
+                .columns
+                .iter()
+                .map(|column| column.trim().trim_matches(|c| c == '"' || c == '`').to_string())
+                .collect::<Vec<String>>()
+        };
+        let normalize = |predicate: &Option<String>| {
+            predicate.as_ref().map(|p| {
+                p.chars()
+                    .filter(|c| !c.is_whitespace() && !"()\"".contains(*c))
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+        };
+
+        self.index_names_match(&previous.name, &next.name)
+            && normalize_columns(previous) == normalize_columns(next)
+            && previous.tpe == next.tpe
+            && normalize(&previous.predicate) == normalize(&next.predicate)
+            && previous.sort_orders == next.sort_orders
+    }
+
+    /// The previous index is introspected, so its name may be the truncated name of the next index on Postgres.
+    /// Without this, an unchanged index with a long name would be dropped and recreated on every migration.
+    /// Other families keep the names as they are, so they have to match exactly.
+    fn index_names_match(&self, previous: &str, next: &str) -> bool {
+        if previous == next {
+            return true;
+        }
+
+        if self.sql_family != SqlFamily::Postgres {
+            return false;
+        }
+
+        let mut truncated_length = POSTGRES_IDENTIFIER_MAX_LENGTH;
+        while truncated_length < next.len() && !next.is_char_boundary(truncated_length) {
+            truncated_length -= 1;
+        }
+
+        next.len() > POSTGRES_IDENTIFIER_MAX_LENGTH && previous == &next[..truncated_length]
+    }
+
     fn drop_columns(previous: &Table, next: &Table) -> Vec<TableChange> {
         let mut result = Vec::new();
         for previous_column in &previous.columns {
@@ -129,6 +349,22 @@ impl<'a> DatabaseSchemaDiffer<'a> {
                         column: Self::column_description(next_column),
                     };
                     result.push(TableChange::AlterColumn(change));
+                } else {
+                    if previous_column.comparable_default() != next_column.comparable_default() {
+                        let change = AlterColumnDefault {
+                            name: next_column.name.clone(),
+                            tpe: Self::convert_column_type(&next_column.tpe),
+                            default: next_column.comparable_default(),
+                        };
+                        result.push(TableChange::AlterColumnDefault(change));
+                    }
+                    if previous_column.differs_in_check(next_column) {
+                        let change = AlterColumnCheck {
+                            name: next_column.name.clone(),
+                            check: next_column.check.clone(),
+                        };
+                        result.push(TableChange::AlterColumnCheck(change));
+                    }
                 }
             }
         }
@@ -141,16 +377,25 @@ impl<'a> DatabaseSchemaDiffer<'a> {
 
     fn column_description(column: &Column) -> ColumnDescription {
         let fk = column.foreign_key.as_ref().map(|fk| ForeignKey {
+            name: fk.name.clone(),
             table: fk.table.clone(),
             column: fk.column.clone(),
+            schema: fk.schema.clone(),
             on_delete: Self::convert_on_delete(fk.on_delete),
+            on_update: Self::convert_on_update(fk.on_update),
+            deferrable: fk.deferrable,
+            composite_columns: fk.composite_columns.clone(),
         });
         ColumnDescription {
             name: column.name.clone(),
-            tpe: Self::convert_column_type(column.tpe),
+            tpe: Self::convert_column_type(&column.tpe),
             required: column.is_required,
+            is_list: column.is_list,
             foreign_key: fk,
             default: column.default.clone(),
+            comment: column.comment.clone(),
+            sequence: column.sequence.as_ref().map(|s| s.name.clone()),
+            check: column.check.clone(),
         }
     }
 
@@ -158,17 +403,44 @@ impl<'a> DatabaseSchemaDiffer<'a> {
         match on_delete {
             database_inspector::OnDelete::NoAction => OnDelete::NoAction,
             database_inspector::OnDelete::SetNull => OnDelete::SetNull,
+            database_inspector::OnDelete::SetDefault => OnDelete::SetDefault,
+            database_inspector::OnDelete::Restrict => OnDelete::Restrict,
             database_inspector::OnDelete::Cascade => OnDelete::Cascade,
         }
     }
 
-    pub fn convert_column_type(inspector_type: database_inspector::ColumnType) -> ColumnType {
+    fn convert_on_update(on_update: database_inspector::OnUpdate) -> OnUpdate {
+        match on_update {
+            database_inspector::OnUpdate::NoAction => OnUpdate::NoAction,
+            database_inspector::OnUpdate::SetNull => OnUpdate::SetNull,
+            database_inspector::OnUpdate::SetDefault => OnUpdate::SetDefault,
+            database_inspector::OnUpdate::Restrict => OnUpdate::Restrict,
+            database_inspector::OnUpdate::Cascade => OnUpdate::Cascade,
+        }
+    }
+
+    fn convert_index_type(index_type: &database_inspector::IndexType) -> IndexType {
+        match index_type {
+            database_inspector::IndexType::Unique => IndexType::Unique,
+            database_inspector::IndexType::Normal => IndexType::Normal,
+            database_inspector::IndexType::Fulltext => IndexType::Fulltext,
+        }
+    }
+
+    /// Enum columns are stored as strings.
+    pub fn convert_column_type(inspector_type: &database_inspector::ColumnType) -> ColumnType {
         match inspector_type {
             database_inspector::ColumnType::Boolean => ColumnType::Boolean,
             database_inspector::ColumnType::Int => ColumnType::Int,
+            database_inspector::ColumnType::BigInt => ColumnType::BigInt,
             database_inspector::ColumnType::Float => ColumnType::Float,
             database_inspector::ColumnType::String => ColumnType::String,
             database_inspector::ColumnType::DateTime => ColumnType::DateTime,
+            database_inspector::ColumnType::DateTimeTz => ColumnType::DateTimeTz,
+            database_inspector::ColumnType::Xml => ColumnType::Xml,
+            database_inspector::ColumnType::Uuid => ColumnType::Uuid,
+            database_inspector::ColumnType::Enum(_) => ColumnType::String,
+            database_inspector::ColumnType::Unsupported(tpe) => ColumnType::Unsupported(tpe.clone()),
         }
     }
 }
@@ -0,0 +1,332 @@
+use crate::database_inspector::*;
+use crate::database_schema_calculator::FOREIGN_KEY_INDEX_SUFFIX;
+use crate::SqlResult;
+use datamodel::*;
+use std::collections::HashSet;
+
+/// Generates a `Datamodel` from an introspected `DatabaseSchema`.
+///
+/// This is the inverse of the `DatabaseSchemaCalculator`: tables become models,
+/// columns become fields and foreign keys become relation fields. Back relations
+/// are not generated here, the standardiser adds them when the rendered datamodel
+/// is parsed again.
+pub struct DatamodelIntrospector<'a> {
+    database_schema: &'a DatabaseSchema,
+}
+
+impl<'a> DatamodelIntrospector<'a> {
+    pub fn introspect(database_schema: &DatabaseSchema) -> SqlResult<Datamodel> {
+        let introspector = DatamodelIntrospector { database_schema };
+        introspector.introspect_internal()
+    }
+
+    fn introspect_internal(&self) -> SqlResult<Datamodel> {
+        let mut datamodel = Datamodel::new();
+
+        for table in self.model_tables() {
+            datamodel.add_model(self.calculate_model(table)?);
+        }
+
+        add_back_relation_fields_of_named_relations(&mut datamodel)?;
+
+        for table in self.scalar_list_tables() {
+            self.add_scalar_list_field(&mut datamodel, table)?;
+        }
+
+        Ok(datamodel)
+    }
+
+    /// Tables prefixed with an underscore are managed by Prisma itself (e.g. `_Migration` or relation tables).
+    fn model_tables(&self) -> impl Iterator<Item = &Table> {
+        self.database_schema
+            .tables
+            .iter()
+            .filter(|t| !t.name.starts_with('_') && !is_scalar_list_table(t))
+    }
+
+    fn scalar_list_tables(&self) -> impl Iterator<Item = &Table> {
+        self.database_schema.tables.iter().filter(|t| is_scalar_list_table(t))
+    }
+
+    fn calculate_model(&self, table: &Table) -> SqlResult<Model> {
+        let mut model = Model::new(&table.name);
+        model.schema = table.schema.clone();
+        model.documentation = table.comment.clone();
+
+        for column in table.columns.iter() {
+            let mut field = match &column.foreign_key {
+                Some(foreign_key) => self.calculate_relation_field(table, column, foreign_key)?,
+                None => calculate_scalar_field(column),
+            };
+            field.documentation = column.comment.clone();
+
+            if table.primary_key_columns.len() == 1 && table.primary_key_columns[0] == column.name {
+                field.id_info = Some(match &column.sequence {
+                    Some(sequence) => IdInfo {
+                        strategy: IdStrategy::Sequence,
+                        sequence: Some(datamodel::Sequence {
+                            name: sequence.name.clone(),
+                            initial_value: sequence.initial_value as i32,
+                            allocation_size: 1,
+                        }),
+                    },
+                    None => IdInfo {
+                        strategy: IdStrategy::Auto,
+                        sequence: None,
+                    },
+                });
+            }
+
+            field.is_unique = field.id_info.is_none() && field_unique_index(table, column).is_some();
+
+            model.add_field(field);
+        }
+
+        if table.primary_key_columns.len() > 1 {
+            model.compound_id_fields = table.primary_key_columns.iter().map(|c| field_name(c)).collect();
+        }
+
+        let field_unique_indexes: HashSet<&str> = table
+            .columns
+            .iter()
+            .filter_map(|c| field_unique_index(table, c))
+            .map(|i| i.name.as_str())
+            .collect();
+
+        for index in table
+            .indexes
+            .iter()
+            .filter(|i| !field_unique_indexes.contains(i.name.as_str()))
+            // The indexes of foreign keys are implied by the relation.
+            .filter(|i| !i.name.ends_with(FOREIGN_KEY_INDEX_SUFFIX))
+        {
+            model.add_index(IndexDefinition {
+                name: Some(index.name.clone()),
+                fields: index.columns.iter().map(|c| field_name(c)).collect(),
+                tpe: match index.tpe {
+                    crate::database_inspector::IndexType::Unique => datamodel::IndexType::Unique,
+                    crate::database_inspector::IndexType::Normal => datamodel::IndexType::Normal,
+                    crate::database_inspector::IndexType::Fulltext => datamodel::IndexType::Fulltext,
+                },
+                predicate: index.predicate.clone(),
+                sort_orders: index.sort_orders.clone(),
+            });
+        }
+
+        Ok(model)
+    }
+
+    /// Relations are left unnamed, unless the table has several foreign keys to the same table. Then each
+    /// relation is named after its column, like `Post_authorToUser`, so that they can be told apart.
+    fn calculate_relation_field(&self, table: &Table, column: &Column, foreign_key: &ForeignKey) -> SqlResult<Field> {
+        let related_table = self.database_schema.table(&foreign_key.table)?;
+        let related_column = related_table.column(&foreign_key.column).ok_or_else(|| {
+            format!(
+                "Column {} referenced by {} not found in Table {}",
+                foreign_key.column, column.name, related_table.name
+            )
+        })?;
+
+        let mut relation_info = RelationInfo::new_with_field(&related_table.name, &field_name(&related_column.name));
+        relation_info.on_delete = on_delete_strategy(foreign_key.on_delete, column.is_required);
+        relation_info.on_update = on_update_strategy(foreign_key.on_update);
+
+        let foreign_keys_to_the_same_table = table
+            .columns
+            .iter()
+            .filter_map(|c| c.foreign_key.as_ref())
+            .filter(|fk| fk.table == foreign_key.table)
+            .count();
+        if foreign_keys_to_the_same_table > 1 {
+            relation_info.name = format!("{}_{}To{}", table.name, column.name, related_table.name);
+        }
+
+        let mut field = Field::new(&field_name(&column.name), FieldType::Relation(relation_info));
+        field.arity = arity(column);
+        field.database_name = database_name(column);
+
+        Ok(field)
+    }
+
+    fn add_scalar_list_field(&self, datamodel: &mut Datamodel, table: &Table) -> SqlResult<()> {
+        let node_id = table.column_bang("nodeId");
+        let value = table.column_bang("value");
+        let parent_table = match &node_id.foreign_key {
+            Some(foreign_key) => self.database_schema.table(&foreign_key.table)?,
+            None => return Err(format!("Scalar list table {} has no parent table", table.name).into()),
+        };
+
+        let column_name = table
+            .name
+            .strip_prefix(&format!("{}_", parent_table.name))
+            .ok_or_else(|| {
+                format!(
+                    "Scalar list table {} is not prefixed with the name of its parent table {}",
+                    table.name, parent_table.name
+                )
+            })?;
+        let mut field = Field::new(&field_name(column_name), field_type(&value.tpe));
+        field.arity = FieldArity::List;
+        field.native_type = native_type(&value.tpe);
+        if field.name != column_name {
+            field.database_name = Some(column_name.to_string());
+        }
+
+        match datamodel.find_model_mut(&parent_table.name) {
+            Some(model) => model.add_field(field),
+            None => return Err(format!("Model for Table {} not found", parent_table.name).into()),
+        }
+
+        Ok(())
+    }
+}
+
+/// The standardiser only adds the back relation fields of unnamed relations, so the named ones get theirs here.
+/// They are named after the model and the relation field, like `postAuthor`.
+fn add_back_relation_fields_of_named_relations(datamodel: &mut Datamodel) -> SqlResult<()> {
+    let mut back_relation_fields = Vec::new();
+
+    for model in datamodel.models() {
+        for field in model.fields() {
+            match &field.field_type {
+                FieldType::Relation(relation_info) if !relation_info.name.is_empty() => {
+                    let mut back_relation_info = RelationInfo::new(&model.name);
+                    back_relation_info.name = relation_info.name.clone();
+
+                    let mut back_relation_field = Field::new(
+                        &field_name(&format!("{}_{}", model.name, field.name)),
+                        FieldType::Relation(back_relation_info),
+                    );
+                    back_relation_field.arity = if field.is_unique {
+                        FieldArity::Optional
+                    } else {
+                        FieldArity::List
+                    };
+
+                    back_relation_fields.push((relation_info.to.clone(), back_relation_field));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    for (model_name, field) in back_relation_fields {
+        match datamodel.find_model_mut(&model_name) {
+            Some(model) => model.add_field(field),
+            None => return Err(format!("Model for Table {} not found", model_name).into()),
+        }
+    }
+
+    Ok(())
+}
+
+fn calculate_scalar_field(column: &Column) -> Field {
+    let mut field = Field::new(&field_name(&column.name), field_type(&column.tpe));
+    field.arity = arity(column);
+    field.native_type = native_type(&column.tpe);
+    if column.is_list {
+        field.scalar_list_strategy = Some(ScalarListStrategy::Embedded);
+    }
+    field.database_name = database_name(column);
+    field.default_value = column.default.clone();
+    field.check = column.check.clone();
+    field
+}
+
+/// Finds the index backing a field marked with `@unique`. Those are named `{table}.{column}._UNIQUE`.
+fn field_unique_index<'a>(table: &'a Table, column: &Column) -> Option<&'a Index> {
+    let name = format!("{}.{}._UNIQUE", table.name, column.name);
+
+    table.indexes.iter().find(|i| {
+        i.name == name
+            && i.tpe == crate::database_inspector::IndexType::Unique
+            && i.columns == vec![column.name.clone()]
+    })
+}
+
+/// The default of the calculator is left implicit, see `Field::on_delete_strategy`. `NO ACTION` only differs from
+/// `RESTRICT` in when it is checked. `SET DEFAULT` would require explicit foreign key fields, which are not
+/// introspected yet.
+fn on_delete_strategy(on_delete: OnDelete, is_required: bool) -> OnDeleteStrategy {
+    match on_delete {
+        OnDelete::Cascade if is_required => OnDeleteStrategy::None,
+        OnDelete::Cascade => OnDeleteStrategy::Cascade,
+        OnDelete::SetNull if !is_required => OnDeleteStrategy::None,
+        OnDelete::SetNull => OnDeleteStrategy::SetNull,
+        OnDelete::Restrict | OnDelete::NoAction => OnDeleteStrategy::Restrict,
+        OnDelete::SetDefault => OnDeleteStrategy::None,
+    }
+}
+
+/// `SET DEFAULT` would require explicit foreign key fields, which are not introspected yet.
+fn on_update_strategy(on_update: OnUpdate) -> OnUpdateStrategy {
+    match on_update {
+        OnUpdate::Cascade => OnUpdateStrategy::Cascade,
+        OnUpdate::SetNull => OnUpdateStrategy::SetNull,
+        OnUpdate::Restrict => OnUpdateStrategy::Restrict,
+        OnUpdate::NoAction | OnUpdate::SetDefault => OnUpdateStrategy::None,
+    }
+}
+
+fn is_scalar_list_table(table: &Table) -> bool {
+    table.primary_key_columns == vec!["nodeId".to_string(), "position".to_string()] && table.has_column("value")
+}
+
+fn arity(column: &Column) -> FieldArity {
+    if column.is_list {
+        FieldArity::List
+    } else if column.is_required {
+        FieldArity::Required
+    } else {
+        FieldArity::Optional
+    }
+}
+
+fn database_name(column: &Column) -> Option<String> {
+    if field_name(&column.name) != column.name {
+        Some(column.name.clone())
+    } else {
+        None
+    }
+}
+
+fn field_type(column_type: &ColumnType) -> FieldType {
+    match column_type {
+        ColumnType::Int => FieldType::Base(ScalarType::Int),
+        ColumnType::BigInt => FieldType::Base(ScalarType::BigInt),
+        ColumnType::Float => FieldType::Base(ScalarType::Float),
+        ColumnType::Boolean => FieldType::Base(ScalarType::Boolean),
+        ColumnType::String | ColumnType::Uuid => FieldType::Base(ScalarType::String),
+        ColumnType::DateTime | ColumnType::DateTimeTz => FieldType::Base(ScalarType::DateTime),
+        ColumnType::Xml => FieldType::Base(ScalarType::Xml),
+        ColumnType::Enum(enum_name) => FieldType::Enum(enum_name.clone()),
+        ColumnType::Unsupported(database_type) => FieldType::Unsupported(database_type.clone()),
+    }
+}
+
+fn native_type(column_type: &ColumnType) -> Option<NativeType> {
+    match column_type {
+        ColumnType::DateTimeTz => Some(NativeType::Timestamptz),
+        ColumnType::Uuid => Some(NativeType::Uuid),
+        _ => None,
+    }
+}
+
+/// Converts a column name like `created_at` or `CreatedAt` to the field name `createdAt`.
+fn field_name(column_name: &str) -> String {
+    let mut result = String::with_capacity(column_name.len());
+
+    for (index, part) in column_name.split('_').filter(|p| !p.is_empty()).enumerate() {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            if index == 0 {
+                result.extend(first.to_lowercase());
+            } else {
+                result.extend(first.to_uppercase());
+            }
+            result.push_str(chars.as_str());
+        }
+    }
+
+    result
+}
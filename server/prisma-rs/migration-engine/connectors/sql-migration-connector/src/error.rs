@@ -11,6 +11,9 @@ pub enum SqlError {
     ConnectionError(&'static str),
     #[fail(display = "Error querying the database: {}", _0)]
     QueryError(Error),
+    /// A step of a migration failed while the migration was verified, see `SqlMigrationVerifier`.
+    #[fail(display = "The migration could not be applied, step {} failed: {}", step, error)]
+    VerificationError { step: usize, sql: String, error: Error },
 }
 
 impl From<SqlError> for ConnectorError {
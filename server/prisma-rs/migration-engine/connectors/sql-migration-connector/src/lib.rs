@@ -6,19 +6,24 @@ pub mod migration_database;
 
 mod database_schema_calculator;
 mod database_schema_differ;
+mod datamodel_introspector;
 mod error;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
 mod sql_migration;
 mod sql_migration_persistence;
+mod sql_migration_verifier;
 
+pub use datamodel_introspector::*;
 pub use error::*;
 pub use sql_migration::*;
+pub use sql_migration_verifier::*;
 
 use database_inspector::{DatabaseInspector, sqlite_with_database, postgres_with_database, mysql_with_database};
 use migration_connector::*;
 use migration_database::*;
+use prisma_models::RelationTableNaming;
 use prisma_query::connector::{MysqlParams, PostgresParams};
 use serde_json;
 use sql_database_migration_inferrer::*;
@@ -41,6 +46,9 @@ pub struct SqlMigrationConnector {
     pub database_migration_step_applier: Arc<dyn DatabaseMigrationStepApplier<SqlMigration>>,
     pub destructive_changes_checker: Arc<dyn DestructiveChangesChecker<SqlMigration>>,
     pub database_inspector: Arc<dyn DatabaseInspector + Send + Sync + 'static>,
+    pub relation_table_naming: RelationTableNaming,
+    /// Whether the foreign keys of inline relations get an index on Postgres. Enabled by default.
+    pub foreign_key_indexes: bool,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -58,6 +66,23 @@ impl SqlFamily {
             SqlFamily::Sqlite => "sqlite",
         }
     }
+
+    /// The features of the datamodel this family supports, see `ConnectorCapabilities`.
+    pub fn capabilities(&self) -> datamodel::ConnectorCapabilities {
+        datamodel::ConnectorCapabilities::for_connector_type(self.connector_type_string())
+    }
+
+    /// Quotes an identifier (schema, table, column, index or constraint name) for this family.
+    ///
+    /// Identifiers are always quoted instead of only quoting reserved keywords: the keyword sets differ between
+    /// databases and versions, and Postgres would fold unquoted mixed-case identifiers to lower case.
+    /// Quote characters inside the identifier are escaped by doubling them.
+    pub(crate) fn quote(&self, identifier: &str) -> String {
+        match self {
+            SqlFamily::Sqlite | SqlFamily::Postgres => format!("\"{}\"", identifier.replace('"', "\"\"")),
+            SqlFamily::Mysql => format!("`{}`", identifier.replace('`', "``")),
+        }
+    }
 }
 
 impl SqlMigrationConnector {
@@ -152,10 +177,15 @@ impl SqlMigrationConnector {
             file_path: file_path.clone(),
         });
 
+        let relation_table_naming = RelationTableNaming::default();
+        let foreign_key_indexes = true;
+
         let database_migration_inferrer = Arc::new(SqlDatabaseMigrationInferrer {
             sql_family,
             inspector: Arc::clone(&inspector),
             schema_name: schema_name.to_string(),
+            relation_table_naming,
+            foreign_key_indexes,
         });
 
         let database_migration_step_applier = Arc::new(SqlDatabaseStepApplier {
@@ -176,8 +206,48 @@ impl SqlMigrationConnector {
             database_migration_step_applier,
             destructive_changes_checker,
             database_inspector: Arc::clone(&inspector),
+            relation_table_naming,
+            foreign_key_indexes,
         }
     }
+
+    /// Names the tables of many-to-many relations according to `relation_table_naming`, e.g. to match the
+    /// conventions of an existing database.
+    pub fn with_relation_table_naming(mut self, relation_table_naming: RelationTableNaming) -> Self {
+        self.relation_table_naming = relation_table_naming;
+        self.rebuild_database_migration_inferrer();
+        self
+    }
+
+    /// Enables or disables the indexes on the foreign keys of inline relations, see `foreign_key_indexes`.
+    pub fn with_foreign_key_indexes(mut self, foreign_key_indexes: bool) -> Self {
+        self.foreign_key_indexes = foreign_key_indexes;
+        self.rebuild_database_migration_inferrer();
+        self
+    }
+
+    /// Checks that a migration applies cleanly to a copy of the schema, without touching the schema itself.
+    /// See `SqlMigrationVerifier`.
+    pub fn verify_migration(&self, database_migration: &SqlMigration) -> crate::Result<()> {
+        let verifier = SqlMigrationVerifier {
+            sql_family: self.sql_family,
+            schema_name: self.schema_name.clone(),
+            conn: Arc::clone(&self.database),
+            inspector: Arc::clone(&self.database_inspector),
+        };
+
+        verifier.verify(database_migration)
+    }
+
+    fn rebuild_database_migration_inferrer(&mut self) {
+        self.database_migration_inferrer = Arc::new(SqlDatabaseMigrationInferrer {
+            sql_family: self.sql_family,
+            inspector: Arc::clone(&self.database_inspector),
+            schema_name: self.schema_name.clone(),
+            relation_table_naming: self.relation_table_naming,
+            foreign_key_indexes: self.foreign_key_indexes,
+        });
+    }
 }
 
 impl MigrationConnector for SqlMigrationConnector {
@@ -249,4 +319,8 @@ impl MigrationConnector for SqlMigrationConnector {
     fn deserialize_database_migration(&self, json: serde_json::Value) -> SqlMigration {
         serde_json::from_value(json).expect("Deserializing the database migration failed.")
     }
+
+    fn verify_migration(&self, database_migration: &SqlMigration) -> ConnectorResult<()> {
+        Ok(SqlMigrationConnector::verify_migration(self, database_migration)?)
+    }
 }
@@ -1,16 +1,20 @@
 use crate::database_inspector::{DatabaseInspector, DatabaseSchema, Table};
 use crate::database_schema_calculator::{DatabaseSchemaCalculator, FieldExtensions, ModelExtensions};
 use crate::database_schema_differ::{DatabaseSchemaDiff, DatabaseSchemaDiffer};
+use crate::IndexType;
 use crate::*;
 use datamodel::*;
 use migration_connector::steps::*;
 use migration_connector::*;
+use prisma_models::RelationTableNaming;
 use std::sync::Arc;
 
 pub struct SqlDatabaseMigrationInferrer {
     pub sql_family: SqlFamily,
     pub inspector: Arc<dyn DatabaseInspector + Send + Sync + 'static>,
     pub schema_name: String,
+    pub relation_table_naming: RelationTableNaming,
+    pub foreign_key_indexes: bool,
 }
 
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
@@ -20,8 +24,20 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         next: &Datamodel,
         steps: &Vec<MigrationStep>,
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema = self.inspector.introspect(&self.schema_name);
-        let expected_database_schema = DatabaseSchemaCalculator::calculate(next)?;
+        let mut current_database_schema = self.inspector.introspect(&self.schema_name);
+        for schema in other_schemas(previous, next, self.sql_family, &self.schema_name) {
+            let mut other_database_schema = self.inspector.introspect(&schema);
+            other_database_schema.qualify(&schema, &self.schema_name);
+            current_database_schema.tables.append(&mut other_database_schema.tables);
+        }
+        current_database_schema.annotate_enum_columns(previous);
+        let mut expected_database_schema = DatabaseSchemaCalculator::calculate(
+            next,
+            self.sql_family,
+            self.relation_table_naming,
+            self.foreign_key_indexes,
+        )?;
+        preserve_unsupported_columns(&current_database_schema, &mut expected_database_schema);
         infer(
             &current_database_schema,
             &expected_database_schema,
@@ -34,6 +50,39 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
     }
 }
 
+/// The schemas other than the one of the connector that the tables of either datamodel live in, see `@@schema`.
+fn other_schemas(previous: &Datamodel, next: &Datamodel, sql_family: SqlFamily, schema_name: &str) -> Vec<String> {
+    let mut schemas: Vec<String> = Vec::new();
+
+    for schema in previous
+        .models()
+        .chain(next.models())
+        .filter_map(|model| model.table_schema(sql_family))
+    {
+        if schema != schema_name && !schemas.contains(&schema) {
+            schemas.push(schema);
+        }
+    }
+
+    schemas
+}
+
+/// Columns of types Prisma cannot model are not part of the calculated schema. They are carried over from the
+/// database, so they are never dropped or altered, not even when SQLite has to recreate their table.
+fn preserve_unsupported_columns(current: &DatabaseSchema, expected: &mut DatabaseSchema) {
+    for expected_table in expected.tables.iter_mut() {
+        if let Ok(current_table) = current.table(&expected_table.name) {
+            for column in current_table.columns.iter() {
+                if let database_inspector::ColumnType::Unsupported(_) = column.tpe {
+                    if !expected_table.has_column(&column.name) {
+                        expected_table.columns.push(column.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn infer(
     current_database_schema: &DatabaseSchema,
     expected_database_schema: &DatabaseSchema,
@@ -49,13 +98,18 @@ fn infer(
         schema_name,
         sql_family,
     )?;
-    let mut datamodel_diff_based = infer_based_on_datamodel_diff(previous, next, model_steps)?;
+    let mut datamodel_diff_based = infer_based_on_datamodel_diff(previous, next, sql_family, model_steps)?;
+    let scalar_list_migrations = infer_scalar_list_migrations(previous, next, sql_family, model_steps)?;
     let mut combined_steps = Vec::new();
     let mut combined_rollback = Vec::new();
-    combined_steps.append(&mut db_schema_diff_based.steps);
+    combined_steps.append(&mut add_scalar_list_migrations(
+        db_schema_diff_based.steps,
+        scalar_list_migrations,
+    ));
     combined_steps.append(&mut datamodel_diff_based.steps);
-    combined_rollback.append(&mut db_schema_diff_based.rollback);
+    // The rollback undoes the steps in reverse order, e.g. a unique index is dropped before its column.
     combined_rollback.append(&mut datamodel_diff_based.rollback);
+    combined_rollback.append(&mut db_schema_diff_based.rollback);
     Ok(SqlMigration {
         steps: combined_steps,
         rollback: combined_rollback,
@@ -66,6 +120,7 @@ fn infer(
 fn infer_based_on_datamodel_diff(
     previous: &Datamodel,
     next: &Datamodel,
+    sql_family: SqlFamily,
     model_steps: &Vec<MigrationStep>,
 ) -> ConnectorResult<SqlMigration> {
     let mut steps = Vec::new();
@@ -82,17 +137,36 @@ fn infer_based_on_datamodel_diff(
                     .find(|f| f.name == create_field.name)
                     .expect("Field for MigrationStep not found");
                 let index_name = format!("{}.{}._UNIQUE", model.db_name(), field.db_name());
-                if create_field.is_unique {
-                    steps.push(SqlMigrationStep::CreateIndex(CreateIndex {
-                        table: model.db_name(),
-                        name: index_name.clone(),
-                        tpe: IndexType::Unique,
-                        columns: vec![field.db_name()],
-                    }));
-                    rollback.push(SqlMigrationStep::DropIndex(DropIndex {
-                        table: model.db_name(),
-                        name: index_name,
-                    }));
+                let create_index = SqlMigrationStep::CreateIndex(CreateIndex {
+                    table: model.db_name().to_string(),
+                    schema: model.table_schema(sql_family),
+                    name: index_name.clone(),
+                    tpe: IndexType::Unique,
+                    columns: vec![field.db_name()],
+                    predicate: None,
+                    sort_orders: Vec::new(),
+                });
+                let drop_index = SqlMigrationStep::DropIndex(DropIndex {
+                    table: model.db_name().to_string(),
+                    schema: model.table_schema(sql_family),
+                    name: index_name,
+                });
+
+                // The column of a created field may already exist, e.g. when a model is renamed but still mapped to its old table.
+                let previous_field_is_unique = find_field_by_db_name(previous, model.db_name(), &field.db_name())
+                    .map(|(previous_model, previous_field)| previous_model.has_field_unique_index(previous_field))
+                    .unwrap_or(false);
+
+                match (previous_field_is_unique, model.has_field_unique_index(field)) {
+                    (false, true) => {
+                        steps.push(create_index);
+                        rollback.push(drop_index);
+                    }
+                    (true, false) => {
+                        steps.push(drop_index);
+                        rollback.push(create_index);
+                    }
+                    (_, _) => {}
                 }
             }
             MigrationStep::UpdateField(update_field) => {
@@ -112,20 +186,30 @@ fn infer_based_on_datamodel_diff(
                     .fields()
                     .find(|f| f.name == update_field.name)
                     .expect("new Field for MigrationStep not found");
+                check_list_arity_unchanged(old_model, old_field, new_field)?;
+                check_enum_values_preserved(previous, next, old_model, old_field, new_field)?;
+
                 let index_name = format!("{}.{}._UNIQUE", old_model.db_name(), old_field.db_name());
 
                 let drop_index = SqlMigrationStep::DropIndex(DropIndex {
-                    table: new_model.db_name(),
+                    table: new_model.db_name().to_string(),
+                    schema: new_model.table_schema(sql_family),
                     name: index_name.clone(),
                 });
                 let create_index = SqlMigrationStep::CreateIndex(CreateIndex {
-                    table: old_model.db_name(),
+                    table: old_model.db_name().to_string(),
+                    schema: old_model.table_schema(sql_family),
                     name: index_name,
                     tpe: IndexType::Unique,
                     columns: vec![new_field.db_name()],
+                    predicate: None,
+                    sort_orders: Vec::new(),
                 });
 
-                match (old_field.is_unique, new_field.is_unique) {
+                match (
+                    old_model.has_field_unique_index(old_field),
+                    new_model.has_field_unique_index(new_field),
+                ) {
                     (true, false) => {
                         steps.push(drop_index);
                         rollback.push(create_index);
@@ -137,12 +221,235 @@ fn infer_based_on_datamodel_diff(
                     (_, _) => {}
                 }
             }
+            // A named `@@unique` on a single `@unique` field replaces the index of the field, so changed indexes
+            // can create or drop the index of a field that is not updated itself.
+            MigrationStep::UpdateModel(update_model) if update_model.indices.is_some() => {
+                let old_model = previous
+                    .models()
+                    .find(|m| m.name == update_model.name)
+                    .expect("old Model for MigrationStep not found");
+                let new_model_name = update_model.new_name.as_ref().unwrap_or(&update_model.name);
+                let new_model = next
+                    .models()
+                    .find(|m| &m.name == new_model_name)
+                    .expect("new Model for MigrationStep not found");
+
+                for new_field in new_model.fields() {
+                    let old_field = match old_model.find_field(&new_field.name) {
+                        Some(old_field) => old_field,
+                        None => continue,
+                    };
+                    let is_updated = model_steps.iter().any(|step| match step {
+                        MigrationStep::UpdateField(update_field) => {
+                            &update_field.model == new_model_name && update_field.name == new_field.name
+                        }
+                        _ => false,
+                    });
+                    if is_updated {
+                        continue;
+                    }
+
+                    let index_name = format!("{}.{}._UNIQUE", new_model.db_name(), new_field.db_name());
+                    let create_index = SqlMigrationStep::CreateIndex(CreateIndex {
+                        table: new_model.db_name().to_string(),
+                        schema: new_model.table_schema(sql_family),
+                        name: index_name.clone(),
+                        tpe: IndexType::Unique,
+                        columns: vec![new_field.db_name()],
+                        predicate: None,
+                        sort_orders: Vec::new(),
+                    });
+                    let drop_index = SqlMigrationStep::DropIndex(DropIndex {
+                        table: new_model.db_name().to_string(),
+                        schema: new_model.table_schema(sql_family),
+                        name: index_name,
+                    });
+
+                    match (
+                        old_model.has_field_unique_index(old_field),
+                        new_model.has_field_unique_index(new_field),
+                    ) {
+                        (true, false) => {
+                            steps.push(drop_index);
+                            rollback.push(create_index);
+                        }
+                        (false, true) => {
+                            steps.push(create_index);
+                            rollback.push(drop_index);
+                        }
+                        (_, _) => {}
+                    }
+                }
+            }
             _ => {}
         }
     }
     Ok(SqlMigration { steps, rollback })
 }
 
+/// The values of a scalar list are stored differently than a single scalar value, e.g. in a separate table.
+/// Existing data can therefore not be carried over when a scalar field becomes a list or vice versa.
+fn check_list_arity_unchanged(model: &Model, old_field: &Field, new_field: &Field) -> ConnectorResult<()> {
+    let is_scalar = |field: &Field| match field.field_type {
+        FieldType::Base(_) | FieldType::Enum(_) => true,
+        _ => false,
+    };
+    let describe = |field: &Field| {
+        if field.arity == FieldArity::List {
+            "a list"
+        } else {
+            "a scalar"
+        }
+    };
+
+    if is_scalar(old_field)
+        && is_scalar(new_field)
+        && (old_field.arity == FieldArity::List) != (new_field.arity == FieldArity::List)
+    {
+        return Err(SqlError::Generic(format!(
+            "The field {} of Model {} cannot be changed from {} to {}. The values of list fields are stored differently than those of scalar fields, so the existing data cannot be migrated. Remove the field and add a new one instead.",
+            new_field.name,
+            model.name,
+            describe(old_field),
+            describe(new_field)
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Scalar lists switching from a separate table to an array column keep their values, see `MigrateScalarListToArray`.
+/// Families that cannot store scalar lists as arrays reject the switch, instead of silently keeping the table.
+fn infer_scalar_list_migrations(
+    previous: &Datamodel,
+    next: &Datamodel,
+    sql_family: SqlFamily,
+    model_steps: &Vec<MigrationStep>,
+) -> SqlResult<Vec<MigrateScalarListToArray>> {
+    let is_embedded_list = |field: &Field| {
+        field.arity == FieldArity::List
+            && !field.field_type.is_relation()
+            && field.scalar_list_strategy == Some(ScalarListStrategy::Embedded)
+    };
+    let mut migrations = Vec::new();
+
+    for update_field in model_steps.iter().filter_map(|step| match step {
+        MigrationStep::UpdateField(update_field) => Some(update_field),
+        _ => None,
+    }) {
+        let old_model = previous
+            .find_model(&update_field.model)
+            .expect("old Model for MigrationStep not found");
+        let old_field = old_model
+            .find_field(&update_field.name)
+            .expect("old Field for MigrationStep not found");
+        let new_model = next
+            .find_model(&update_field.model)
+            .expect("new Model for MigrationStep not found");
+        let new_field = new_model
+            .find_field(&update_field.name)
+            .expect("new Field for MigrationStep not found");
+
+        if old_field.arity != FieldArity::List || is_embedded_list(old_field) || !is_embedded_list(new_field) {
+            continue;
+        }
+
+        if !sql_family.capabilities().scalar_list_arrays {
+            return Err(SqlError::Generic(format!(
+                "The field {} of Model {} cannot be changed to the EMBEDDED scalar list strategy. The `{}` connector cannot store scalar lists as arrays.",
+                new_field.name,
+                new_model.name,
+                sql_family.connector_type_string()
+            )));
+        }
+
+        migrations.push(MigrateScalarListToArray {
+            table: new_model.db_name().to_string(),
+            schema: new_model.table_schema(sql_family),
+            id_column: new_model.id_field()?.db_name(),
+            column: new_field.db_name(),
+            list_table: format!("{}_{}", old_model.db_name(), old_field.db_name()),
+        });
+    }
+
+    Ok(migrations)
+}
+
+/// The tables of scalar lists switching to array columns are only dropped once their values are copied. The copies
+/// run after all other steps, so the array columns exist by then.
+fn add_scalar_list_migrations(
+    steps: Vec<SqlMigrationStep>,
+    migrations: Vec<MigrateScalarListToArray>,
+) -> Vec<SqlMigrationStep> {
+    let (mut list_table_drops, mut steps): (Vec<_>, Vec<_>) = steps.into_iter().partition(|step| match step {
+        SqlMigrationStep::DropTable(drop_table) => migrations
+            .iter()
+            .any(|m| m.list_table == drop_table.name && m.schema == drop_table.schema),
+        _ => false,
+    });
+
+    steps.extend(migrations.into_iter().map(SqlMigrationStep::MigrateScalarListToArray));
+    steps.append(&mut list_table_drops);
+    steps
+}
+
+/// The column of a field changing from one enum to another keeps its values, see `ColumnType::is_stored_like`.
+/// This is only possible if every value of the previous enum also exists in the next one.
+fn check_enum_values_preserved(
+    previous: &Datamodel,
+    next: &Datamodel,
+    model: &Model,
+    old_field: &Field,
+    new_field: &Field,
+) -> ConnectorResult<()> {
+    let (old_enum_name, new_enum_name) = match (&old_field.field_type, &new_field.field_type) {
+        (FieldType::Enum(old_enum_name), FieldType::Enum(new_enum_name)) if old_enum_name != new_enum_name => {
+            (old_enum_name, new_enum_name)
+        }
+        _ => return Ok(()),
+    };
+    let old_enum = previous
+        .find_enum(old_enum_name)
+        .expect("old Enum for MigrationStep not found");
+    let new_enum = next
+        .find_enum(new_enum_name)
+        .expect("new Enum for MigrationStep not found");
+
+    let missing_values: Vec<&str> = old_enum
+        .values
+        .iter()
+        .filter(|value| !new_enum.values.contains(value))
+        .map(String::as_str)
+        .collect();
+
+    if !missing_values.is_empty() {
+        return Err(SqlError::Generic(format!(
+            "The field {} of Model {} cannot be changed from enum {} to enum {}. The values {} of {} do not exist in {}, so the existing data cannot be migrated. Add the values to {} or migrate the data first.",
+            new_field.name,
+            model.name,
+            old_enum.name,
+            new_enum.name,
+            missing_values.join(", "),
+            old_enum.name,
+            new_enum.name,
+            new_enum.name
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Finds the field that is stored in the given column of the given table, together with its model.
+fn find_field_by_db_name<'a>(datamodel: &'a Datamodel, table: &str, column: &str) -> Option<(&'a Model, &'a Field)> {
+    datamodel
+        .models()
+        .filter(|m| m.db_name() == table)
+        .flat_map(|m| m.fields().map(move |f| (m, f)))
+        .find(|(_, f)| f.db_name() == column)
+}
+
 fn infer_based_on_db_schema_diff(
     current: &DatabaseSchema,
     next: &DatabaseSchema,
@@ -157,20 +464,25 @@ fn infer_based_on_db_schema_diff(
     })
 }
 
-fn infer_database_migration_steps_and_fix(
+pub(crate) fn infer_database_migration_steps_and_fix(
     from: &DatabaseSchema,
     to: &DatabaseSchema,
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
-    let diff = DatabaseSchemaDiffer::diff(&from, &to);
+    let diff = DatabaseSchemaDiffer::diff(&from, &to, sql_family);
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     if is_sqlite {
         fix_stupid_sqlite(diff, &from, &to, &schema_name)
     } else {
-        let steps = delay_foreign_key_creation(diff);
-        fix_id_column_type_change(&from, &to, schema_name, steps)
+        let steps = add_composite_foreign_keys(delay_foreign_key_creation(diff));
+        let steps = fix_id_column_type_change(&from, &to, schema_name, sql_family, steps)?;
+        let steps = match sql_family {
+            SqlFamily::Mysql => inline_column_comments(&from, steps),
+            _ => steps,
+        };
+        Ok(create_schemas(steps))
     }
 }
 
@@ -178,6 +490,7 @@ fn fix_id_column_type_change(
     from: &DatabaseSchema,
     to: &DatabaseSchema,
     _schema_name: &str,
+    sql_family: SqlFamily,
     steps: Vec<SqlMigrationStep>,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
@@ -188,7 +501,7 @@ fn fix_id_column_type_change(
                     let change_to_id_column = alter_table.changes.iter().find(|c| match c {
                         TableChange::AlterColumn(alter_column) => {
                             let current_column = current_table.column_bang(&alter_column.name);
-                            let current_column_type = DatabaseSchemaDiffer::convert_column_type(current_column.tpe);
+                            let current_column_type = DatabaseSchemaDiffer::convert_column_type(&current_column.tpe);
                             let has_type_changed = current_column_type != alter_column.column.tpe;
                             let is_part_of_pk = current_table.primary_key_columns.contains(&alter_column.name);
                             is_part_of_pk && has_type_changed
@@ -210,12 +523,19 @@ fn fix_id_column_type_change(
         let tables_to_drop: Vec<String> = from
             .tables
             .iter()
-            .filter(|t| t.name != "_Migration")
+            .filter(|t| t.name != "_Migration" && t.schema.is_none())
             .map(|t| t.name.clone())
             .collect();
         radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty = DatabaseSchemaDiffer::diff(&DatabaseSchema::empty(), &to);
-        let mut steps_from_empty = delay_foreign_key_creation(diff_from_empty);
+        // `DropTables` only covers the schema of the connector.
+        radical_steps.extend(from.tables.iter().filter(|t| t.schema.is_some()).map(|t| {
+            SqlMigrationStep::DropTable(DropTable {
+                name: t.name.clone(),
+                schema: t.schema.clone(),
+            })
+        }));
+        let diff_from_empty = DatabaseSchemaDiffer::diff(&DatabaseSchema::empty(), &to, sql_family);
+        let mut steps_from_empty = add_composite_foreign_keys(delay_foreign_key_creation(diff_from_empty));
         radical_steps.append(&mut steps_from_empty);
 
         Ok(radical_steps)
@@ -236,7 +556,9 @@ fn delay_foreign_key_creation(mut diff: DatabaseSchemaDiff) -> Vec<SqlMigrationS
     for create_table in diff.create_tables.iter_mut() {
         let mut column_that_need_to_be_done_later_for_this_table = Vec::new();
         for column in &create_table.columns {
-            if let Some(ref foreign_key) = column.foreign_key {
+            // Composite foreign keys are added after all tables and indexes exist, see `add_composite_foreign_keys`.
+            let foreign_key = column.foreign_key.as_ref().filter(|fk| fk.composite_columns.is_empty());
+            if let Some(foreign_key) = foreign_key {
                 let references_non_existent_table = names_of_tables_that_get_created.contains(&foreign_key.table);
                 let is_part_of_primary_key = create_table.primary_columns.contains(&column.name);
                 let is_relation_table = create_table.name.starts_with("_"); // todo: this is a very weak check. find a better one
@@ -258,6 +580,7 @@ fn delay_foreign_key_creation(mut diff: DatabaseSchemaDiff) -> Vec<SqlMigrationS
 
         let alter_table = AlterTable {
             table: create_table.name.clone(),
+            schema: create_table.schema.clone(),
             changes,
         };
         if !alter_table.changes.is_empty() {
@@ -268,6 +591,111 @@ fn delay_foreign_key_creation(mut diff: DatabaseSchemaDiff) -> Vec<SqlMigrationS
     diff.into_steps()
 }
 
+/// MySQL renders the comment of a column as part of its definition, so created columns already carry their comment.
+/// Only the comments of columns that already exist and are not altered need to be set separately, as altered columns
+/// are added again with their comment.
+fn inline_column_comments(from: &DatabaseSchema, steps: Vec<SqlMigrationStep>) -> Vec<SqlMigrationStep> {
+    let mut altered_columns: Vec<(String, String)> = Vec::new();
+    for step in steps.iter() {
+        if let SqlMigrationStep::AlterTable(AlterTable { table, changes, .. }) = step {
+            for change in changes.iter() {
+                if let TableChange::AlterColumn(AlterColumn { column, .. }) = change {
+                    altered_columns.push((table.clone(), column.name.clone()));
+                }
+            }
+        }
+    }
+
+    steps
+        .into_iter()
+        .filter(|step| match step {
+            SqlMigrationStep::SetComment(SetComment {
+                table,
+                column: Some(column),
+                ..
+            }) => {
+                let exists = from.table(table).map(|t| t.has_column(&column.name)).unwrap_or(false);
+                let altered = altered_columns.contains(&(table.clone(), column.name.clone()));
+
+                exists && !altered
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// Tables in other schemas than the one of the connector can only be created once their schema exists.
+fn create_schemas(steps: Vec<SqlMigrationStep>) -> Vec<SqlMigrationStep> {
+    let mut schemas: Vec<String> = Vec::new();
+
+    for step in steps.iter() {
+        if let SqlMigrationStep::CreateTable(CreateTable {
+            schema: Some(schema), ..
+        }) = step
+        {
+            if !schemas.contains(schema) {
+                schemas.push(schema.clone());
+            }
+        }
+    }
+
+    schemas
+        .into_iter()
+        .map(|schema| SqlMigrationStep::RawSql {
+            raw: format!("CREATE SCHEMA IF NOT EXISTS {};", SqlFamily::Postgres.quote(&schema)),
+        })
+        .chain(steps)
+        .collect()
+}
+
+/// Composite foreign keys may reference a `@@unique`, whose index must exist before the foreign key can be created.
+/// They are therefore added to their tables once all other steps are done.
+fn add_composite_foreign_keys(mut steps: Vec<SqlMigrationStep>) -> Vec<SqlMigrationStep> {
+    let mut foreign_keys: Vec<(String, Option<String>, ForeignKey)> = Vec::new();
+
+    for step in steps.iter() {
+        let (table, schema, columns): (&String, &Option<String>, Vec<&ColumnDescription>) = match step {
+            SqlMigrationStep::CreateTable(create_table) => (
+                &create_table.name,
+                &create_table.schema,
+                create_table.columns.iter().collect(),
+            ),
+            SqlMigrationStep::AlterTable(alter_table) => (
+                &alter_table.table,
+                &alter_table.schema,
+                alter_table
+                    .changes
+                    .iter()
+                    .filter_map(|change| match change {
+                        TableChange::AddColumn(add_column) => Some(&add_column.column),
+                        TableChange::AlterColumn(alter_column) => Some(&alter_column.column),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => continue,
+        };
+
+        for foreign_key in columns.into_iter().filter_map(|c| c.foreign_key.as_ref()) {
+            let is_new = foreign_keys
+                .iter()
+                .all(|(t, s, fk)| t != table || s != schema || fk.composite_columns != foreign_key.composite_columns);
+            if !foreign_key.composite_columns.is_empty() && is_new {
+                foreign_keys.push((table.clone(), schema.clone(), foreign_key.clone()));
+            }
+        }
+    }
+
+    steps.extend(foreign_keys.into_iter().map(|(table, schema, foreign_key)| {
+        SqlMigrationStep::AlterTable(AlterTable {
+            table,
+            schema,
+            changes: vec![TableChange::AddForeignKey(AddForeignKey { foreign_key })],
+        })
+    }));
+    steps
+}
+
 fn fix_stupid_sqlite(
     diff: DatabaseSchemaDiff,
     current_database_schema: &DatabaseSchema,
@@ -276,6 +704,7 @@ fn fix_stupid_sqlite(
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let steps = diff.into_steps();
     let mut result = Vec::new();
+    let mut fixed_tables = Vec::new();
     for step in steps {
         match step {
             SqlMigrationStep::AlterTable(ref alter_table) if needs_fix(&alter_table) => {
@@ -283,7 +712,10 @@ fn fix_stupid_sqlite(
                 let next_table = next_database_schema.table(&alter_table.table)?;
                 let mut altered_steps = fix(&alter_table, &current_table, &next_table, &schema_name);
                 result.append(&mut altered_steps);
+                fixed_tables.push(alter_table.table.clone());
             }
+            // The indexes of fixed tables have already been recreated.
+            SqlMigrationStep::CreateIndex(ref create_index) if fixed_tables.contains(&create_index.table) => {}
             x => result.push(x),
         }
     }
@@ -300,6 +732,11 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         }
         TableChange::DropColumn(_) => true,
         TableChange::AlterColumn(_) => true,
+        // SQLite cannot change the default of a column, so the table is recreated with the new default.
+        TableChange::AlterColumnDefault(_) => true,
+        // SQLite cannot drop a check constraint either.
+        TableChange::AlterColumnCheck(_) => true,
+        TableChange::AddForeignKey(_) => true,
     });
     change_that_does_not_work_on_sqlite.is_some()
 }
@@ -307,13 +744,14 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
 fn fix(_alter_table: &AlterTable, current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep> {
     // based on 'Making Other Kinds Of Table Schema Changes' from https://www.sqlite.org/lang_altertable.html
     let name_of_temporary_table = format!("new_{}", next.name.clone());
-    vec![
+    let mut steps = vec![
         SqlMigrationStep::RawSql {
             raw: "PRAGMA foreign_keys=OFF;".to_string(),
         },
         // todo: start transaction now
         SqlMigrationStep::CreateTable(CreateTable {
             name: name_of_temporary_table.clone(),
+            schema: None,
             columns: DatabaseSchemaDiffer::column_descriptions(&next.columns),
             primary_columns: next.primary_key_columns.clone(),
         }),
@@ -327,34 +765,40 @@ fn fix(_alter_table: &AlterTable, current: &Table, next: &Table, schema_name: &s
                 .collect();
             let columns_string = intersection_columns
                 .iter()
-                .map(|c| format!("\"{}\"", c))
+                .map(|c| SqlFamily::Sqlite.quote(c))
                 .collect::<Vec<String>>()
                 .join(",");
             let sql = format!(
-                "INSERT INTO \"{}\" ({}) SELECT {} from \"{}\"",
-                name_of_temporary_table,
+                "INSERT INTO {} ({}) SELECT {} from {}",
+                SqlFamily::Sqlite.quote(&name_of_temporary_table),
                 columns_string,
                 columns_string,
-                next.name.clone()
+                SqlFamily::Sqlite.quote(&next.name)
             );
             SqlMigrationStep::RawSql { raw: sql.to_string() }
         },
         SqlMigrationStep::DropTable(DropTable {
             name: current.name.clone(),
+            schema: None,
         }),
         SqlMigrationStep::RenameTable {
             name: name_of_temporary_table,
             new_name: next.name.clone(),
         },
-        // todo: recreate indexes + triggers
-        SqlMigrationStep::RawSql {
-            raw: format!(r#"PRAGMA "{}".foreign_key_check;"#, schema_name),
-        },
-        // todo: commit transaction
-        SqlMigrationStep::RawSql {
-            raw: "PRAGMA foreign_keys=ON;".to_string(),
-        },
-    ]
+    ];
+    // todo: recreate indexes of fields marked with @unique + triggers
+    steps.extend(
+        DatabaseSchemaDiffer::diffable_indexes(&next)
+            .map(|index| SqlMigrationStep::CreateIndex(DatabaseSchemaDiffer::create_index(&next, index))),
+    );
+    steps.push(SqlMigrationStep::RawSql {
+        raw: format!("PRAGMA {}.foreign_key_check;", SqlFamily::Sqlite.quote(schema_name)),
+    });
+    // todo: commit transaction
+    steps.push(SqlMigrationStep::RawSql {
+        raw: "PRAGMA foreign_keys=ON;".to_string(),
+    });
+    steps
 }
 
 pub fn wrap_as_step<T, F>(steps: Vec<T>, mut wrap_fn: F) -> Vec<SqlMigrationStep>
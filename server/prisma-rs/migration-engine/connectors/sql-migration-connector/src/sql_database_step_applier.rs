@@ -1,5 +1,5 @@
 use crate::*;
-use datamodel::Value;
+use datamodel::{SortOrder, Value};
 use migration_connector::*;
 use std::sync::Arc;
 
@@ -26,6 +26,14 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
             &self.schema_name,
         )?)
     }
+
+    fn render_rollback_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<serde_json::Value> {
+        Ok(render_steps_pretty(
+            &database_migration.reverse(),
+            self.sql_family,
+            &self.schema_name,
+        )?)
+    }
 }
 
 impl SqlDatabaseStepApplier {
@@ -71,12 +79,13 @@ fn render_steps_pretty(
     Ok(serde_json::Value::Array(jsons))
 }
 
-fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> String {
+pub(crate) fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> String {
     let schema_name = schema_name.to_string();
 
     match step {
         SqlMigrationStep::CreateTable(CreateTable {
             name,
+            schema,
             columns,
             primary_columns,
         }) => {
@@ -84,129 +93,275 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             let primary_columns = primary_columns.clone();
             let mut lines = Vec::new();
             for column in cloned_columns.clone() {
-                let col_sql = render_column(sql_family, schema_name.to_string(), &column, false);
+                let col_sql = render_column(sql_family, schema_name.to_string(), name, &column, false);
                 lines.push(col_sql);
             }
             if primary_columns.len() > 0 {
                 let column_names: Vec<String> = primary_columns
                     .clone()
                     .into_iter()
-                    .map(|col| quote(&col, sql_family))
+                    .map(|col| sql_family.quote(&col))
                     .collect();
                 lines.push(format!("PRIMARY KEY ({})", column_names.join(",")))
             }
+            // SQLite cannot add constraints to existing tables, so its composite foreign keys are part of the table.
+            if sql_family == SqlFamily::Sqlite {
+                let mut composite_foreign_keys: Vec<&ForeignKey> = Vec::new();
+                for foreign_key in columns.iter().filter_map(|c| c.foreign_key.as_ref()) {
+                    let is_new = composite_foreign_keys
+                        .iter()
+                        .all(|fk| fk.composite_columns != foreign_key.composite_columns);
+                    if !foreign_key.composite_columns.is_empty() && is_new {
+                        composite_foreign_keys.push(foreign_key);
+                    }
+                }
+                for foreign_key in composite_foreign_keys {
+                    lines.push(render_composite_foreign_key(sql_family, &schema_name, foreign_key));
+                }
+            }
             format!(
                 "CREATE TABLE {}.{}({})\n{};",
-                quote(&schema_name, sql_family),
-                quote(name, sql_family),
+                sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+                sql_family.quote(name),
                 lines.join(","),
                 create_table_suffix(sql_family),
             )
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => format!(
+        SqlMigrationStep::DropTable(DropTable { name, schema }) => format!(
             "DROP TABLE {}.{};",
-            quote(&schema_name, sql_family),
-            quote(name, sql_family)
+            sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+            sql_family.quote(name)
         ),
         SqlMigrationStep::DropTables(DropTables { names }) => {
             let fully_qualified_names: Vec<String> = names
                 .iter()
-                .map(|name| format!("{}.{}", quote(&schema_name, sql_family), quote(name, sql_family)))
+                .map(|name| format!("{}.{}", sql_family.quote(&schema_name), sql_family.quote(name)))
                 .collect();
             format!("DROP TABLE {};", fully_qualified_names.join(","))
         }
         SqlMigrationStep::RenameTable { name, new_name } => {
             let new_name = match sql_family {
-                SqlFamily::Sqlite => format!("{}", quote(new_name, sql_family)),
-                _ => format!("{}.{}", quote(&schema_name, sql_family), quote(new_name, sql_family)),
+                SqlFamily::Sqlite => format!("{}", sql_family.quote(new_name)),
+                _ => format!("{}.{}", sql_family.quote(&schema_name), sql_family.quote(new_name)),
             };
             format!(
                 "ALTER TABLE {}.{} RENAME TO {};",
-                quote(&schema_name, sql_family),
-                quote(name, sql_family),
+                sql_family.quote(&schema_name),
+                sql_family.quote(name),
                 new_name
             )
         }
-        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+        SqlMigrationStep::AlterTable(AlterTable { table, schema, changes }) => {
             let mut lines = Vec::new();
             for change in changes.clone() {
                 match change {
                     TableChange::AddColumn(AddColumn { column }) => {
-                        let col_sql = render_column(sql_family, schema_name.to_string(), &column, true);
+                        let col_sql = render_column(sql_family, schema_name.to_string(), table, &column, true);
                         lines.push(format!("ADD COLUMN {}", col_sql));
                     }
                     TableChange::DropColumn(DropColumn { name }) => {
                         // TODO: this does not work on MySQL for columns with foreign keys. Here the FK must be dropped first by name.
-                        let name = quote(&name, sql_family);
+                        let name = sql_family.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                     }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
-                        let name = quote(&name, sql_family);
+                        let name = sql_family.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
-                        let col_sql = render_column(sql_family, schema_name.to_string(), &column, true);
+                        let col_sql = render_column(sql_family, schema_name.to_string(), table, &column, true);
                         lines.push(format!("ADD COLUMN {}", col_sql));
                     }
+                    TableChange::AlterColumnDefault(AlterColumnDefault { name, tpe, default }) => {
+                        let name = sql_family.quote(&name);
+                        match default.and_then(|value| render_default(sql_family, &tpe, &value)) {
+                            Some(default) => lines.push(format!("ALTER COLUMN {} SET DEFAULT {}", name, default)),
+                            None => lines.push(format!("ALTER COLUMN {} DROP DEFAULT", name)),
+                        }
+                    }
+                    TableChange::AlterColumnCheck(AlterColumnCheck { name, check }) => {
+                        let constraint_name = sql_family.quote(&check_constraint_name(table, &name));
+                        lines.push(format!("DROP CONSTRAINT IF EXISTS {}", constraint_name));
+                        if let Some(check) = check {
+                            lines.push(format!("ADD CONSTRAINT {} CHECK ({})", constraint_name, check));
+                        }
+                    }
+                    TableChange::AddForeignKey(AddForeignKey { foreign_key }) => {
+                        let fk_sql = render_composite_foreign_key(sql_family, &schema_name, &foreign_key);
+                        lines.push(format!("ADD {}", fk_sql));
+                    }
                 }
             }
             format!(
                 "ALTER TABLE {}.{} {};",
-                quote(&schema_name, sql_family),
-                quote(table, sql_family),
+                sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+                sql_family.quote(table),
                 lines.join(",")
             )
         }
         SqlMigrationStep::CreateIndex(CreateIndex {
             table,
+            schema,
             name,
             tpe,
             columns,
+            predicate,
+            sort_orders,
         }) => {
-            let index_type = match tpe {
-                IndexType::Unique => "UNIQUE",
-                IndexType::Normal => "",
+            let index_type = match (tpe, sql_family) {
+                (IndexType::Unique, _) => "UNIQUE",
+                (IndexType::Fulltext, SqlFamily::Mysql) => "FULLTEXT",
+                (IndexType::Fulltext, SqlFamily::Sqlite) => panic!("Fulltext indexes are not supported on SQLite."),
+                _ => "",
             };
             let index_name = match sql_family {
-                SqlFamily::Sqlite => format!("{}.{}", quote(&schema_name, sql_family), quote(&name, sql_family)),
-                _ => quote(&name, sql_family),
+                SqlFamily::Sqlite => format!("{}.{}", sql_family.quote(&schema_name), sql_family.quote(&name)),
+                _ => sql_family.quote(&name),
             };
             let table_reference = match sql_family {
-                SqlFamily::Sqlite => quote(&table, sql_family),
-                _ => format!("{}.{}", quote(&schema_name, sql_family), quote(&table, sql_family)),
+                SqlFamily::Sqlite => sql_family.quote(&table),
+                _ => format!(
+                    "{}.{}",
+                    sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+                    sql_family.quote(&table)
+                ),
+            };
+            // Postgres has no fulltext index type. The equivalent is a GIN index over the tsvector of each column.
+            let index_method = match (tpe, sql_family) {
+                (IndexType::Fulltext, SqlFamily::Postgres) => " USING GIN",
+                _ => "",
+            };
+            let columns: Vec<String> = columns
+                .iter()
+                .enumerate()
+                .map(|(position, c)| match (tpe, sql_family) {
+                    (IndexType::Fulltext, SqlFamily::Postgres) => format!(
+                        "to_tsvector('{}', {})",
+                        POSTGRES_FULLTEXT_CONFIGURATION,
+                        sql_family.quote(c)
+                    ),
+                    _ => match sort_orders.get(position) {
+                        Some(SortOrder::Desc) => format!("{} DESC", sql_family.quote(c)),
+                        _ => sql_family.quote(c),
+                    },
+                })
+                .collect();
+            let where_clause = match predicate {
+                Some(predicate) => format!(" WHERE ({})", predicate),
+                None => "".to_string(),
             };
-            let columns: Vec<String> = columns.iter().map(|c| quote(c, sql_family)).collect();
             format!(
-                "CREATE {} INDEX {} ON {}({})",
+                "CREATE {} INDEX {} ON {}{}({}){}",
                 index_type,
                 index_name,
                 table_reference,
-                columns.join(",")
+                index_method,
+                columns.join(","),
+                where_clause
             )
         }
-        SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
+        SqlMigrationStep::DropIndex(DropIndex { table, schema, name }) => match sql_family {
             SqlFamily::Mysql => format!(
                 "DROP INDEX {} ON {}.{}",
-                quote(&name, sql_family),
-                quote(&schema_name, sql_family),
-                quote(&table, sql_family)
+                sql_family.quote(&name),
+                sql_family.quote(&schema_name),
+                sql_family.quote(&table)
             ),
+            // Postgres indexes live in the schema of their table.
             SqlFamily::Postgres | SqlFamily::Sqlite => format!(
                 "DROP INDEX {}.{}",
-                quote(&schema_name, sql_family),
-                quote(&name, sql_family)
+                sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+                sql_family.quote(&name)
+            ),
+        },
+        SqlMigrationStep::SetComment(SetComment {
+            table,
+            schema,
+            column,
+            comment,
+        }) => {
+            let table_reference = format!(
+                "{}.{}",
+                sql_family.quote(schema.as_ref().unwrap_or(&schema_name)),
+                sql_family.quote(&table)
+            );
+            match (sql_family, column) {
+                (SqlFamily::Postgres, None) => format!(
+                    "COMMENT ON TABLE {} IS {};",
+                    table_reference,
+                    render_comment(sql_family, comment)
+                ),
+                (SqlFamily::Postgres, Some(column)) => format!(
+                    "COMMENT ON COLUMN {}.{} IS {};",
+                    table_reference,
+                    sql_family.quote(&column.name),
+                    render_comment(sql_family, comment)
+                ),
+                // MySQL removes the comment of a table when it is set to an empty string.
+                (SqlFamily::Mysql, None) => format!(
+                    "ALTER TABLE {} COMMENT = {};",
+                    table_reference,
+                    render_comment(sql_family, &Some(comment.clone().unwrap_or_default()))
+                ),
+                // The foreign key of the column already exists and must not be added again.
+                (SqlFamily::Mysql, Some(column)) => {
+                    let column = ColumnDescription {
+                        foreign_key: None,
+                        ..column.clone()
+                    };
+                    format!(
+                        "ALTER TABLE {} MODIFY COLUMN {};",
+                        table_reference,
+                        render_column(sql_family, schema_name.to_string(), table, &column, false)
+                    )
+                }
+                (SqlFamily::Sqlite, _) => panic!("Comments are not supported on SQLite."),
+            }
+        }
+        SqlMigrationStep::CreateSequence(CreateSequence { name, initial_value }) => match sql_family {
+            SqlFamily::Postgres => format!(
+                "CREATE SEQUENCE {}.{} START WITH {};",
+                sql_family.quote(&schema_name),
+                sql_family.quote(&name),
+                initial_value
+            ),
+            _ => panic!("Sequences are only supported on Postgres."),
+        },
+        SqlMigrationStep::DropSequence(DropSequence { name }) => match sql_family {
+            SqlFamily::Postgres => format!(
+                "DROP SEQUENCE {}.{};",
+                sql_family.quote(&schema_name),
+                sql_family.quote(&name)
             ),
+            _ => panic!("Sequences are only supported on Postgres."),
+        },
+        SqlMigrationStep::MigrateScalarListToArray(MigrateScalarListToArray {
+            table,
+            schema,
+            id_column,
+            column,
+            list_table,
+        }) => match sql_family {
+            SqlFamily::Postgres => {
+                let schema = sql_family.quote(schema.as_ref().unwrap_or(&schema_name));
+                let table_reference = format!("{}.{}", schema, sql_family.quote(&table));
+                format!(
+                    "UPDATE {} SET {} = (SELECT array_agg({} ORDER BY {}) FROM {}.{} WHERE {} = {}.{});",
+                    table_reference,
+                    sql_family.quote(&column),
+                    sql_family.quote("value"),
+                    sql_family.quote("position"),
+                    schema,
+                    sql_family.quote(&list_table),
+                    sql_family.quote("nodeId"),
+                    table_reference,
+                    sql_family.quote(&id_column)
+                )
+            }
+            _ => panic!("Scalar lists can only be stored as arrays on Postgres."),
         },
         SqlMigrationStep::RawSql { raw } => raw.to_string(),
     }
 }
 
-fn quote(name: &str, sql_family: SqlFamily) -> String {
-    match sql_family {
-        SqlFamily::Sqlite => format!("\"{}\"", name),
-        SqlFamily::Postgres => format!("\"{}\"", name),
-        SqlFamily::Mysql => format!("`{}`", name),
-    }
-}
-
 fn create_table_suffix(sql_family: SqlFamily) -> String {
     match sql_family {
         SqlFamily::Sqlite => "".to_string(),
@@ -218,79 +373,215 @@ fn create_table_suffix(sql_family: SqlFamily) -> String {
 fn render_column(
     sql_family: SqlFamily,
     schema_name: String,
+    table_name: &str,
     column_description: &ColumnDescription,
     add_fk_prefix: bool,
 ) -> String {
-    let column_name = quote(&column_description.name, sql_family);
-    let tpe_str = render_column_type(sql_family, column_description.tpe);
+    let column_name = sql_family.quote(&column_description.name);
+    let tpe_str = if column_description.is_list {
+        format!("{}[]", render_column_type(sql_family, &column_description.tpe))
+    } else {
+        render_column_type(sql_family, &column_description.tpe)
+    };
     // TODO: bring back when the query planning for writes is done
     let nullability_str = if column_description.required && column_description.foreign_key.is_none() {
         "NOT NULL"
     } else {
         ""
     };
-    let default_str = match &column_description.default {
-        Some(value) => {
-            match render_value(value) {
+    let default_str = match (&column_description.sequence, &column_description.default) {
+        (Some(sequence), _) => format!(
+            "DEFAULT nextval('{}.{}')",
+            sql_family.quote(&schema_name),
+            sql_family.quote(sequence)
+        ),
+        (None, Some(value)) => {
+            match render_default(sql_family, &column_description.tpe, value) {
                 Some(ref default) if column_description.required => format!("DEFAULT {}", default),
+                // The cast is required for empty arrays, as their element type cannot be inferred.
+                Some(ref default) if column_description.is_list => format!("DEFAULT {}::{}", default, tpe_str),
                 Some(_) => "".to_string(), // we use the default value right now only to smoothen migrations. So we only use it when absolutely needed.
                 None => "".to_string(),
             }
         }
+        (None, None) => "".to_string(),
+    };
+    // Composite foreign keys span several columns and are rendered separately, see `render_composite_foreign_key`.
+    let foreign_key = column_description
+        .foreign_key
+        .as_ref()
+        .filter(|fk| fk.composite_columns.is_empty());
+    let constraint_str = match foreign_key.and_then(|fk| fk.name.as_ref()) {
+        Some(name) => format!("CONSTRAINT {}", sql_family.quote(name)),
         None => "".to_string(),
     };
-    let references_str = match (sql_family, &column_description.foreign_key) {
+    let references_str = match (sql_family, foreign_key) {
         (SqlFamily::Postgres, Some(fk)) => format!(
-            "REFERENCES \"{}\".\"{}\"(\"{}\") {}",
-            schema_name,
-            fk.table,
-            fk.column,
-            render_on_delete(&fk.on_delete)
+            "REFERENCES {}.{}({}) {} {} {}",
+            sql_family.quote(fk.schema.as_ref().unwrap_or(&schema_name)),
+            sql_family.quote(&fk.table),
+            sql_family.quote(&fk.column),
+            render_on_delete(sql_family, &fk.on_delete),
+            render_on_update(sql_family, &fk.on_update),
+            render_deferrable(fk.deferrable)
         ),
         (SqlFamily::Mysql, Some(fk)) => format!(
-            "REFERENCES `{}`.`{}`(`{}`) {}",
-            schema_name,
-            fk.table,
-            fk.column,
-            render_on_delete(&fk.on_delete)
+            "REFERENCES {}.{}({}) {} {}",
+            sql_family.quote(&schema_name),
+            sql_family.quote(&fk.table),
+            sql_family.quote(&fk.column),
+            render_on_delete(sql_family, &fk.on_delete),
+            render_on_update(sql_family, &fk.on_update)
         ),
         (SqlFamily::Sqlite, Some(fk)) => format!(
-            "REFERENCES \"{}\"({}) {}",
-            fk.table,
-            fk.column,
-            render_on_delete(&fk.on_delete)
+            "REFERENCES {}({}) {} {}",
+            sql_family.quote(&fk.table),
+            sql_family.quote(&fk.column),
+            render_on_delete(sql_family, &fk.on_delete),
+            render_on_update(sql_family, &fk.on_update)
         ),
         (_, None) => "".to_string(),
     };
-    match (sql_family, &column_description.foreign_key) {
+    let comment_str = match (sql_family, &column_description.comment) {
+        (SqlFamily::Mysql, Some(_)) => format!("COMMENT {}", render_comment(sql_family, &column_description.comment)),
+        _ => "".to_string(),
+    };
+    // Check constraints are not supported on MySQL, see `ConnectorCapabilities`.
+    let check_str = match (sql_family, &column_description.check) {
+        (SqlFamily::Mysql, _) | (_, None) => "".to_string(),
+        (SqlFamily::Postgres, Some(check)) => format!(
+            "CONSTRAINT {} CHECK ({})",
+            sql_family.quote(&check_constraint_name(table_name, &column_description.name)),
+            check
+        ),
+        (_, Some(check)) => format!("CHECK ({})", check),
+    };
+    match (sql_family, foreign_key) {
         (SqlFamily::Mysql, Some(_)) => {
             let add = if add_fk_prefix { "ADD" } else { "" };
-            let fk_line = format!("{} FOREIGN KEY ({}) {}", add, column_name, references_str);
+            let fk_line = format!(
+                "{} {} FOREIGN KEY ({}) {}",
+                add, constraint_str, column_name, references_str
+            );
             format!(
-                "{} {} {} {},{}",
-                column_name, tpe_str, nullability_str, default_str, fk_line
+                "{} {} {} {} {},{}",
+                column_name, tpe_str, nullability_str, default_str, comment_str, fk_line
             )
         }
-        _ => format!(
+        (SqlFamily::Mysql, None) => format!(
             "{} {} {} {} {}",
-            column_name, tpe_str, nullability_str, default_str, references_str
+            column_name, tpe_str, nullability_str, default_str, comment_str
+        ),
+        _ => format!(
+            "{} {} {} {} {} {} {}",
+            column_name, tpe_str, nullability_str, default_str, constraint_str, references_str, check_str
         ),
     }
 }
 
-fn render_on_delete(on_delete: &OnDelete) -> &'static str {
+/// Comments are arbitrary text, so they are rendered as escaped string literals. MySQL also treats backslashes as escapes.
+fn render_comment(sql_family: SqlFamily, comment: &Option<String>) -> String {
+    match comment {
+        Some(comment) => {
+            let escaped = comment.replace("'", "''");
+            let escaped = match sql_family {
+                SqlFamily::Mysql => escaped.replace("\\", "\\\\"),
+                _ => escaped,
+            };
+            format!("'{}'", escaped)
+        }
+        None => "NULL".to_string(),
+    }
+}
+
+fn render_on_delete(sql_family: SqlFamily, on_delete: &OnDelete) -> &'static str {
     match on_delete {
         OnDelete::NoAction => "",
         OnDelete::SetNull => "ON DELETE SET NULL",
+        // InnoDB rejects SET DEFAULT, so MySQL falls back to its default behaviour, which is equivalent to RESTRICT.
+        OnDelete::SetDefault if sql_family == SqlFamily::Mysql => "",
+        OnDelete::SetDefault => "ON DELETE SET DEFAULT",
+        OnDelete::Restrict => "ON DELETE RESTRICT",
         OnDelete::Cascade => "ON DELETE CASCADE",
     }
 }
 
+fn render_on_update(sql_family: SqlFamily, on_update: &OnUpdate) -> &'static str {
+    match on_update {
+        OnUpdate::NoAction => "",
+        OnUpdate::SetNull => "ON UPDATE SET NULL",
+        // See `render_on_delete`.
+        OnUpdate::SetDefault if sql_family == SqlFamily::Mysql => "",
+        OnUpdate::SetDefault => "ON UPDATE SET DEFAULT",
+        OnUpdate::Restrict => "ON UPDATE RESTRICT",
+        OnUpdate::Cascade => "ON UPDATE CASCADE",
+    }
+}
+
+fn render_composite_foreign_key(sql_family: SqlFamily, schema_name: &str, foreign_key: &ForeignKey) -> String {
+    let constraint_str = match &foreign_key.name {
+        Some(name) => format!("CONSTRAINT {} ", sql_family.quote(name)),
+        None => "".to_string(),
+    };
+    let (columns, referenced_columns): (Vec<String>, Vec<String>) = foreign_key
+        .composite_columns
+        .iter()
+        .map(|(column, referenced_column)| (sql_family.quote(column), sql_family.quote(referenced_column)))
+        .unzip();
+    let referenced_table = match sql_family {
+        SqlFamily::Sqlite => sql_family.quote(&foreign_key.table),
+        _ => format!(
+            "{}.{}",
+            sql_family.quote(foreign_key.schema.as_ref().map(String::as_str).unwrap_or(schema_name)),
+            sql_family.quote(&foreign_key.table)
+        ),
+    };
+    let deferrable_str = match sql_family {
+        SqlFamily::Postgres => render_deferrable(foreign_key.deferrable),
+        _ => "",
+    };
+
+    format!(
+        "{}FOREIGN KEY ({}) REFERENCES {}({}) {} {} {}",
+        constraint_str,
+        columns.join(","),
+        referenced_table,
+        referenced_columns.join(","),
+        render_on_delete(sql_family, &foreign_key.on_delete),
+        render_on_update(sql_family, &foreign_key.on_update),
+        deferrable_str
+    )
+}
+
+fn render_deferrable(deferrable: bool) -> &'static str {
+    if deferrable {
+        "DEFERRABLE INITIALLY DEFERRED"
+    } else {
+        ""
+    }
+}
+
+/// `now()` becomes a database default on timezone aware columns, so rows inserted outside of Prisma
+/// get a timezone aware timestamp as well. `now()` returns a `timestamptz` on Postgres.
+/// Fixed timestamps are UTC, which timezone aware columns must be told explicitly.
+fn render_default(sql_family: SqlFamily, tpe: &ColumnType, value: &Value) -> Option<String> {
+    match value {
+        Value::Expression(name, _, args) if name == "now" && args.is_empty() && *tpe == ColumnType::DateTimeTz => {
+            Some("now()".to_string())
+        }
+        Value::DateTime(x) if *tpe == ColumnType::DateTimeTz => {
+            Some(format!("'{}+00:00'", x.format("%Y-%m-%d %H:%M:%S%.f")))
+        }
+        _ => render_value(sql_family, value),
+    }
+}
+
 // TODO: this returns None for expressions
-fn render_value(value: &Value) -> Option<String> {
+fn render_value(sql_family: SqlFamily, value: &Value) -> Option<String> {
     match value {
         Value::Boolean(x) => Some(if *x { "true".to_string() } else { "false".to_string() }),
         Value::Int(x) => Some(format!("{}", x)),
+        Value::BigInt(x) => Some(format!("{}", x)),
         Value::Float(x) => Some(format!("{}", x)),
         Value::Decimal(x) => Some(format!("{}", x)),
         Value::String(x) => Some(format!("'{}'", x)),
@@ -301,12 +592,28 @@ fn render_value(value: &Value) -> Option<String> {
             Some(format!("'{}'", raw)) // add quotes
         }
         Value::ConstantLiteral(x) => Some(format!("'{}'", x)), // this represents enum values
+        Value::List(values) => {
+            let values: Option<Vec<String>> = values.iter().map(|v| render_value(sql_family, v)).collect();
+            values.map(|values| format!("ARRAY[{}]", values.join(",")))
+        }
+        // Lets rows inserted outside of Prisma get an id as well. Before Postgres 13, this requires the `pgcrypto` extension.
+        // cuids cannot be generated by any database, so they remain generated by Prisma.
+        Value::Expression(name, _, args) if name == "uuid" && args.is_empty() && sql_family == SqlFamily::Postgres => {
+            Some("gen_random_uuid()".to_string())
+        }
         _ => None,
     }
 }
 
 // TODO: this must become database specific akin to our TypeMappers in Scala
-fn render_column_type(sql_family: SqlFamily, t: ColumnType) -> String {
+/// The name of the check constraint of a column on Postgres. The constraint is named explicitly when it is created,
+/// because Postgres shortens the names it generates itself differently than names which are too long. Explicit names
+/// longer than 63 bytes are truncated the same way when the constraint is created and when it is dropped.
+fn check_constraint_name(table: &str, column: &str) -> String {
+    format!("{}_{}_check", table, column)
+}
+
+fn render_column_type(sql_family: SqlFamily, t: &ColumnType) -> String {
     match sql_family {
         SqlFamily::Sqlite => render_column_type_sqlite(t),
         SqlFamily::Postgres => render_column_type_postgres(t),
@@ -314,34 +621,44 @@ fn render_column_type(sql_family: SqlFamily, t: ColumnType) -> String {
     }
 }
 
-fn render_column_type_sqlite(t: ColumnType) -> String {
+fn render_column_type_sqlite(t: &ColumnType) -> String {
     match t {
         ColumnType::Boolean => format!("BOOLEAN"),
-        ColumnType::DateTime => format!("DATE"),
+        ColumnType::DateTime | ColumnType::DateTimeTz => format!("DATE"),
         ColumnType::Float => format!("REAL"),
-        ColumnType::Int => format!("INTEGER"),
-        ColumnType::String => format!("TEXT"),
+        ColumnType::Int | ColumnType::BigInt => format!("INTEGER"),
+        ColumnType::String | ColumnType::Uuid => format!("TEXT"),
+        ColumnType::Xml => format!("TEXT"),
+        ColumnType::Unsupported(tpe) => tpe.clone(),
     }
 }
 
-fn render_column_type_postgres(t: ColumnType) -> String {
+fn render_column_type_postgres(t: &ColumnType) -> String {
     match t {
         ColumnType::Boolean => format!("boolean"),
         ColumnType::DateTime => format!("timestamp(3)"),
+        ColumnType::DateTimeTz => format!("timestamptz(3)"),
         ColumnType::Float => format!("Decimal(65,30)"),
         ColumnType::Int => format!("integer"),
+        ColumnType::BigInt => format!("bigint"),
         ColumnType::String => format!("text"),
+        ColumnType::Xml => format!("xml"),
+        ColumnType::Uuid => format!("uuid"),
+        ColumnType::Unsupported(tpe) => tpe.clone(),
     }
 }
 
-fn render_column_type_mysql(t: ColumnType) -> String {
+fn render_column_type_mysql(t: &ColumnType) -> String {
     match t {
         ColumnType::Boolean => format!("boolean"),
-        ColumnType::DateTime => format!("datetime(3)"),
+        ColumnType::DateTime | ColumnType::DateTimeTz => format!("datetime(3)"),
         ColumnType::Float => format!("Decimal(65,30)"),
         ColumnType::Int => format!("int"),
+        ColumnType::BigInt => format!("bigint"),
         // we use varchar right now as mediumtext doesn't allow default values
         // a bigger length would not allow to use such a column as primary key
-        ColumnType::String => format!("varchar(191)"),
+        ColumnType::String | ColumnType::Uuid => format!("varchar(191)"),
+        ColumnType::Xml => format!("text"),
+        ColumnType::Unsupported(tpe) => tpe.clone(),
     }
 }
@@ -1,4 +1,4 @@
-use datamodel::Value;
+use datamodel::{SortOrder, Value};
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,16 @@ impl SqlMigration {
             rollback: Vec::new(),
         }
     }
+
+    /// The inverse migration, which turns the database schema after this migration back into the one before it.
+    /// The rollback steps are inferred by diffing the database schemas in the opposite direction, so e.g. reversing
+    /// a type change restores the prior column type.
+    pub fn reverse(&self) -> SqlMigration {
+        SqlMigration {
+            steps: self.rollback.clone(),
+            rollback: self.steps.clone(),
+        }
+    }
 }
 
 impl DatabaseMigrationMarker for SqlMigration {
@@ -33,11 +43,18 @@ pub enum SqlMigrationStep {
     RawSql { raw: String },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
+    SetComment(SetComment),
+    CreateSequence(CreateSequence),
+    DropSequence(DropSequence),
+    MigrateScalarListToArray(MigrateScalarListToArray),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CreateTable {
     pub name: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub columns: Vec<ColumnDescription>,
     pub primary_columns: Vec<String>,
 }
@@ -45,6 +62,9 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -55,6 +75,9 @@ pub struct DropTables {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AlterTable {
     pub table: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub changes: Vec<TableChange>,
 }
 
@@ -62,7 +85,10 @@ pub struct AlterTable {
 pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
+    AlterColumnDefault(AlterColumnDefault),
+    AlterColumnCheck(AlterColumnCheck),
     DropColumn(DropColumn),
+    AddForeignKey(AddForeignKey),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -81,55 +107,180 @@ pub struct AlterColumn {
     pub column: ColumnDescription,
 }
 
+/// Changes the default of a column in place, without recreating the column. Without a default, it is dropped.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlterColumnDefault {
+    pub name: String,
+    pub tpe: ColumnType,
+    pub default: Option<Value>,
+}
+
+/// Replaces the `CHECK` constraint of a column, without recreating the column. Without a check, it is dropped.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlterColumnCheck {
+    pub name: String,
+    pub check: Option<String>,
+}
+
+/// Adds a composite foreign key to the columns of an existing table, see `ForeignKey::composite_columns`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddForeignKey {
+    pub foreign_key: ForeignKey,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ColumnDescription {
     pub name: String,
     pub tpe: ColumnType,
     pub required: bool,
+    #[serde(default)]
+    pub is_list: bool,
     pub foreign_key: Option<ForeignKey>,
     pub default: Option<Value>,
+    /// Rendered inline on MySQL. Other families set comments with a separate `SetComment`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// The name of the sequence providing the default of the column, see `CreateSequence`. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<String>,
+    /// The expression of the `CHECK` constraint of the column. Not used on MySQL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ForeignKey {
+    /// The name of the constraint. If not set, the database chooses a name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     pub table: String,
     pub column: String,
+    /// The schema of the referenced table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub on_delete: OnDelete,
+    #[serde(default)]
+    pub on_update: OnUpdate,
+    #[serde(default)]
+    pub deferrable: bool,
+    /// The columns of a composite foreign key, each paired with the column it references. Composite foreign keys
+    /// are not created with their columns, but by a separate `AddForeignKey`. Empty for single column foreign keys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub composite_columns: Vec<(String, String)>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum OnDelete {
     NoAction,
     SetNull,
+    SetDefault,
+    Restrict,
+    Cascade,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum OnUpdate {
+    NoAction,
+    SetNull,
+    SetDefault,
+    Restrict,
     Cascade,
 }
 
-#[derive(Debug, Copy, PartialEq, Eq, Clone, Serialize, Deserialize)]
+impl Default for OnUpdate {
+    fn default() -> Self {
+        OnUpdate::NoAction
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ColumnType {
     Int,
+    BigInt,
     Float,
     Boolean,
     String,
     DateTime,
+    DateTimeTz,
+    Xml,
+    Uuid,
+    /// The raw database type of a column Prisma cannot model.
+    Unsupported(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub name: String,
     pub tpe: IndexType,
     pub columns: Vec<String>,
+    #[serde(default)]
+    pub predicate: Option<String>,
+    /// The sort order of each column, in the order of `columns`. Empty if all columns are sorted ascending.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sort_orders: Vec<SortOrder>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub name: String,
+}
+
+/// Sets the comment of a table, or of one of its columns. A comment of `None` removes the existing comment.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetComment {
+    pub table: String,
+    /// The schema of the table, if it is not the schema of the connector. Only used on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// The column to set the comment of. MySQL can only change the comment of a column by restating its
+    /// whole definition, so the column is described in full.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<ColumnDescription>,
+    pub comment: Option<String>,
+}
+
+/// Creates the sequence of an id column with the `SEQUENCE` strategy. Only used on Postgres.
+/// Sequences always live in the schema of the connector.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateSequence {
+    pub name: String,
+    pub initial_value: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropSequence {
     pub name: String,
 }
 
+/// Copies the values of a scalar list from its separate table into the array column replacing it, when the field
+/// switches to the embedded strategy. Runs after the column is added and before the table is dropped. Only used on
+/// Postgres.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MigrateScalarListToArray {
+    pub table: String,
+    /// The schema of both tables, if it is not the schema of the connector.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub id_column: String,
+    pub column: String,
+    pub list_table: String,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum IndexType {
-    // can later add fulltext or custom ones
+    // can later add custom ones
     Unique,
     Normal,
+    Fulltext,
 }
+
+/// The text search configuration of the tsvectors backing fulltext indexes on Postgres.
+pub const POSTGRES_FULLTEXT_CONFIGURATION: &str = "simple";
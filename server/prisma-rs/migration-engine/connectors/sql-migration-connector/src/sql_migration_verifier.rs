@@ -0,0 +1,138 @@
+use crate::database_inspector::{DatabaseInspector, DatabaseSchema};
+use crate::sql_database_migration_inferrer::infer_database_migration_steps_and_fix;
+use crate::sql_database_step_applier::render_raw_sql;
+use crate::*;
+use std::sync::Arc;
+
+/// Applies a migration to a scratch schema first, so an invalid migration is detected before it touches the real
+/// schema. The scratch schema is a copy of the tables of the real schema without their data, and it is dropped
+/// again whether the migration applied or not.
+///
+/// Steps are rendered for the scratch schema. Migrations with steps that name another schema, e.g. for models with
+/// `@@schema`, or with raw SQL steps are refused, as those would run against the real tables.
+/// Not supported on SQLite, as the database file cannot hold a second schema.
+pub struct SqlMigrationVerifier {
+    pub sql_family: SqlFamily,
+    pub schema_name: String,
+    pub conn: Arc<dyn MigrationDatabase + Send + Sync + 'static>,
+    pub inspector: Arc<dyn DatabaseInspector + Send + Sync + 'static>,
+}
+
+impl SqlMigrationVerifier {
+    pub fn verify(&self, database_migration: &SqlMigration) -> SqlResult<()> {
+        if self.sql_family == SqlFamily::Sqlite {
+            return Err(SqlError::Generic(
+                "Verifying migrations is not supported on SQLite.".to_string(),
+            ));
+        }
+
+        if let Some(step) = database_migration
+            .steps
+            .iter()
+            .find(|step| !runs_in_connector_schema(step))
+        {
+            return Err(SqlError::Generic(format!(
+                "Verifying migrations is only supported for steps in the schema of the connector, without raw SQL. Refusing to verify the step {:?}.",
+                step
+            )));
+        }
+
+        let scratch_schema_name = format!("{}_verification", self.schema_name);
+
+        // A scratch schema may be left over from an interrupted verification.
+        self.drop_schema(&scratch_schema_name)?;
+        self.execute(
+            &scratch_schema_name,
+            &format!("CREATE SCHEMA {};", self.sql_family.quote(&scratch_schema_name)),
+        )?;
+
+        let result = self.apply_to_copy(database_migration, &scratch_schema_name);
+        let cleanup = self.drop_schema(&scratch_schema_name);
+
+        // An error of the migration takes precedence over one of the cleanup.
+        result.and(cleanup)
+    }
+
+    fn apply_to_copy(&self, database_migration: &SqlMigration, scratch_schema_name: &str) -> SqlResult<()> {
+        let current_database_schema = self.inspector.introspect(&self.schema_name);
+        let copy_steps = infer_database_migration_steps_and_fix(
+            &DatabaseSchema::empty(),
+            &current_database_schema,
+            scratch_schema_name,
+            self.sql_family,
+        )?;
+
+        for step in copy_steps.iter() {
+            self.execute(
+                scratch_schema_name,
+                &render_raw_sql(step, self.sql_family, scratch_schema_name),
+            )?;
+        }
+
+        for (index, step) in database_migration.steps.iter().enumerate() {
+            let sql = render_raw_sql(step, self.sql_family, scratch_schema_name);
+
+            self.execute(scratch_schema_name, &sql)
+                .map_err(|error| SqlError::VerificationError {
+                    step: index,
+                    sql,
+                    error: error.into(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn drop_schema(&self, schema_name: &str) -> SqlResult<()> {
+        let sql = match self.sql_family {
+            SqlFamily::Postgres => format!("DROP SCHEMA IF EXISTS {} CASCADE;", self.sql_family.quote(schema_name)),
+            _ => format!("DROP SCHEMA IF EXISTS {};", self.sql_family.quote(schema_name)),
+        };
+
+        self.execute(schema_name, &sql)
+    }
+
+    fn execute(&self, schema_name: &str, sql: &str) -> SqlResult<()> {
+        debug!("{}", sql);
+        self.conn.query_raw(schema_name, sql, &[])?;
+
+        Ok(())
+    }
+}
+
+/// Whether the step only touches the schema of the connector, so it can be rendered for the scratch schema instead.
+fn runs_in_connector_schema(step: &SqlMigrationStep) -> bool {
+    let references_other_schema = |column: &ColumnDescription| {
+        column
+            .foreign_key
+            .iter()
+            .any(|foreign_key| foreign_key.schema.is_some())
+    };
+
+    match step {
+        SqlMigrationStep::CreateTable(step) => {
+            step.schema.is_none() && !step.columns.iter().any(references_other_schema)
+        }
+        SqlMigrationStep::AlterTable(step) => {
+            step.schema.is_none()
+                && step.changes.iter().all(|change| match change {
+                    TableChange::AddColumn(AddColumn { column })
+                    | TableChange::AlterColumn(AlterColumn { column, .. }) => !references_other_schema(column),
+                    TableChange::AddForeignKey(AddForeignKey { foreign_key }) => foreign_key.schema.is_none(),
+                    TableChange::AlterColumnDefault(_)
+                    | TableChange::AlterColumnCheck(_)
+                    | TableChange::DropColumn(_) => true,
+                })
+        }
+        SqlMigrationStep::DropTable(step) => step.schema.is_none(),
+        SqlMigrationStep::CreateIndex(step) => step.schema.is_none(),
+        SqlMigrationStep::DropIndex(step) => step.schema.is_none(),
+        SqlMigrationStep::SetComment(step) => step.schema.is_none(),
+        SqlMigrationStep::MigrateScalarListToArray(step) => step.schema.is_none(),
+        SqlMigrationStep::RawSql { .. } => false,
+        SqlMigrationStep::DropTables(_)
+        | SqlMigrationStep::RenameTable { .. }
+        | SqlMigrationStep::CreateSequence(_)
+        | SqlMigrationStep::DropSequence(_) => true,
+    }
+}
@@ -32,49 +32,67 @@ fn all_columns_types_must_work() {
                     name: "int_col".to_string(),
                     tpe: ColumnType::Int,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "float_col".to_string(),
                     tpe: ColumnType::Float,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "boolean_col".to_string(),
                     tpe: ColumnType::Boolean,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "string1_col".to_string(),
                     tpe: ColumnType::String,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "string2_col".to_string(),
                     tpe: ColumnType::String,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "date_time_col".to_string(),
                     tpe: ColumnType::DateTime,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
             ];
 
@@ -101,17 +119,23 @@ fn is_required_must_work() {
                     name: "column1".to_string(),
                     tpe: ColumnType::Int,
                     is_required: true,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
                 Column {
                     name: "column2".to_string(),
                     tpe: ColumnType::Int,
                     is_required: false,
+                    is_list: false,
                     foreign_key: None,
                     sequence: None,
                     default: None,
+                    comment: None,
+                    check: None,
                 },
             ];
             assert_eq!(user_table.columns, expected_columns);
@@ -145,6 +169,7 @@ fn foreign_keys_must_work() {
                 name: "city".to_string(),
                 tpe: ColumnType::Int,
                 is_required: true,
+                is_list: false,
                 foreign_key: Some(ForeignKey::new(
                     "City".to_string(),
                     "id".to_string(),
@@ -152,6 +177,8 @@ fn foreign_keys_must_work() {
                 )),
                 sequence: None,
                 default: None,
+                comment: None,
+                check: None,
             }];
             assert_eq!(user_table.columns, expected_columns);
         },
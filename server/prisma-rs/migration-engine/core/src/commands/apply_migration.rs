@@ -51,9 +51,9 @@ impl<'a> ApplyMigrationCommand<'a> {
         let last_non_watch_datamodel = migration_persistence.last_non_watch_datamodel();
         let next_datamodel = engine
             .datamodel_calculator()
-            .infer(&last_non_watch_datamodel, &self.input.steps);
+            .infer(&last_non_watch_datamodel, &self.input.steps)?;
 
-        self.handle_migration(&engine, current_datamodel, next_datamodel)
+        self.handle_migration(&engine, current_datamodel, next_datamodel, &last_non_watch_datamodel)
     }
 
     fn handle_normal_migration<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<MigrationStepsResultOutput>
@@ -67,16 +67,19 @@ impl<'a> ApplyMigrationCommand<'a> {
 
         let next_datamodel = engine
             .datamodel_calculator()
-            .infer(&current_datamodel, &self.input.steps);
+            .infer(&current_datamodel, &self.input.steps)?;
 
-        self.handle_migration(&engine, current_datamodel, next_datamodel)
+        self.handle_migration(&engine, current_datamodel.clone(), next_datamodel, &current_datamodel)
     }
 
+    /// The input steps are applied to `steps_base_datamodel`, which differs from the current datamodel when
+    /// transitioning out of watch mode.
     fn handle_migration<C, D>(
         &self,
         engine: &MigrationEngine<C, D>,
         current_datamodel: Datamodel,
         next_datamodel: Datamodel,
+        steps_base_datamodel: &Datamodel,
     ) -> CommandResult<MigrationStepsResultOutput>
     where
         C: MigrationConnector<DatabaseMigration = D>,
@@ -94,6 +97,30 @@ impl<'a> ApplyMigrationCommand<'a> {
             .database_migration_step_applier()
             .render_steps_pretty(&database_migration)?;
 
+        let output = MigrationStepsResultOutput {
+            datamodel: datamodel::render(&next_datamodel).unwrap(),
+            datamodel_steps: self.input.steps.clone(),
+            destructiveness: self
+                .input
+                .steps
+                .iter()
+                .map(|step| step.destructiveness(steps_base_datamodel))
+                .collect(),
+            database_steps: database_steps_json_pretty,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            general_errors: Vec::new(),
+        };
+
+        if self.input.is_verified() {
+            connector.verify_migration(&database_migration)?;
+        }
+
+        // A dry run only reports the SQL of the migration. Neither the database nor the migrations table are touched.
+        if self.input.is_dry_run() {
+            return Ok(output);
+        }
+
         let database_migration_json = database_migration.serialize();
 
         let mut migration = Migration::new(self.input.migration_id.clone());
@@ -106,14 +133,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             .migration_applier()
             .apply(&saved_migration, &database_migration)?;
 
-        Ok(MigrationStepsResultOutput {
-            datamodel: datamodel::render(&next_datamodel).unwrap(),
-            datamodel_steps: self.input.steps.clone(),
-            database_steps: database_steps_json_pretty,
-            errors: Vec::new(),
-            warnings: Vec::new(),
-            general_errors: Vec::new(),
-        })
+        Ok(output)
     }
 }
 
@@ -123,6 +143,19 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    pub dry_run: Option<bool>,
+    /// Checks that the migration applies cleanly before it is applied, see `MigrationConnector::verify_migration`.
+    pub verify: Option<bool>,
+}
+
+impl ApplyMigrationInput {
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.unwrap_or(false)
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.verify.unwrap_or(false)
+    }
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
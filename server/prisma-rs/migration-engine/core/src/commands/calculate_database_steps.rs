@@ -27,11 +27,11 @@ impl<'a> MigrationCommand<'a> for CalculateDatabaseStepsCommand<'a> {
 
         let assumed_datamodel = engine
             .datamodel_calculator()
-            .infer(&Datamodel::empty(), &self.input.assume_to_be_applied);
+            .infer(&Datamodel::empty(), &self.input.assume_to_be_applied)?;
 
         let next_datamodel = engine
             .datamodel_calculator()
-            .infer(&assumed_datamodel, &self.input.steps_to_apply);
+            .infer(&assumed_datamodel, &self.input.steps_to_apply)?;
 
         let database_migration = connector.database_migration_inferrer().infer(
             &assumed_datamodel,
@@ -46,6 +46,12 @@ impl<'a> MigrationCommand<'a> for CalculateDatabaseStepsCommand<'a> {
         Ok(MigrationStepsResultOutput {
             datamodel: datamodel::render(&next_datamodel).unwrap(),
             datamodel_steps: self.input.steps_to_apply.clone(),
+            destructiveness: self
+                .input
+                .steps_to_apply
+                .iter()
+                .map(|step| step.destructiveness(&assumed_datamodel))
+                .collect(),
             database_steps: database_steps_json,
             errors: Vec::new(),
             warnings: Vec::new(),
@@ -23,7 +23,9 @@ impl<'a> MigrationCommand<'a> for CalculateDatamodelCommand<'a> {
         debug!("{:?}", self.input);
 
         let base_datamodel = Datamodel::empty();
-        let datamodel = engine.datamodel_calculator().infer(&base_datamodel, &self.input.steps);
+        let datamodel = engine
+            .datamodel_calculator()
+            .infer(&base_datamodel, &self.input.steps)?;
 
         Ok(CalculateDatamodelOutput {
             datamodel: datamodel::render(&datamodel).unwrap(),
@@ -28,7 +28,7 @@ impl<'a> MigrationCommand<'a> for InferMigrationStepsCommand<'a> {
         let current_datamodel = migration_persistence.current_datamodel();
         let assumed_datamodel = engine
             .datamodel_calculator()
-            .infer(&current_datamodel, &self.input.assume_to_be_applied);
+            .infer(&current_datamodel, &self.input.assume_to_be_applied)?;
 
         let next_datamodel = parse_datamodel(&self.input.datamodel)?;
 
@@ -46,17 +46,24 @@ impl<'a> MigrationCommand<'a> for InferMigrationStepsCommand<'a> {
             .database_migration_step_applier()
             .render_steps_pretty(&database_migration)?;
 
-        let returned_datamodel_steps = if self.input.is_watch_migration() {
-            model_migration_steps
+        // Without watch mode, the returned steps migrate from the last non-watch datamodel.
+        let (returned_datamodel_steps, previous_datamodel) = if self.input.is_watch_migration() {
+            (model_migration_steps, assumed_datamodel)
         } else {
             let mut steps = migration_persistence.load_all_datamodel_steps_from_all_current_watch_migrations();
             steps.append(&mut model_migration_steps.clone());
-            steps
+            (steps, migration_persistence.last_non_watch_datamodel())
         };
 
+        let destructiveness = returned_datamodel_steps
+            .iter()
+            .map(|step| step.destructiveness(&previous_datamodel))
+            .collect();
+
         Ok(MigrationStepsResultOutput {
             datamodel: datamodel::render(&next_datamodel).unwrap(),
             datamodel_steps: returned_datamodel_steps,
+            destructiveness,
             database_steps: database_steps_json,
             errors: vec![],
             warnings: vec![],
@@ -42,11 +42,15 @@ where
     let database_steps_json = connector
         .database_migration_step_applier()
         .render_steps_pretty(&database_migration)?;
+    let database_rollback_steps_json = connector
+        .database_migration_step_applier()
+        .render_rollback_steps_pretty(&database_migration)?;
 
     Ok(ListMigrationStepsOutput {
         id: migration.name,
         datamodel_steps: migration.datamodel_steps,
         database_steps: database_steps_json,
+        database_rollback_steps: database_rollback_steps_json,
         status: migration.status,
         datamodel: engine.render_datamodel(&migration.datamodel),
     })
@@ -58,6 +62,7 @@ pub struct ListMigrationStepsOutput {
     pub id: String,
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_steps: serde_json::Value,
+    pub database_rollback_steps: serde_json::Value,
     pub status: MigrationStatus,
     pub datamodel: String,
 }
@@ -18,7 +18,7 @@ pub use migration_progress::*;
 pub use reset::*;
 pub use unapply_migration::*;
 
-use migration_connector::{MigrationError, MigrationStep, MigrationWarning};
+use migration_connector::{Destructiveness, MigrationError, MigrationStep, MigrationWarning};
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -34,6 +34,8 @@ pub struct DataModelWarningOrError {
 pub struct MigrationStepsResultOutput {
     pub datamodel: String,
     pub datamodel_steps: Vec<MigrationStep>,
+    /// The destructiveness of each of the `datamodel_steps`, in the same order.
+    pub destructiveness: Vec<Destructiveness>,
     pub database_steps: serde_json::Value,
     pub warnings: Vec<MigrationWarning>,
     pub errors: Vec<MigrationError>,
@@ -17,9 +17,21 @@ use datamodel::{self, Datamodel};
 pub use error::Error;
 pub use migration_engine::*;
 
+/// Parses a datamodel for migration. The engine migrates the first datasource, so models stored in other
+/// datasources, see `@@datasource`, are left out. They are migrated by the engines of their datasources.
 pub fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
     let result = datamodel::parse_with_formatted_error(&datamodel, "datamodel file, line");
-    result.map_err(|e| CommandError::Generic { code: 1001, error: e })
+    let mut parsed = result.map_err(|e| CommandError::Generic { code: 1001, error: e })?;
+
+    if let Ok(config) = datamodel::load_configuration(datamodel) {
+        if let Some(source) = config.datasources.first() {
+            parsed
+                .models
+                .retain(|model| config.datasource_for_model(model).map(|s| s.name()) == Some(source.name()));
+        }
+    }
+
+    Ok(parsed)
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -1,3 +1,4 @@
+use datamodel::errors::ErrorCollection;
 use datamodel::*;
 use migration_connector::steps::*;
 
@@ -11,12 +12,12 @@ macro_rules! set (
 );
 
 pub trait DataModelCalculator: Send + Sync + 'static {
-    fn infer(&self, current: &Datamodel, steps: &Vec<MigrationStep>) -> Datamodel;
+    fn infer(&self, current: &Datamodel, steps: &Vec<MigrationStep>) -> Result<Datamodel, ErrorCollection>;
 }
 
 pub struct DataModelCalculatorImpl {}
 impl DataModelCalculator for DataModelCalculatorImpl {
-    fn infer(&self, current: &Datamodel, steps: &Vec<MigrationStep>) -> Datamodel {
+    fn infer(&self, current: &Datamodel, steps: &Vec<MigrationStep>) -> Result<Datamodel, ErrorCollection> {
         let mut result = current.clone();
         steps.into_iter().for_each(|step| match step {
             MigrationStep::DeleteModel(x) => apply_delete_model(&mut result, x),
@@ -29,7 +30,9 @@ impl DataModelCalculator for DataModelCalculatorImpl {
             MigrationStep::UpdateField(x) => apply_update_field(&mut result, x),
             MigrationStep::CreateField(x) => apply_create_field(&mut result, x),
         });
-        result
+
+        // Validating adds the generated back relation fields, which are not part of the steps.
+        datamodel::validate(&result)
     }
 }
 
@@ -52,6 +55,10 @@ fn apply_update_model(data_model: &mut Datamodel, step: &UpdateModel) {
     set!(model, step, name, new_name);
     set!(model, step, is_embedded, embedded);
     set!(model, step, database_name, db_name);
+    set!(model, step, indices, indices);
+    set!(model, step, compound_id_fields, compound_id_fields);
+    set!(model, step, schema, schema);
+    set!(model, step, documentation, documentation);
 }
 
 fn apply_create_model(data_model: &mut Datamodel, step: &CreateModel) {
@@ -64,6 +71,10 @@ fn apply_create_model(data_model: &mut Datamodel, step: &CreateModel) {
     let mut model = Model::new(&step.name);
     model.is_embedded = step.embedded;
     model.database_name = step.db_name.clone();
+    model.indices = step.indices.clone();
+    model.compound_id_fields = step.compound_id_fields.clone();
+    model.schema = step.schema.clone();
+    model.documentation = step.documentation.clone();
     data_model.add_model(model);
 }
 
@@ -131,6 +142,9 @@ fn apply_update_field(data_model: &mut Datamodel, step: &UpdateField) {
     set!(field, step, id_info, id_info);
     set!(field, step, default_value, default);
     set!(field, step, scalar_list_strategy, scalar_list);
+    set!(field, step, native_type, native_type);
+    set!(field, step, check, check);
+    set!(field, step, documentation, documentation);
     set!(field, step, is_unique, is_unique);
 }
 
@@ -139,11 +153,14 @@ fn apply_create_field(data_model: &mut Datamodel, step: &CreateField) {
         "The model {} does not exist in this Datamodel. It is not possible to create a field in it.",
         step.model
     ));
-    if model.find_field(&step.name).is_some() {
-        panic!(
+    match model.find_field(&step.name) {
+        // A generated back relation field is replaced by the explicitly declared one.
+        Some(field) if field.is_generated => model.remove_field(&step.name),
+        Some(_) => panic!(
             "The field {} on model {} already exists in this Datamodel. It is not possible to create it once more.",
             &step.name, &step.model
-        )
+        ),
+        None => (),
     }
     let mut field = Field::new(&step.name, step.tpe.clone());
     field.arity = step.arity;
@@ -152,6 +169,9 @@ fn apply_create_field(data_model: &mut Datamodel, step: &CreateField) {
     field.is_unique = step.is_unique;
     field.id_info = step.id.clone();
     field.scalar_list_strategy = step.scalar_list;
+    field.native_type = step.native_type;
+    field.check = step.check.clone();
+    field.documentation = step.documentation.clone();
 
     model.add_field(field);
 }
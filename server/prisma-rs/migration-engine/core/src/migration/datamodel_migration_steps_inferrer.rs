@@ -54,6 +54,10 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
                     name: next_model.name().to_string(),
                     db_name: next_model.database_name.as_ref().cloned(),
                     embedded: next_model.is_embedded,
+                    indices: next_model.indices.clone(),
+                    compound_id_fields: next_model.compound_id_fields.clone(),
+                    schema: next_model.schema.clone(),
+                    documentation: next_model.documentation.clone(),
                 };
                 result.push(step);
             }
@@ -71,6 +75,10 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
                     new_name: None,
                     db_name: Self::diff(&previous_model.database_name, &next_model.database_name),
                     embedded: Self::diff(&previous_model.is_embedded, &next_model.is_embedded),
+                    indices: Self::diff(&previous_model.indices, &next_model.indices),
+                    compound_id_fields: Self::diff(&previous_model.compound_id_fields, &next_model.compound_id_fields),
+                    schema: Self::diff(&previous_model.schema, &next_model.schema),
+                    documentation: Self::diff(&previous_model.documentation, &next_model.documentation),
                 };
                 if step.is_any_option_set() {
                     result.push(step);
@@ -97,10 +105,10 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
     fn fields_to_create(&self) -> Vec<CreateField> {
         let mut result = Vec::new();
         for next_model in self.next.models() {
-            for next_field in next_model.fields() {
+            for next_field in declared_fields(next_model) {
                 let must_create_field = match self.previous.find_model(&next_model.name) {
                     None => true,
-                    Some(previous_model) => previous_model.find_field(&next_field.name).is_none(),
+                    Some(previous_model) => find_declared_field(previous_model, &next_field.name).is_none(),
                 };
                 if must_create_field {
                     let step = CreateField {
@@ -115,6 +123,9 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
                         is_updated_at: None,
                         is_unique: next_field.is_unique,
                         scalar_list: next_field.scalar_list_strategy,
+                        native_type: next_field.native_type,
+                        check: next_field.check.clone(),
+                        documentation: next_field.documentation.clone(),
                     };
                     result.push(step);
                 }
@@ -131,10 +142,10 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
                 .find(|dm| dm.name == previous_model.name)
                 .is_none();
             if model_is_deleted {
-                for previous_field in previous_model.fields() {
+                for previous_field in declared_fields(previous_model) {
                     let must_delete_field = match self.next.find_model(&previous_model.name) {
                         None => true,
-                        Some(next_model) => next_model.find_field(&previous_field.name).is_none(),
+                        Some(next_model) => find_declared_field(next_model, &previous_field.name).is_none(),
                     };
                     if must_delete_field {
                         let step = DeleteField {
@@ -152,11 +163,11 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
     fn fields_to_update(&self) -> Vec<UpdateField> {
         let mut result = Vec::new();
         for previous_model in self.previous.models() {
-            for previous_field in previous_model.fields() {
+            for previous_field in declared_fields(previous_model) {
                 if let Some(next_field) = self
                     .next
                     .find_model(&previous_model.name)
-                    .and_then(|m| m.find_field(&previous_field.name))
+                    .and_then(|m| find_declared_field(m, &previous_field.name))
                 {
                     let (p, n) = (previous_field, next_field);
                     let step = UpdateField {
@@ -172,6 +183,9 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
                         id_info: None,
                         default: Self::diff(&p.default_value, &n.default_value),
                         scalar_list: Self::diff(&p.scalar_list_strategy, &n.scalar_list_strategy),
+                        native_type: Self::diff(&p.native_type, &n.native_type),
+                        check: Self::diff(&p.check, &n.check),
+                        documentation: Self::diff(&p.documentation, &n.documentation),
                     };
                     if step.is_any_option_set() {
                         result.push(step);
@@ -245,3 +259,13 @@ impl<'a> DataModelMigrationStepsInferrerImpl<'a> {
         steps.into_iter().map(|x| wrap_fn(x)).collect()
     }
 }
+
+/// Generated back relation fields are not part of the migration steps, the datamodel calculator
+/// adds them again when it validates its result.
+fn declared_fields(model: &Model) -> impl Iterator<Item = &Field> {
+    model.fields().filter(|field| !field.is_generated)
+}
+
+fn find_declared_field<'a>(model: &'a Model, name: &str) -> Option<&'a Field> {
+    declared_fields(model).find(|field| field.name == name)
+}
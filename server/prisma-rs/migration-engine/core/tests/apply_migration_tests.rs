@@ -2,6 +2,8 @@
 mod test_harness;
 use datamodel::dml::*;
 use migration_connector::*;
+use migration_core::commands::*;
+use sql_migration_connector::SqlFamily;
 use test_harness::*;
 
 #[test]
@@ -150,3 +152,72 @@ fn must_handle_additional_steps_when_transitioning_out_of_watch_mode() {
         assert_eq!(migrations[2].name, custom_migration_id);
     });
 }
+
+#[test]
+fn dry_runs_must_return_the_sql_without_touching_the_database() {
+    test_each_connector(|_, api| {
+        let migration_persistence = api.migration_persistence();
+        let db_schema_before = introspect_database(api);
+
+        let steps = vec![
+            create_model_step("Test"),
+            create_id_field_step("Test", "id", ScalarType::Int),
+        ];
+
+        let output = apply_migration_dry_run(api, steps, "the-migration-id");
+
+        let raw_sql: Vec<&str> = output
+            .database_steps
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|step| step["raw"].as_str().unwrap())
+            .collect();
+        assert!(!raw_sql.is_empty());
+        assert!(raw_sql.iter().any(|sql| sql.contains("CREATE TABLE")));
+
+        assert_eq!(introspect_database(api), db_schema_before);
+        assert!(migration_persistence.load_all().is_empty());
+    });
+}
+
+#[test]
+fn verified_migrations_must_be_applied() {
+    test_each_connector_with_ignores(vec![SqlFamily::Sqlite], |_, api| {
+        let steps = vec![
+            create_model_step("Test"),
+            create_id_field_step("Test", "id", ScalarType::Int),
+        ];
+
+        apply_migration_verified(api, steps, "the-migration-id").unwrap();
+
+        introspect_database(api).table_bang("Test");
+        assert_eq!(api.migration_persistence().load_all().len(), 1);
+    });
+}
+
+#[test]
+fn verified_migrations_that_fail_must_not_touch_the_database() {
+    test_only_connector(SqlFamily::Postgres, |_, api| {
+        let migration_persistence = api.migration_persistence();
+        let db_schema_before = introspect_database(api);
+
+        let dm = r#"
+            model Test {
+                id Int @id
+                age Int @check("doesNotExist >= 0")
+            }
+        "#;
+        let input = InferMigrationStepsInput {
+            migration_id: "the-migration-id".to_string(),
+            datamodel: dm.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+        let steps = run_infer_command(api, input);
+
+        assert!(apply_migration_verified(api, steps, "the-migration-id").is_err());
+
+        assert_eq!(introspect_database(api), db_schema_before);
+        assert!(migration_persistence.load_all().is_empty());
+    });
+}
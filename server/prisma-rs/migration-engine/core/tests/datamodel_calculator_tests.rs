@@ -58,6 +58,65 @@ fn add_UpdateModel_to_existing_schema() {
     test(dm1, dm2);
 }
 
+#[test]
+fn add_CreateModel_with_compound_id_to_existing_schema() {
+    let dm1 = Datamodel::empty();
+    let dm2 = parse(
+        r#"
+        model Test {
+            firstName String
+            lastName String
+
+            @@id([firstName, lastName])
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
+#[test]
+fn add_UpdateModel_changing_the_compound_id_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            firstName String
+            lastName String
+
+            @@id([firstName, lastName])
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Test {
+            firstName String
+            lastName String
+
+            @@id([lastName, firstName])
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
+#[test]
+fn steps_resulting_in_an_invalid_datamodel_must_error() {
+    let steps = vec![MigrationStep::CreateModel(CreateModel {
+        name: "Test".to_string(),
+        db_name: None,
+        embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
+    })];
+
+    let calc = DataModelCalculatorImpl {};
+    assert!(calc.infer(&Datamodel::empty(), &steps).is_err());
+}
+
 #[test]
 fn add_CreateField_to_existing_schema() {
     let dm1 = parse(
@@ -107,6 +166,64 @@ fn add_CreateField_for_relation_to_existing_schema() {
     test(dm1, dm2);
 }
 
+#[test]
+fn add_CreateField_for_relation_with_implicit_back_relation_field_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        model Blog {
+            id String @id @default(cuid())
+        }
+        model Post {
+            id String @id @default(cuid())
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Blog {
+            id String @id @default(cuid())
+            posts Post[]
+        }
+        model Post {
+            id String @id @default(cuid())
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+    assert_eq!(steps.len(), 1);
+    assert_eq!(calculate(&dm1, steps), dm2);
+}
+
+#[test]
+fn add_CreateField_replacing_an_implicit_back_relation_field_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        model Blog {
+            id String @id @default(cuid())
+            posts Post[]
+        }
+        model Post {
+            id String @id @default(cuid())
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Blog {
+            id String @id @default(cuid())
+            posts Post[]
+        }
+        model Post {
+            id String @id @default(cuid())
+            blog Blog?
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
 #[test]
 fn add_DeleteField_to_existing_schema() {
     let dm1 = parse(
@@ -150,6 +267,30 @@ fn add_UpdateField_to_existing_schema() {
     test(dm1, dm2);
 }
 
+#[test]
+fn add_documentation_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            id Int @id
+            name String
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        /// A test.
+        model Test {
+            id Int @id
+            /// The name of the test.
+            name String
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
 #[test]
 fn add_CreateEnum_to_existing_schema() {
     let dm1 = Datamodel::empty();
@@ -197,6 +338,10 @@ fn creating_a_model_that_already_exists_must_error() {
         name: "Test".to_string(),
         db_name: None,
         embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
     })];
 
     calculate(&dm, steps);
@@ -227,6 +372,9 @@ fn creating_a_field_that_already_exists_must_error() {
         id: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })];
 
     calculate(&dm, steps);
@@ -318,6 +466,10 @@ fn updating_a_model_that_does_not_exist_must_error() {
         new_name: None,
         db_name: None,
         embedded: None,
+        indices: None,
+        compound_id_fields: None,
+        schema: None,
+        documentation: None,
     })];
 
     calculate(&dm, steps);
@@ -342,6 +494,9 @@ fn updating_a_field_that_does_not_exist_must_error() {
         id_info: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })];
 
     calculate(&dm, steps);
@@ -372,6 +527,9 @@ fn updating_a_field_that_does_not_exist_must_error_2() {
         id_info: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })];
 
     calculate(&dm, steps);
@@ -399,7 +557,7 @@ fn test(dm1: Datamodel, dm2: Datamodel) {
 }
 fn calculate(schema: &Datamodel, steps: Vec<MigrationStep>) -> Datamodel {
     let calc = DataModelCalculatorImpl {};
-    calc.infer(schema, &steps)
+    calc.infer(schema, &steps).unwrap()
 }
 
 fn infer(dm1: &Datamodel, dm2: &Datamodel) -> Vec<MigrationStep> {
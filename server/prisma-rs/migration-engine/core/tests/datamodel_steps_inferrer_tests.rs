@@ -23,6 +23,10 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
             name: "Test".to_string(),
             db_name: None,
             embedded: false,
+            indices: vec![],
+            compound_id_fields: vec![],
+            schema: None,
+            documentation: None,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -39,6 +43,9 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
             }),
             default: None,
             scalar_list: None,
+            native_type: None,
+            check: None,
+            documentation: None,
         }),
     ];
     assert_eq!(steps, expected);
@@ -88,6 +95,53 @@ fn infer_UpdateModel() {
         new_name: None,
         db_name: None,
         embedded: Some(true),
+        indices: None,
+        compound_id_fields: None,
+        schema: None,
+        documentation: None,
+    })];
+    assert_eq!(steps, expected);
+}
+
+#[test]
+fn infer_UpdateModel_for_changed_indices() {
+    let dm1 = parse(
+        r#"
+        model Post {
+            id String @id @default(cuid())
+            title String
+
+            @@index([title], name: "idx_title")
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Post {
+            id String @id @default(cuid())
+            title String
+
+            @@unique([title], name: "uq_title")
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+    let expected = vec![MigrationStep::UpdateModel(UpdateModel {
+        name: "Post".to_string(),
+        new_name: None,
+        db_name: None,
+        embedded: None,
+        indices: Some(vec![IndexDefinition {
+            name: Some("uq_title".to_string()),
+            fields: vec!["title".to_string()],
+            tpe: IndexType::Unique,
+            predicate: None,
+            sort_orders: vec![],
+        }]),
+        compound_id_fields: None,
+        schema: None,
+        documentation: None,
     })];
     assert_eq!(steps, expected);
 }
@@ -123,6 +177,9 @@ fn infer_CreateField_if_it_does_not_exist_yet() {
         id: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })];
     assert_eq!(steps, expected);
 }
@@ -160,8 +217,11 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
             tpe: FieldType::Relation(RelationInfo {
                 to: "Post".to_string(),
                 to_fields: vec![],
+                fields: vec![],
                 name: String::from("BlogToPost"),
                 on_delete: OnDeleteStrategy::None,
+                on_update: OnUpdateStrategy::None,
+                fk_name: None,
             }),
             arity: FieldArity::List,
             db_name: None,
@@ -171,6 +231,9 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
             id: None,
             default: None,
             scalar_list: None,
+            native_type: None,
+            check: None,
+            documentation: None,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Post".to_string(),
@@ -178,8 +241,11 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
             tpe: FieldType::Relation(RelationInfo {
                 to: "Blog".to_string(),
                 to_fields: vec![String::from("id")],
+                fields: vec![],
                 name: String::from("BlogToPost"),
                 on_delete: OnDeleteStrategy::None,
+                on_update: OnUpdateStrategy::None,
+                fk_name: None,
             }),
             arity: FieldArity::Optional,
             db_name: None,
@@ -189,6 +255,9 @@ fn infer_CreateField_if_relation_field_does_not_exist_yet() {
             id: None,
             default: None,
             scalar_list: None,
+            native_type: None,
+            check: None,
+            documentation: None,
         }),
     ];
     assert_eq!(steps, expected);
@@ -253,6 +322,9 @@ fn infer_UpdateField_simple() {
         id_info: None,
         default: Some(Some(Value::Boolean(false))),
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })];
     assert_eq!(steps, expected);
 }
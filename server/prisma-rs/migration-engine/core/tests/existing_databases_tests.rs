@@ -295,6 +295,40 @@ fn renaming_a_field_where_the_column_was_already_renamed_must_work() {
     })
 }
 
+#[test]
+fn columns_of_unsupported_types_must_be_left_alone() {
+    test_each_backend_with_ignores(vec![SqlFamily::Sqlite], |api, barrel| {
+        let initial_result = barrel.execute(|migration| {
+            migration.create_table("Host", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("network", types::custom("cidr"));
+            });
+        });
+        let initial_column = initial_result.table_bang("Host").column_bang("network");
+        assert_eq!(initial_column.tpe, ColumnType::Unsupported("cidr".to_string()));
+
+        let dm1 = r#"
+            model Host {
+                id Int @id
+                name String?
+            }
+        "#;
+        let result = infer_and_apply(api, &dm1);
+        assert_eq!(result.table_bang("Host").column_bang("network"), initial_column);
+        assert_eq!(result.table_bang("Host").column("name").is_some(), true);
+
+        let dm2 = r#"
+            model Host {
+                id Int @id
+                network Unsupported("cidr")
+            }
+        "#;
+        let result = infer_and_apply(api, &dm2);
+        assert_eq!(result.table_bang("Host").column_bang("network"), initial_column);
+        assert_eq!(result.table_bang("Host").column("name").is_some(), false);
+    });
+}
+
 fn test_each_backend<F>(test_fn: F)
 where
     F: Fn(&dyn GenericApi, &BarrelMigrationExecutor) -> () + std::panic::RefUnwindSafe,
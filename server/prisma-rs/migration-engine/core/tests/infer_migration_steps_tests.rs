@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 mod test_harness;
 use datamodel::dml::*;
+use migration_connector::Destructiveness;
 use migration_core::commands::*;
 use test_harness::*;
 
@@ -103,3 +104,36 @@ fn special_handling_of_watch_migrations() {
         );
     });
 }
+
+#[test]
+fn infer_migration_steps_must_classify_the_destructiveness_of_each_step() {
+    test_each_connector(|_, api| {
+        let dm1 = r#"
+            model Blog {
+                id Int @id
+                title String
+            }
+        "#;
+        infer_and_apply(api, &dm1);
+
+        let dm2 = r#"
+            model Blog {
+                id Int @id
+                title String
+                subtitle String?
+            }
+        "#;
+        let output = infer_migration_output(api, &dm2, "mig0002");
+        assert_eq!(output.datamodel_steps.len(), 1);
+        assert_eq!(output.destructiveness, vec![Destructiveness::Safe]);
+
+        let dm3 = r#"
+            model Blog {
+                id Int @id
+            }
+        "#;
+        let output = infer_migration_output(api, &dm3, "mig0003");
+        assert_eq!(output.datamodel_steps, vec![delete_field_step("Blog", "title")]);
+        assert_eq!(output.destructiveness, vec![Destructiveness::Destructive]);
+    });
+}
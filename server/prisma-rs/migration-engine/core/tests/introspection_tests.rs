@@ -0,0 +1,195 @@
+#![allow(non_snake_case)]
+mod test_harness;
+use datamodel::dml::{FieldArity, FieldType, SortOrder};
+use sql_migration_connector::{database_inspector::*, DatamodelIntrospector, SqlFamily};
+use test_harness::*;
+
+#[test]
+fn introspecting_an_applied_datamodel_must_result_in_the_same_database_structure() {
+    test_each_connector(|_, api| {
+        let dm = r#"
+            model Blog {
+                id Int @id
+                name String @unique
+                description String?
+                created_at DateTime
+                posts Post[]
+                tags String[]
+            }
+
+            model Post {
+                id Int @id
+                title String
+                published Boolean
+                rating Float?
+                blog Blog
+            }
+        "#;
+        let initial_result = infer_and_apply(api, &dm);
+
+        let introspected = DatamodelIntrospector::introspect(&initial_result).unwrap();
+        let introspected_dm = datamodel::render(&introspected).unwrap();
+
+        let blog = introspected.find_model("Blog").unwrap();
+        let created_at = blog.find_field("createdAt").unwrap();
+        assert_eq!(created_at.database_name, Some("created_at".to_string()));
+
+        let result = infer_and_apply_with_migration_id(api, &introspected_dm, "the-second-migration-id");
+        assert_eq!(initial_result, result);
+    });
+}
+
+#[test]
+fn introspecting_a_descending_index_must_preserve_its_sort_order() {
+    test_each_connector_with_ignores(vec![SqlFamily::Mysql], |_, api| {
+        let dm = r#"
+            model Post {
+                id Int @id
+                createdAt DateTime
+
+                @@index([createdAt(sort: Desc)], name: "idx_recent")
+            }
+        "#;
+        let initial_result = infer_and_apply(api, &dm);
+
+        let introspected = DatamodelIntrospector::introspect(&initial_result).unwrap();
+        let introspected_dm = datamodel::render(&introspected).unwrap();
+
+        let index = introspected
+            .find_model("Post")
+            .unwrap()
+            .find_index("idx_recent")
+            .unwrap();
+        assert_eq!(index.sort_orders, vec![SortOrder::Desc]);
+
+        let result = infer_and_apply_with_migration_id(api, &introspected_dm, "the-second-migration-id");
+        assert_eq!(initial_result, result);
+    });
+}
+
+#[test]
+fn introspecting_a_column_of_an_unsupported_type_must_result_in_an_unsupported_field() {
+    let database_schema = DatabaseSchema {
+        tables: vec![Table {
+            name: "Host".to_string(),
+            schema: None,
+            comment: None,
+            columns: vec![
+                Column::new("id".to_string(), ColumnType::Int, true),
+                Column::new(
+                    "network".to_string(),
+                    ColumnType::Unsupported("cidr".to_string()),
+                    false,
+                ),
+            ],
+            indexes: vec![],
+            primary_key_columns: vec!["id".to_string()],
+        }],
+    };
+
+    let introspected = DatamodelIntrospector::introspect(&database_schema).unwrap();
+    let network = introspected.find_model("Host").unwrap().find_field("network").unwrap();
+    assert_eq!(network.field_type, FieldType::Unsupported("cidr".to_string()));
+    assert_eq!(network.arity, FieldArity::Optional);
+
+    let introspected_dm = datamodel::render(&introspected).unwrap();
+    assert!(introspected_dm.contains(r#"network Unsupported("cidr")?"#));
+    assert_eq!(datamodel::parse(&introspected_dm).unwrap(), introspected);
+}
+
+#[test]
+fn introspecting_several_foreign_keys_to_the_same_table_must_name_their_relations() {
+    test_each_connector(|_, api| {
+        let dm = r#"
+            model User {
+                id Int @id
+                authoredPosts Post[] @relation("author")
+                reviewedPosts Post[] @relation("reviewer")
+            }
+
+            model Post {
+                id Int @id
+                author User @relation("author")
+                reviewer User @relation("reviewer")
+            }
+        "#;
+        let initial_result = infer_and_apply(api, &dm);
+
+        let introspected = DatamodelIntrospector::introspect(&initial_result).unwrap();
+        let introspected_dm = datamodel::render(&introspected).unwrap();
+
+        let post = introspected.find_model("Post").unwrap();
+        let relation_name = |field_name: &str| match &post.find_field(field_name).unwrap().field_type {
+            FieldType::Relation(relation_info) => relation_info.name.clone(),
+            field_type => panic!("{:?} is not a relation", field_type),
+        };
+        assert_eq!(relation_name("author"), "Post_authorToUser");
+        assert_eq!(relation_name("reviewer"), "Post_reviewerToUser");
+
+        let user = introspected.find_model("User").unwrap();
+        assert_eq!(user.find_field("postAuthor").unwrap().arity, FieldArity::List);
+        assert_eq!(user.find_field("postReviewer").unwrap().arity, FieldArity::List);
+
+        let result = infer_and_apply_with_migration_id(api, &introspected_dm, "the-second-migration-id");
+        assert_eq!(initial_result, result);
+    });
+}
+
+#[test]
+fn introspecting_a_composite_primary_key_must_result_in_a_compound_id() {
+    test_each_connector(|_, api| {
+        let dm = r#"
+            model User {
+                first_name String
+                lastName String
+
+                @@id([lastName, first_name])
+            }
+        "#;
+        let initial_result = infer_and_apply(api, &dm);
+
+        let introspected = DatamodelIntrospector::introspect(&initial_result).unwrap();
+        let introspected_dm = datamodel::render(&introspected).unwrap();
+
+        let user = introspected.find_model("User").unwrap();
+        assert_eq!(user.compound_id_fields, vec!["lastName", "firstName"]);
+
+        let result = infer_and_apply_with_migration_id(api, &introspected_dm, "the-second-migration-id");
+        assert_eq!(initial_result, result);
+    });
+}
+
+#[test]
+fn introspecting_a_scalar_list_table_must_only_strip_the_name_of_its_parent_table_once() {
+    let mut node_id = Column::new("nodeId".to_string(), ColumnType::Int, true);
+    node_id.foreign_key = Some(ForeignKey::new("A".to_string(), "id".to_string(), OnDelete::Cascade));
+    let database_schema = DatabaseSchema {
+        tables: vec![
+            Table {
+                name: "A".to_string(),
+                schema: None,
+                comment: None,
+                columns: vec![Column::new("id".to_string(), ColumnType::Int, true)],
+                indexes: vec![],
+                primary_key_columns: vec!["id".to_string()],
+            },
+            Table {
+                name: "A_A_tags".to_string(),
+                schema: None,
+                comment: None,
+                columns: vec![
+                    node_id,
+                    Column::new("position".to_string(), ColumnType::Int, true),
+                    Column::new("value".to_string(), ColumnType::String, true),
+                ],
+                indexes: vec![],
+                primary_key_columns: vec!["nodeId".to_string(), "position".to_string()],
+            },
+        ],
+    };
+
+    let introspected = DatamodelIntrospector::introspect(&database_schema).unwrap();
+    let tags = introspected.find_model("A").unwrap().find_field("aTags").unwrap();
+    assert_eq!(tags.arity, FieldArity::List);
+    assert_eq!(tags.database_name, Some("A_tags".to_string()));
+}
@@ -19,6 +19,31 @@ pub fn infer_and_apply_with_migration_id(api: &dyn GenericApi, datamodel: &str,
     apply_migration(api, steps, migration_id)
 }
 
+/// Runs the infer command for a datamodel that cannot be migrated and returns the rendered error.
+pub fn infer_and_expect_error(api: &dyn GenericApi, datamodel: &str) -> String {
+    let input = InferMigrationStepsInput {
+        migration_id: "the-migration-id".to_string(),
+        datamodel: datamodel.to_string(),
+        assume_to_be_applied: Vec::new(),
+    };
+
+    match api.infer_migration_steps(&input) {
+        Ok(_) => panic!("InferMigration succeeded, but an error was expected."),
+        Err(err) => format!("{}", err),
+    }
+}
+
+/// Runs the infer command and returns its complete output, including the database steps and warnings.
+pub fn infer_migration_output(api: &dyn GenericApi, datamodel: &str, migration_id: &str) -> MigrationStepsResultOutput {
+    let input = InferMigrationStepsInput {
+        migration_id: migration_id.to_string(),
+        datamodel: datamodel.to_string(),
+        assume_to_be_applied: Vec::new(),
+    };
+
+    api.infer_migration_steps(&input).expect("InferMigration failed")
+}
+
 pub fn run_infer_command(api: &dyn GenericApi, input: InferMigrationStepsInput) -> Vec<MigrationStep> {
     let output = api.infer_migration_steps(&input).expect("InferMigration failed");
 
@@ -35,6 +60,8 @@ pub fn apply_migration(api: &dyn GenericApi, steps: Vec<MigrationStep>, migratio
         migration_id: migration_id.to_string(),
         steps: steps,
         force: None,
+        dry_run: None,
+        verify: None,
     };
 
     let output = api.apply_migration(&input).expect("ApplyMigration failed");
@@ -47,6 +74,40 @@ pub fn apply_migration(api: &dyn GenericApi, steps: Vec<MigrationStep>, migratio
     introspect_database(api)
 }
 
+/// Runs the apply command as a dry run and returns its output, which contains the SQL of each database step.
+pub fn apply_migration_dry_run(
+    api: &dyn GenericApi,
+    steps: Vec<MigrationStep>,
+    migration_id: &str,
+) -> MigrationStepsResultOutput {
+    let input = ApplyMigrationInput {
+        migration_id: migration_id.to_string(),
+        steps: steps,
+        force: None,
+        dry_run: Some(true),
+        verify: None,
+    };
+
+    api.apply_migration(&input).expect("ApplyMigration failed")
+}
+
+/// Runs the apply command with `verify` set and returns its result, which is an error if the verification failed.
+pub fn apply_migration_verified(
+    api: &dyn GenericApi,
+    steps: Vec<MigrationStep>,
+    migration_id: &str,
+) -> migration_core::Result<MigrationStepsResultOutput> {
+    let input = ApplyMigrationInput {
+        migration_id: migration_id.to_string(),
+        steps: steps,
+        force: None,
+        dry_run: None,
+        verify: Some(true),
+    };
+
+    api.apply_migration(&input)
+}
+
 pub fn unapply_migration(api: &dyn GenericApi) -> DatabaseSchema {
     let input = UnapplyMigrationInput {};
     let _ = api.unapply_migration(&input);
@@ -16,6 +16,9 @@ pub fn parse(datamodel_string: &str) -> datamodel::Datamodel {
     parse_datamodel(datamodel_string).unwrap()
 }
 
+/// Runs the test against every connector. The closure receives the `SqlFamily` that is currently
+/// under test, so assertions that differ between databases (native column types, enum handling)
+/// can branch on it instead of ignoring whole connectors.
 pub fn test_each_connector<F>(test_fn: F)
 where
     F: Fn(SqlFamily, &dyn GenericApi) -> () + std::panic::RefUnwindSafe,
@@ -23,6 +26,7 @@ where
     test_each_connector_with_ignores(Vec::new(), test_fn);
 }
 
+/// Runs the test against the given connector only.
 pub fn test_only_connector<F>(sql_family: SqlFamily, test_fn: F)
 where
     F: Fn(SqlFamily, &dyn GenericApi) -> () + std::panic::RefUnwindSafe,
@@ -33,6 +37,8 @@ where
     test_each_connector_with_ignores(ignores, test_fn);
 }
 
+/// Runs the test against every connector that is not ignored. Prefer `test_each_connector` and
+/// branching on the `SqlFamily` passed to the closure where only the assertions differ.
 pub fn test_each_connector_with_ignores<F>(ignores: Vec<SqlFamily>, test_fn: F)
 where
     F: Fn(SqlFamily, &dyn GenericApi) -> () + std::panic::RefUnwindSafe,
@@ -101,10 +107,30 @@ pub fn introspect_database(api: &dyn GenericApi) -> DatabaseSchema {
 
     // the presence of the _Migration table makes assertions harder. Therefore remove it from the result.
     result.tables = result.tables.into_iter().filter(|t| t.name != "_Migration").collect();
+    result.annotate_enum_columns(&api.migration_persistence().current_datamodel());
 
     result
 }
 
+/// The type of the column as it is stored in the database, e.g. `text` or `varchar`.
+pub fn native_column_type(sql_family: SqlFamily, table: &str, column: &str) -> String {
+    let sql = match sql_family {
+        SqlFamily::Sqlite => format!(r#"Pragma "{}".table_info ("{}")"#, SCHEMA_NAME, table),
+        _ => format!(
+            "SELECT column_name AS name, data_type AS type FROM information_schema.columns WHERE table_schema = '{}' AND table_name = '{}'",
+            SCHEMA_NAME, table
+        ),
+    };
+    let result_set = database(sql_family).query_raw(SCHEMA_NAME, &sql, &[]).unwrap();
+
+    result_set
+        .into_iter()
+        .map(|row| (row["name"].to_string().unwrap(), row["type"].to_string().unwrap()))
+        .find(|(name, _)| name == column)
+        .map(|(_, tpe)| tpe.to_lowercase())
+        .expect(&format!("Column {} not found in table {}", column, table))
+}
+
 pub fn database(sql_family: SqlFamily) -> Box<dyn MigrationDatabase> {
     match sql_family {
         SqlFamily::Postgres => {
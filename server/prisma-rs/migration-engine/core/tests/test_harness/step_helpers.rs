@@ -14,6 +14,9 @@ pub fn create_field_step(model: &str, field: &str, scalar_type: ScalarType) -> M
         id: None,
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })
 }
 
@@ -40,6 +43,9 @@ pub fn create_id_field_step(model: &str, field: &str, scalar_type: ScalarType) -
         }),
         default: None,
         scalar_list: None,
+        native_type: None,
+        check: None,
+        documentation: None,
     })
 }
 
@@ -48,5 +54,9 @@ pub fn create_model_step(model: &str) -> MigrationStep {
         name: model.to_string(),
         db_name: None,
         embedded: false,
+        indices: vec![],
+        compound_id_fields: vec![],
+        schema: None,
+        documentation: None,
     })
 }
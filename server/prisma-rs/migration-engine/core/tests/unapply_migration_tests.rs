@@ -32,3 +32,29 @@ fn unapply_must_work() {
         assert_eq!(result2, result4);
     });
 }
+
+#[test]
+fn unapply_must_restore_changed_types_and_added_fields() {
+    test_each_connector(|_, api| {
+        let dm1 = r#"
+            model Test {
+                id String @id @default(cuid())
+                field String
+            }
+        "#;
+        let result1 = infer_and_apply(api, &dm1);
+
+        let dm2 = r#"
+            model Test {
+                id String @id @default(cuid())
+                field Int
+                other String @unique
+            }
+        "#;
+        let result2 = infer_and_apply(api, &dm2);
+        assert_ne!(result1, result2);
+
+        let result3 = unapply_migration(api);
+        assert_eq!(result1, result3);
+    });
+}
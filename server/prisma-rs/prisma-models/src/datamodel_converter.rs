@@ -1,6 +1,11 @@
 use crate::*;
-use datamodel::dml;
+use datamodel::{
+    ast::Span,
+    dml,
+    errors::{ErrorCollection, ValidationError},
+};
 use itertools::Itertools;
+use std::collections::BTreeMap;
 
 pub struct DatamodelConverter<'a> {
     datamodel: &'a dml::Datamodel,
@@ -11,27 +16,35 @@ pub struct DatamodelConverter<'a> {
 impl<'a> DatamodelConverter<'a> {
     pub fn convert_string(datamodel: String) -> InternalDataModelTemplate {
         let datamodel = datamodel::parse(&datamodel).unwrap();
-        Self::convert(&datamodel)
+        Self::convert(&datamodel).unwrap()
     }
 
-    pub fn convert(datamodel: &dml::Datamodel) -> InternalDataModelTemplate {
-        DatamodelConverter::new(datamodel).convert_internal()
+    /// Default values which cannot be coerced to the type of their field are reported as validation errors.
+    pub fn convert(datamodel: &dml::Datamodel) -> Result<InternalDataModelTemplate, ErrorCollection> {
+        Self::convert_with_relation_table_naming(datamodel, RelationTableNaming::default())
     }
 
-    fn new(datamodel: &dml::Datamodel) -> DatamodelConverter {
+    pub fn convert_with_relation_table_naming(
+        datamodel: &dml::Datamodel,
+        relation_table_naming: RelationTableNaming,
+    ) -> Result<InternalDataModelTemplate, ErrorCollection> {
+        DatamodelConverter::new(datamodel, relation_table_naming).convert_internal()
+    }
+
+    fn new(datamodel: &dml::Datamodel, relation_table_naming: RelationTableNaming) -> DatamodelConverter {
         DatamodelConverter {
             datamodel,
-            relations: Self::calculate_relations(datamodel),
+            relations: Self::calculate_relations(datamodel, relation_table_naming),
         }
     }
 
-    fn convert_internal(&self) -> InternalDataModelTemplate {
-        InternalDataModelTemplate {
-            models: self.convert_models(),
+    fn convert_internal(&self) -> Result<InternalDataModelTemplate, ErrorCollection> {
+        Ok(InternalDataModelTemplate {
+            models: self.convert_models()?,
             relations: self.convert_relations(),
             enums: self.convert_enums(),
             version: Some("v2".to_string()),
-        }
+        })
     }
 
     fn convert_enums(&self) -> Vec<InternalEnum> {
@@ -40,26 +53,58 @@ impl<'a> DatamodelConverter<'a> {
             .map(|e| InternalEnum {
                 name: e.name.clone(),
                 values: e.values.clone(),
+                // The datamodel cannot map enum values to other database values yet.
+                db_values: BTreeMap::new(),
             })
             .collect()
     }
 
-    fn convert_models(&self) -> Vec<ModelTemplate> {
-        self.datamodel
+    fn convert_models(&self) -> Result<Vec<ModelTemplate>, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let models = self
+            .datamodel
             .models()
-            .map(|model| ModelTemplate {
+            .filter_map(|model| match self.convert_fields(model) {
+                Ok(fields) => Some((model, fields)),
+                Err(err) => {
+                    errors.push(err);
+                    None
+                }
+            })
+            .map(|(model, fields)| ModelTemplate {
                 name: model.name.clone(),
                 stable_identifier: "".to_string(),
                 is_embedded: model.is_embedded,
-                fields: self.convert_fields(model),
+                fields,
                 manifestation: model.database_name.clone().map(|n| ModelManifestation { db_name: n }),
+                documentation: model.documentation.clone(),
+                compound_id_field_names: model.compound_id_fields.clone(),
+                unique_indexes: model
+                    .indices
+                    .iter()
+                    .filter(|index| index.tpe == dml::IndexType::Unique)
+                    .map(|index| UniqueIndex {
+                        name: index.name.clone(),
+                        field_names: index.fields.clone(),
+                    })
+                    .collect(),
             })
-            .collect()
+            .collect();
+
+        errors.ok()?;
+
+        Ok(models)
     }
 
-    fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
+    fn convert_fields(&self, model: &dml::Model) -> Result<Vec<FieldTemplate>, ValidationError> {
         model
             .fields()
+            // Columns of unsupported types only exist in the database, they cannot be queried.
+            .filter(|field| match field.field_type {
+                dml::FieldType::Unsupported(_) => false,
+                _ => true,
+            })
             .map(|field| match field.type_identifier() {
                 TypeIdentifier::Relation => {
                     let relation = self
@@ -71,7 +116,7 @@ impl<'a> DatamodelConverter<'a> {
                             model.name, field.name
                         ));
 
-                    FieldTemplate::Relation(RelationFieldTemplate {
+                    Ok(FieldTemplate::Relation(RelationFieldTemplate {
                         name: field.name.clone(),
                         type_identifier: field.type_identifier(),
                         is_required: field.is_required(),
@@ -82,9 +127,9 @@ impl<'a> DatamodelConverter<'a> {
                         manifestation: field.manifestation(),
                         relation_name: relation.name(),
                         relation_side: relation.relation_side(field),
-                    })
+                    }))
                 }
-                ti => FieldTemplate::Scalar(ScalarFieldTemplate {
+                ti => Ok(FieldTemplate::Scalar(ScalarFieldTemplate {
                     name: field.name.clone(),
                     type_identifier: field.type_identifier(),
                     is_required: field.is_required(),
@@ -94,9 +139,10 @@ impl<'a> DatamodelConverter<'a> {
                     is_auto_generated: field.is_auto_generated(),
                     manifestation: field.manifestation(),
                     behaviour: field.behaviour(),
-                    default_value: field.default_value(),
+                    default_value: field.default_value()?,
+                    documentation: field.documentation.clone(),
                     internal_enum: field.internal_enum(self.datamodel),
-                }),
+                })),
             })
             .collect()
     }
@@ -106,16 +152,19 @@ impl<'a> DatamodelConverter<'a> {
             .iter()
             .map(|r| RelationTemplate {
                 name: r.name(),
-                model_a_on_delete: OnDelete::SetNull,
-                model_b_on_delete: OnDelete::SetNull,
-                manifestation: Some(r.manifestation()),
+                model_a_on_delete: r.on_delete(RelationSide::A),
+                model_b_on_delete: r.on_delete(RelationSide::B),
+                manifestation: r.manifestation(),
                 model_a_name: r.model_a.name.clone(),
                 model_b_name: r.model_b.name.clone(),
             })
             .collect()
     }
 
-    pub fn calculate_relations(datamodel: &dml::Datamodel) -> Vec<TempRelationHolder> {
+    pub fn calculate_relations(
+        datamodel: &dml::Datamodel,
+        relation_table_naming: RelationTableNaming,
+    ) -> Vec<TempRelationHolder> {
         let mut result = Vec::new();
         for model in datamodel.models() {
             for field in model.fields() {
@@ -182,22 +231,28 @@ impl<'a> DatamodelConverter<'a> {
                     };
                     let inline_on_model_a = TempManifestationHolder::Inline {
                         in_table_of_model: model_a.name.clone(),
-                        column: field_a.final_db_name(),
+                        column: field_a.referencing_column(&model_a),
+                        composite_columns: field_a.composite_foreign_key_columns(&model_a, &model_b),
                     };
                     let inline_on_model_b = TempManifestationHolder::Inline {
                         in_table_of_model: model_b.name.clone(),
-                        column: field_b.final_db_name(),
+                        column: field_b.referencing_column(&model_b),
+                        composite_columns: field_b.composite_foreign_key_columns(&model_b, &model_a),
                     };
                     let inline_on_this_model = TempManifestationHolder::Inline {
                         in_table_of_model: model.name.clone(),
-                        column: field.final_db_name(),
+                        column: field.referencing_column(&model),
+                        composite_columns: field.composite_foreign_key_columns(&model, &related_model),
                     };
                     let inline_on_related_model = TempManifestationHolder::Inline {
                         in_table_of_model: related_model.name.clone(),
-                        column: related_field.final_db_name(),
+                        column: related_field.referencing_column(&related_model),
+                        composite_columns: related_field.composite_foreign_key_columns(&related_model, &model),
                     };
 
                     let manifestation = match (field_a.is_list(), field_b.is_list()) {
+                        // The embedded side has no back relation field of its own, only the generated one.
+                        _ if model.is_embedded || related_model.is_embedded => TempManifestationHolder::Embedded,
                         (true, true) => TempManifestationHolder::Table,
                         (false, true) => inline_on_model_a,
                         (true, false) => inline_on_model_b,
@@ -225,6 +280,8 @@ impl<'a> DatamodelConverter<'a> {
                         field_a,
                         field_b,
                         manifestation,
+                        declared_on: model.name.clone(),
+                        table_naming: relation_table_naming,
                     })
                 }
             }
@@ -233,6 +290,26 @@ impl<'a> DatamodelConverter<'a> {
     }
 }
 
+/// Determines the names of the tables backing many-to-many relations. The
+/// default yields names like `_BlogToPost`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelationTableNaming {
+    /// Prefix the table name with an underscore.
+    pub underscore_prefix: bool,
+    /// Sort the model names of unnamed relations alphabetically. Otherwise the
+    /// model declaring the relation first comes first, as in `_PostToBlog`.
+    pub alphabetical: bool,
+}
+
+impl Default for RelationTableNaming {
+    fn default() -> Self {
+        RelationTableNaming {
+            underscore_prefix: true,
+            alphabetical: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TempRelationHolder {
     pub name: String,
@@ -241,12 +318,23 @@ pub struct TempRelationHolder {
     pub field_a: dml::Field,
     pub field_b: dml::Field,
     pub manifestation: TempManifestationHolder,
+    /// The name of the model on which the relation was encountered first.
+    pub declared_on: String,
+    pub table_naming: RelationTableNaming,
 }
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum TempManifestationHolder {
-    Inline { in_table_of_model: String, column: String },
+    Inline {
+        in_table_of_model: String,
+        column: String,
+        /// The columns of a composite foreign key, each paired with the column of the related model it references.
+        /// Empty if the foreign key consists of a single column.
+        composite_columns: Vec<(String, String)>,
+    },
     Table,
+    /// One of the models is embedded into the other, so neither a column nor a table links them.
+    Embedded,
 }
 
 #[allow(unused)]
@@ -260,7 +348,24 @@ impl TempRelationHolder {
     }
 
     pub fn table_name(&self) -> String {
-        format!("_{}", self.name())
+        let name = match &self.name as &str {
+            "" if !self.table_naming.alphabetical => {
+                let other_model = if self.declared_on == self.model_a.name {
+                    &self.model_b.name
+                } else {
+                    &self.model_a.name
+                };
+
+                format!("{}To{}", &self.declared_on, other_model)
+            }
+            _ => self.name(),
+        };
+
+        if self.table_naming.underscore_prefix {
+            format!("_{}", name)
+        } else {
+            name
+        }
     }
 
     pub fn model_a_column(&self) -> String {
@@ -289,23 +394,71 @@ impl TempRelationHolder {
         }
     }
 
-    fn manifestation(&self) -> RelationLinkManifestation {
+    /// What happens to the related records when a record on the given side of this relation is deleted.
+    /// Deletes cascade if the foreign key on the other side does, see `dml::Field::on_delete_strategy`.
+    fn on_delete(&self, side: RelationSide) -> OnDelete {
+        let referencing_field = match &self.manifestation {
+            TempManifestationHolder::Inline {
+                in_table_of_model,
+                column,
+                ..
+            } => {
+                // Both models are the same in a self relation, so the field holding the foreign key is told apart by its column.
+                let field_a_holds_foreign_key = in_table_of_model == &self.model_a.name
+                    && column == &self.field_a.referencing_column(&self.model_a);
+
+                match side {
+                    RelationSide::A if !field_a_holds_foreign_key => Some(&self.field_b),
+                    RelationSide::B if field_a_holds_foreign_key => Some(&self.field_a),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match referencing_field.and_then(|f| f.on_delete_strategy()) {
+            Some(dml::OnDeleteStrategy::Cascade) => OnDelete::Cascade,
+            _ => OnDelete::SetNull,
+        }
+    }
+
+    fn manifestation(&self) -> Option<RelationLinkManifestation> {
         match &self.manifestation {
             // TODO: relation table columns must get renamed: lowercased type names instead of A and B
-            TempManifestationHolder::Table => RelationLinkManifestation::RelationTable(RelationTable {
+            TempManifestationHolder::Table => Some(RelationLinkManifestation::RelationTable(RelationTable {
                 table: self.table_name(),
                 model_a_column: self.model_a_column(),
                 model_b_column: self.model_b_column(),
                 id_column: None,
-            }),
+            })),
             TempManifestationHolder::Inline {
                 in_table_of_model,
                 column,
-            } => RelationLinkManifestation::Inline(InlineRelation {
+                ..
+            } => Some(RelationLinkManifestation::Inline(InlineRelation {
                 in_table_of_model_name: in_table_of_model.to_string(),
                 referencing_column: column.to_string(),
-            }),
+            })),
+            TempManifestationHolder::Embedded => None,
+        }
+    }
+}
+
+/// The type identifier of a `String` field, which depends on the way its values are generated.
+///
+/// The query engine generates ids based on the type identifier (see `Model::generate_id`), so the two
+/// generated string ids must stay distinct:
+///
+/// - `cuid()` and `auto()` map to `GraphQLID`: a cuid is generated and stored as a plain string.
+/// - `uuid()` maps to `UUID`: a v4 uuid is generated and read back as a `GraphqlId::UUID`.
+/// - Any other string field, including a `String @id` without generated default, maps to `String`.
+fn string_type_identifier(default_value: Option<&datamodel::common::PrismaValue>) -> TypeIdentifier {
+    match default_value {
+        Some(datamodel::common::PrismaValue::Expression(expr, _, _)) if expr == "cuid" || expr == "auto" => {
+            TypeIdentifier::GraphQLID
         }
+        Some(datamodel::common::PrismaValue::Expression(expr, _, _)) if expr == "uuid" => TypeIdentifier::UUID,
+        _ => TypeIdentifier::String,
     }
 }
 
@@ -318,13 +471,14 @@ trait DatamodelFieldExtensions {
     fn manifestation(&self) -> Option<FieldManifestation>;
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn final_db_name(&self) -> String;
+    fn referencing_column(&self, model: &dml::Model) -> String;
+    fn composite_foreign_key_columns(&self, model: &dml::Model, related_model: &dml::Model) -> Vec<(String, String)>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
-    fn default_value(&self) -> Option<PrismaValue>;
+    fn default_value(&self) -> Result<Option<PrismaValue>, ValidationError>;
 }
 
 impl DatamodelFieldExtensions for dml::Field {
     fn type_identifier(&self) -> TypeIdentifier {
-        // todo: add support for CUID and UUID
         match self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
@@ -334,17 +488,12 @@ impl DatamodelFieldExtensions for dml::Field {
                 dml::ScalarType::Decimal => TypeIdentifier::Float,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => match self.default_value {
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "cuid" => {
-                        TypeIdentifier::GraphQLID
-                    }
-                    Some(datamodel::common::PrismaValue::Expression(ref expr, _, _)) if expr == "uuid" => {
-                        TypeIdentifier::UUID
-                    }
-                    _ => TypeIdentifier::String,
-                },
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+                dml::ScalarType::Xml => TypeIdentifier::Xml,
+                dml::ScalarType::String => string_type_identifier(self.default_value.as_ref()),
             },
             dml::FieldType::ConnectorSpecific { .. } => unimplemented!("Connector Specific types are not supported here yet"),
+            dml::FieldType::Unsupported(_) => unreachable!("Fields of unsupported types are not converted"),
         }
     }
 
@@ -361,9 +510,12 @@ impl DatamodelFieldExtensions for dml::Field {
         let has_auto_generating_behaviour = self
             .id_info
             .as_ref()
-            .filter(|id| id.strategy == dml::IdStrategy::Auto)
+            .filter(|id| id.strategy != dml::IdStrategy::None)
             .is_some();
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = match self.type_identifier() {
+            TypeIdentifier::Int | TypeIdentifier::BigInt => true,
+            _ => false,
+        };
         has_auto_generating_behaviour && is_an_int
     }
 
@@ -379,11 +531,15 @@ impl DatamodelFieldExtensions for dml::Field {
                 let strategy = match id_info.strategy {
                     dml::IdStrategy::Auto => IdStrategy::Auto,
                     dml::IdStrategy::None => IdStrategy::None,
+                    dml::IdStrategy::Sequence => IdStrategy::Sequence,
+                    dml::IdStrategy::External => IdStrategy::External,
                 };
-                FieldBehaviour::Id {
-                    strategy,
-                    sequence: None, // the sequence was just used by the migration engine. Now those models are only used by the query engine. Hence we don't need it anyway.
-                }
+                let sequence = id_info.sequence.as_ref().map(|sequence| Sequence {
+                    name: sequence.name.clone(),
+                    initial_value: sequence.initial_value,
+                    allocation_size: sequence.allocation_size,
+                });
+                FieldBehaviour::Id { strategy, sequence }
             })
             // case: @default(now())
             .or_else(|| match self.default_value {
@@ -415,6 +571,48 @@ impl DatamodelFieldExtensions for dml::Field {
         self.database_name.clone().unwrap_or_else(|| self.name.clone())
     }
 
+    fn referencing_column(&self, model: &dml::Model) -> String {
+        // TODO: fields is a list, compound foreign keys are not supported yet.
+        let base_field = match &self.field_type {
+            dml::FieldType::Relation(relation_info) => relation_info
+                .fields
+                .first()
+                .and_then(|base_field| model.find_field(base_field)),
+            _ => None,
+        };
+
+        match base_field {
+            Some(base_field) => base_field.final_db_name(),
+            None => self.final_db_name(),
+        }
+    }
+
+    fn composite_foreign_key_columns(&self, model: &dml::Model, related_model: &dml::Model) -> Vec<(String, String)> {
+        let relation_info = match &self.field_type {
+            dml::FieldType::Relation(relation_info) if relation_info.fields.len() > 1 => relation_info,
+            _ => return Vec::new(),
+        };
+
+        let db_name_of = |model: &dml::Model, field_name: &String| {
+            model
+                .find_field(field_name)
+                .map(|f| f.final_db_name())
+                .unwrap_or_else(|| field_name.clone())
+        };
+
+        relation_info
+            .fields
+            .iter()
+            .zip(relation_info.to_fields.iter())
+            .map(|(base_field, referenced_field)| {
+                (
+                    db_name_of(model, base_field),
+                    db_name_of(related_model, referenced_field),
+                )
+            })
+            .collect()
+    }
+
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
         match self.field_type {
             dml::FieldType::Enum(ref name) => {
@@ -424,24 +622,49 @@ impl DatamodelFieldExtensions for dml::Field {
                     .map(|e| InternalEnum {
                         name: e.name.clone(),
                         values: e.values.clone(),
+                        db_values: BTreeMap::new(),
                     })
             }
             _ => None,
         }
     }
 
-    fn default_value(&self) -> Option<PrismaValue> {
-        self.default_value.as_ref().and_then(|v| match v {
-            datamodel::common::PrismaValue::Boolean(x) => Some(PrismaValue::Boolean(*x)),
-            datamodel::common::PrismaValue::Int(x) => Some(PrismaValue::Int(i64::from(*x))),
-            datamodel::common::PrismaValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
-            datamodel::common::PrismaValue::String(x) => Some(PrismaValue::String(x.clone())),
-            datamodel::common::PrismaValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
-            datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
-            datamodel::common::PrismaValue::ConstantLiteral(x) => {
-                Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
-            }
-            datamodel::common::PrismaValue::Expression(_, _, _) => None, // expressions are handled in the behaviour function right now
-        })
+    fn default_value(&self) -> Result<Option<PrismaValue>, ValidationError> {
+        let type_identifier = self.type_identifier();
+
+        self.default_value
+            .as_ref()
+            .and_then(convert_default_value)
+            .map(|value| value.coerce(type_identifier))
+            .transpose()
+            .map_err(|err| {
+                ValidationError::new_directive_validation_error(
+                    &format!("Invalid default value of field `{}`: {}", self.name, err),
+                    "default",
+                    Span::empty(),
+                )
+            })
+    }
+}
+
+fn convert_default_value(value: &datamodel::common::PrismaValue) -> Option<PrismaValue> {
+    match value {
+        datamodel::common::PrismaValue::Boolean(x) => Some(PrismaValue::Boolean(*x)),
+        datamodel::common::PrismaValue::Int(x) => Some(PrismaValue::Int(i64::from(*x))),
+        datamodel::common::PrismaValue::BigInt(x) => Some(PrismaValue::Int(*x)),
+        datamodel::common::PrismaValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
+        datamodel::common::PrismaValue::String(x) => Some(PrismaValue::String(x.clone())),
+        datamodel::common::PrismaValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
+        datamodel::common::PrismaValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
+        datamodel::common::PrismaValue::ConstantLiteral(x) => {
+            Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
+        }
+        datamodel::common::PrismaValue::Expression(_, _, _) => None, // expressions are handled in the behaviour function right now
+        datamodel::common::PrismaValue::List(values) => Some(PrismaValue::List(
+            values
+                .iter()
+                .map(convert_default_value)
+                .collect::<Option<Vec<PrismaValue>>>(),
+        )),
     }
 }
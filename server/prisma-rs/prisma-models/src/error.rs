@@ -1,3 +1,4 @@
+use crate::TypeIdentifier;
 use failure::Fail;
 
 #[derive(Debug, Fail)]
@@ -25,4 +26,21 @@ pub enum DomainError {
 
     #[fail(display = "Conversion from `{}` to `{}` failed.", _0, _1)]
     ConversionFailure(&'static str, &'static str),
+
+    #[fail(
+        display = "The value `{}` cannot be coerced to the type `{:?}`.",
+        value, type_identifier
+    )]
+    CoercionFailure {
+        value: String,
+        type_identifier: TypeIdentifier,
+    },
+}
+
+/// Returned when models cannot be ordered, because their inline relations form a cycle.
+#[derive(Debug, Fail, PartialEq)]
+#[fail(display = "The inline relations between the models {:?} form a cycle.", models)]
+pub struct CycleError {
+    /// The names of the models that are part of or depend on the cycle.
+    pub models: Vec<String>,
 }
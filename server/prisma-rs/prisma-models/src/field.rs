@@ -16,12 +16,20 @@ pub enum FieldTemplate {
     Scalar(ScalarFieldTemplate),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Field {
     Relation(RelationFieldRef),
     Scalar(ScalarFieldRef),
 }
 
+/// Whether a field holds exactly one, at most one or any number of values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldArity {
+    Required,
+    Optional,
+    List,
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FieldManifestation {
@@ -39,7 +47,9 @@ pub enum TypeIdentifier {
     GraphQLID,
     UUID,
     Int,
+    BigInt,
     Relation,
+    Xml,
 }
 
 impl TypeIdentifier {
@@ -101,6 +111,19 @@ impl Field {
         }
     }
 
+    pub fn arity(&self) -> FieldArity {
+        let (is_list, is_required) = match self {
+            Field::Scalar(ref sf) => (sf.is_list, sf.is_required),
+            Field::Relation(ref rf) => (rf.is_list, rf.is_required),
+        };
+
+        match (is_list, is_required) {
+            (true, _) => FieldArity::List,
+            (false, true) => FieldArity::Required,
+            (false, false) => FieldArity::Optional,
+        }
+    }
+
     pub fn type_identifier(&self) -> TypeIdentifier {
         match self {
             Field::Scalar(ref sf) => sf.type_identifier,
@@ -126,6 +149,7 @@ impl FieldTemplate {
                     behaviour: st.behaviour,
                     model,
                     default_value: st.default_value,
+                    documentation: st.documentation,
                 };
 
                 Field::Scalar(Arc::new(scalar))
@@ -90,6 +90,12 @@ impl RelationField {
     }
 
     pub fn db_name(&self) -> String {
+        self.final_database_name()
+    }
+
+    /// The name of the foreign key column of this field if the relation is inlined on its side,
+    /// taking `@map` into account, and the field name otherwise.
+    pub fn final_database_name(&self) -> String {
         let relation = self.relation();
 
         match relation.manifestation {
@@ -26,6 +26,8 @@ pub struct ScalarFieldTemplate {
     pub manifestation: Option<FieldManifestation>,
     pub behaviour: Option<FieldBehaviour>,
     pub default_value: Option<PrismaValue>,
+    #[serde(default)]
+    pub documentation: Option<String>,
 
     #[serde(rename = "enum")]
     pub internal_enum: Option<InternalEnum>,
@@ -45,6 +47,7 @@ pub struct ScalarField {
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
     pub default_value: Option<PrismaValue>,
+    pub documentation: Option<String>,
 
     pub(crate) is_unique: bool,
 }
@@ -68,6 +71,8 @@ pub enum IdStrategy {
     Auto,
     None,
     Sequence,
+    /// Generated by the datastore (`@default(auto())`). Behaves like `Auto` on SQL connectors.
+    External,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
@@ -135,6 +140,11 @@ impl ScalarField {
     }
 
     pub fn db_name(&self) -> &str {
+        self.final_database_name()
+    }
+
+    /// The name of the column backing this field: the name given with `@map`, or the field name if it is not mapped.
+    pub fn final_database_name(&self) -> &str {
         self.db_name_opt().unwrap_or_else(|| self.name.as_ref())
     }
 
@@ -29,6 +29,11 @@ impl Fields {
         }
     }
 
+    /// Iterates over all fields of the model, scalar and relation fields alike, in the order of their declaration.
+    pub fn iter(&self) -> std::slice::Iter<Field> {
+        self.all.iter()
+    }
+
     pub fn id(&self) -> Arc<ScalarField> {
         self.id
             .get_or_init(|| {
@@ -188,3 +193,12 @@ impl Fields {
         acc
     }
 }
+
+impl<'a> IntoIterator for &'a Fields {
+    type Item = &'a Field;
+    type IntoIter = std::slice::Iter<'a, Field>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.all.iter()
+    }
+}
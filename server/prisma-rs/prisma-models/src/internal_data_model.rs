@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use once_cell::sync::OnceCell;
+use std::collections::BTreeMap;
 use std::sync::{Arc, Weak};
 
 pub type InternalDataModelRef = Arc<InternalDataModel>;
@@ -36,6 +37,25 @@ pub struct InternalDataModel {
 pub struct InternalEnum {
     pub name: String,
     pub values: Vec<String>,
+    /// The database values of the enum values that are mapped to a different name, keyed by the name of the value.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub db_values: BTreeMap<String, String>,
+}
+
+impl InternalEnum {
+    /// The database value of the enum value with the given name. Unmapped values are stored under their name.
+    pub fn db_value_for<'a>(&'a self, name: &'a str) -> &'a str {
+        self.db_values.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    /// The name of the enum value stored as the given database value. Unmapped values are stored under their name.
+    pub fn name_for_db_value<'a>(&'a self, value: &'a str) -> &'a str {
+        self.db_values
+            .iter()
+            .find(|(_, db_value)| db_value.as_str() == value)
+            .map(|(name, _)| name.as_str())
+            .unwrap_or(value)
+    }
 }
 
 impl InternalDataModelTemplate {
@@ -93,6 +113,24 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::RelationNotFound { name: name.to_string() })
     }
 
+    /// Finds a scalar or relation field of the given model by its name.
+    pub fn find_field(&self, model: &str, field: &str) -> Option<Field> {
+        self.find_model(model)
+            .ok()
+            .and_then(|model| model.fields().find_from_all(field).ok().cloned())
+    }
+
+    /// Finds the relation field of the given model that belongs to the relation with the given name.
+    /// Both fields of a self relation live on the same model, in that case the first one is returned.
+    pub fn find_relation_field(&self, model: &str, relation_name: &str) -> Option<RelationFieldRef> {
+        self.find_model(model)
+            .ok()?
+            .fields()
+            .relation()
+            .into_iter()
+            .find(|field| field.relation_name == relation_name)
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.version.is_none()
     }
@@ -106,6 +144,76 @@ impl InternalDataModel {
             .collect()
     }
 
+    /// Orders the models so that each model comes after the models its inline relations point to,
+    /// e.g. for creating tables or inserting records before the records referencing them.
+    /// Self relations are ignored. Models without dependencies between them keep their declaration order.
+    pub fn models_topologically_sorted(&self) -> Result<Vec<ModelRef>, CycleError> {
+        let dependencies: Vec<(String, String)> = self
+            .relations()
+            .iter()
+            .filter_map(|relation| {
+                let inline = relation.inline_manifestation()?;
+                let referenced_model = if inline.in_table_of_model_name == relation.model_a().name {
+                    relation.model_b()
+                } else {
+                    relation.model_a()
+                };
+
+                if referenced_model.name == inline.in_table_of_model_name {
+                    None
+                } else {
+                    Some((inline.in_table_of_model_name.clone(), referenced_model.name.clone()))
+                }
+            })
+            .collect();
+
+        let mut remaining: Vec<ModelRef> = self.models().to_vec();
+        let mut sorted: Vec<ModelRef> = Vec::with_capacity(remaining.len());
+
+        while !remaining.is_empty() {
+            let next_position = remaining.iter().position(|model| {
+                dependencies
+                    .iter()
+                    .filter(|(dependent, _)| dependent == &model.name)
+                    .all(|(_, referenced)| sorted.iter().any(|m| &m.name == referenced))
+            });
+
+            match next_position {
+                Some(position) => sorted.push(remaining.remove(position)),
+                None => {
+                    return Err(CycleError {
+                        models: remaining.iter().map(|m| m.name.clone()).collect(),
+                    })
+                }
+            }
+        }
+
+        Ok(sorted)
+    }
+
+    /// The names of all tables the datamodel is stored in: the model tables, the side tables of
+    /// scalar lists and the join tables of relations that are not inlined, in that order.
+    pub fn table_names(&self) -> Vec<String> {
+        let model_tables = self.models().iter().map(|model| model.db_name().to_string());
+
+        let scalar_list_tables = self
+            .models()
+            .iter()
+            .flat_map(|model| model.scalar_list_tables())
+            .map(|descriptor| descriptor.table_name);
+
+        let relation_tables = self
+            .relations()
+            .iter()
+            .filter_map(|relation| match relation.manifestation {
+                Some(RelationLinkManifestation::RelationTable(ref m)) => Some(m.table.clone()),
+                // Relations without a manifestation are embedded and have no table.
+                Some(RelationLinkManifestation::Inline(_)) | None => None,
+            });
+
+        model_tables.chain(scalar_list_tables).chain(relation_tables).collect()
+    }
+
     pub fn relation_fields(&self) -> &[RelationFieldRef] {
         self.relation_fields
             .get_or_init(|| {
@@ -15,6 +15,13 @@ pub struct ModelTemplate {
     pub is_embedded: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<ModelManifestation>, // todo: convert to Option<String> once we fully switched to dm v2
+    #[serde(default)]
+    pub documentation: Option<String>,
+    /// The fields of a compound id declared via `@@id`. Empty if the model has a single `@id` field, or no id.
+    #[serde(default)]
+    pub compound_id_field_names: Vec<String>,
+    #[serde(default)]
+    pub unique_indexes: Vec<UniqueIndex>,
 }
 
 #[derive(DebugStub)]
@@ -23,6 +30,9 @@ pub struct Model {
     pub stable_identifier: String,
     pub is_embedded: bool,
     pub manifestation: Option<ModelManifestation>,
+    pub documentation: Option<String>,
+    pub compound_id_field_names: Vec<String>,
+    pub unique_indexes: Vec<UniqueIndex>,
 
     fields: OnceCell<Fields>,
 
@@ -36,6 +46,14 @@ pub struct ModelManifestation {
     pub db_name: String,
 }
 
+/// A unique index over several fields of a model, declared via `@@unique`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniqueIndex {
+    pub name: Option<String>,
+    pub field_names: Vec<String>,
+}
+
 impl ModelTemplate {
     pub fn build(self, internal_data_model: InternalDataModelWeakRef) -> ModelRef {
         let model = Arc::new(Model {
@@ -44,6 +62,9 @@ impl ModelTemplate {
             is_embedded: self.is_embedded,
             fields: OnceCell::new(),
             manifestation: self.manifestation,
+            documentation: self.documentation,
+            compound_id_field_names: self.compound_id_field_names,
+            unique_indexes: self.unique_indexes,
             internal_data_model,
         });
 
@@ -80,7 +101,7 @@ impl Model {
             // Panic is a better choice than bubbling this up
             TypeIdentifier::GraphQLID => GraphqlId::String(cuid::cuid().unwrap()),
             TypeIdentifier::UUID => GraphqlId::UUID(Uuid::new_v4()),
-            TypeIdentifier::Int => panic!("Cannot generate integer ids."),
+            TypeIdentifier::Int | TypeIdentifier::BigInt => panic!("Cannot generate integer ids."),
             t => panic!("You shouldn't even use ids of type {:?}", t),
         }
     }
@@ -117,4 +138,52 @@ impl Model {
     pub fn id_column(&self) -> Column<'static> {
         self.fields().id().as_column()
     }
+
+    /// The scalar fields identifying a record of this model: the `@id` field or the fields of the `@@id`.
+    /// Models without an id, like join tables, fall back to the fields of their first unique index,
+    /// preferring `@@unique` indexes over `@unique` fields. Empty if the model has neither. Relation fields
+    /// cannot identify records yet, so an `@@id` or `@@unique` naming one is an error.
+    pub fn primary_identifier(&self) -> DomainResult<Vec<Arc<ScalarField>>> {
+        let fields = self.fields();
+
+        if !self.compound_id_field_names.is_empty() {
+            return self.scalar_fields_by_name(&self.compound_id_field_names);
+        }
+
+        if let Some(id_field) = fields.scalar().into_iter().find(|sf| sf.is_id()) {
+            return Ok(vec![id_field]);
+        }
+
+        if let Some(index) = self.unique_indexes.first() {
+            return self.scalar_fields_by_name(&index.field_names);
+        }
+
+        Ok(fields
+            .scalar()
+            .into_iter()
+            .find(|sf| sf.is_unique())
+            .into_iter()
+            .collect())
+    }
+
+    /// The scalar fields with the given names, in the order of the names.
+    fn scalar_fields_by_name(&self, names: &[String]) -> DomainResult<Vec<Arc<ScalarField>>> {
+        names.iter().map(|name| self.fields().find_from_scalar(name)).collect()
+    }
+
+    /// The side tables of the scalar list fields of this model, in the order of the fields. Lists with the embedded
+    /// strategy are stored in an array column instead and have no side table.
+    pub fn scalar_list_tables(&self) -> Vec<ScalarListTableDescriptor> {
+        self.fields()
+            .scalar_list()
+            .iter()
+            .filter(|field| {
+                field.scalar_list_behaviour_clone()
+                    != Some(FieldBehaviour::ScalarList {
+                        strategy: ScalarListStrategy::Embedded,
+                    })
+            })
+            .map(|field| field.scalar_list_table().descriptor())
+            .collect()
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{DomainError, DomainResult, EnumValue};
+use crate::{DomainError, DomainResult, EnumValue, TypeIdentifier};
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -72,6 +72,69 @@ impl PrismaValue {
             _ => false,
         }
     }
+
+    /// Converts the value to the given type, e.g. a default value given as string to the type of its field.
+    /// Strings are parsed, ints widen to floats, values of lists are coerced one by one and null stays null.
+    pub fn coerce(self, type_identifier: TypeIdentifier) -> DomainResult<PrismaValue> {
+        let failure = |value: &dyn fmt::Display| DomainError::CoercionFailure {
+            value: value.to_string(),
+            type_identifier,
+        };
+
+        match (self, type_identifier) {
+            (PrismaValue::Null, _) => Ok(PrismaValue::Null),
+            (PrismaValue::List(values), _) => values
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|v| v.coerce(type_identifier))
+                        .collect::<DomainResult<Vec<PrismaValue>>>()
+                })
+                .transpose()
+                .map(PrismaValue::List),
+
+            (PrismaValue::String(s), TypeIdentifier::String)
+            | (PrismaValue::String(s), TypeIdentifier::Xml)
+            | (PrismaValue::String(s), TypeIdentifier::GraphQLID) => Ok(PrismaValue::String(s)),
+            (PrismaValue::String(s), TypeIdentifier::Int) | (PrismaValue::String(s), TypeIdentifier::BigInt) => {
+                s.trim().parse().map(PrismaValue::Int).map_err(|_| failure(&s))
+            }
+            (PrismaValue::String(s), TypeIdentifier::Float) => {
+                s.trim().parse().map(PrismaValue::Float).map_err(|_| failure(&s))
+            }
+            (PrismaValue::String(s), TypeIdentifier::Boolean) => match s.trim() {
+                "true" => Ok(PrismaValue::Boolean(true)),
+                "false" => Ok(PrismaValue::Boolean(false)),
+                _ => Err(failure(&s)),
+            },
+            (PrismaValue::String(s), TypeIdentifier::DateTime) => DateTime::parse_from_rfc3339(s.trim())
+                .map(|dt| PrismaValue::DateTime(dt.with_timezone(&Utc)))
+                .map_err(|_| failure(&s)),
+            (PrismaValue::String(s), TypeIdentifier::UUID) => Uuid::parse_str(s.trim())
+                .map(PrismaValue::Uuid)
+                .map_err(|_| failure(&s)),
+            (PrismaValue::String(s), TypeIdentifier::Enum) => Ok(PrismaValue::Enum(EnumValue::string(s.clone(), s))),
+            (PrismaValue::String(s), TypeIdentifier::Json) => {
+                serde_json::from_str(&s).map(PrismaValue::Json).map_err(|_| failure(&s))
+            }
+
+            (PrismaValue::Int(i), TypeIdentifier::Int) | (PrismaValue::Int(i), TypeIdentifier::BigInt) => {
+                Ok(PrismaValue::Int(i))
+            }
+            (PrismaValue::Int(i), TypeIdentifier::Float) => Ok(PrismaValue::Float(i as f64)),
+            (PrismaValue::Int(i), TypeIdentifier::GraphQLID) => Ok(PrismaValue::GraphqlId(GraphqlId::from(i))),
+
+            (value @ PrismaValue::Float(_), TypeIdentifier::Float)
+            | (value @ PrismaValue::Boolean(_), TypeIdentifier::Boolean)
+            | (value @ PrismaValue::DateTime(_), TypeIdentifier::DateTime)
+            | (value @ PrismaValue::Enum(_), TypeIdentifier::Enum)
+            | (value @ PrismaValue::Json(_), TypeIdentifier::Json)
+            | (value @ PrismaValue::Uuid(_), TypeIdentifier::UUID)
+            | (value @ PrismaValue::GraphqlId(_), TypeIdentifier::GraphQLID) => Ok(value),
+
+            (value, _) => Err(failure(&value)),
+        }
+    }
 }
 
 impl fmt::Display for PrismaValue {
@@ -124,20 +124,21 @@ impl Relation {
     pub const TABLE_ALIAS: &'static str = "RelationTable";
 
     /// Returns `true` only if the `Relation` is just a link between two
-    /// `RelationField`s.
-    pub fn is_inline_relation(&self) -> bool {
-        self.manifestation
-            .as_ref()
-            .map(|manifestation| match manifestation {
-                RelationLinkManifestation::Inline(_) => true,
-                _ => false,
-            })
-            .unwrap_or(false)
+    /// `RelationField`s. A relation without manifestation is neither inline
+    /// nor a relation table.
+    pub fn is_inline(&self) -> bool {
+        match self.manifestation {
+            Some(RelationLinkManifestation::Inline(_)) => true,
+            _ => false,
+        }
     }
 
-    /// Returns `true` if the `Relation` is a table linking two models.
+    /// Returns `true` only if the `Relation` is a table linking two models.
     pub fn is_relation_table(&self) -> bool {
-        !self.is_inline_relation()
+        match self.manifestation {
+            Some(RelationLinkManifestation::RelationTable(_)) => true,
+            _ => false,
+        }
     }
 
     /// A model that relates to itself. For example a `Person` that is a parent
@@ -147,14 +148,17 @@ impl Relation {
     }
 
     /// A helper function to decide actions based on the `Relation` type. Inline
-    /// relation will return a column for updates, a relation table gives back
-    /// `None`.
-    pub fn inline_relation_column(&self) -> Option<Column<'static>> {
-        if let Some(mani) = self.inline_manifestation() {
-            Some(Column::from(mani.referencing_column.clone()).table(self.relation_table()))
-        } else {
-            None
-        }
+    /// relation will return the model holding the foreign key and the name of the
+    /// referencing column for updates, a relation table gives back `None`.
+    pub fn inline_relation_column(&self) -> Option<(ModelRef, String)> {
+        self.inline_manifestation().map(|mani| {
+            let model = self
+                .internal_data_model()
+                .find_model(&mani.in_table_of_model_name)
+                .unwrap();
+
+            (model, mani.referencing_column.clone())
+        })
     }
 
     /// A pointer to the first `Model` in the `Relation`.
@@ -318,6 +322,18 @@ impl Relation {
         self.model_a().name == model.name || self.model_b().name == model.name
     }
 
+    /// The model on the opposite end of the relation from the model with the given name. A self relation
+    /// returns the model itself. Returns `None` if the model is not part of the relation.
+    pub fn other_side(&self, model_name: &str) -> Option<ModelRef> {
+        if model_name == self.model_a_name {
+            Some(self.model_b())
+        } else if model_name == self.model_b_name {
+            Some(self.model_a())
+        } else {
+            None
+        }
+    }
+
     pub fn get_field_on_model(&self, model_id: &str) -> DomainResult<Arc<RelationField>> {
         if model_id == self.model_a().name {
             Ok(self.field_a())
@@ -1,6 +1,14 @@
-use crate::ScalarField;
+use crate::{ScalarField, TypeIdentifier};
 use prisma_query::ast::*;
 
+/// Describes the side table holding the values of a scalar list field, e.g. `User_tags`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalarListTableDescriptor {
+    pub table_name: String,
+    /// The type of the `nodeId` column, i.e. the type of the id of the parent model.
+    pub node_id_type: TypeIdentifier,
+}
+
 #[derive(Debug, Clone)]
 pub struct ScalarListTable<'a> {
     parent_field: &'a ScalarField,
@@ -40,4 +48,11 @@ impl<'a> ScalarListTable<'a> {
     pub fn value_column(&self) -> Column<'static> {
         Column::from(Self::VALUE_FIELD_NAME).table(self.table())
     }
+
+    pub fn descriptor(&self) -> ScalarListTableDescriptor {
+        ScalarListTableDescriptor {
+            table_name: self.table_name.clone(),
+            node_id_type: self.parent_field.model().fields().id().type_identifier,
+        }
+    }
 }
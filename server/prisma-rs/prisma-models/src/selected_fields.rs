@@ -178,7 +178,7 @@ impl SelectedFields {
             .filter(|rf| {
                 let relation = rf.relation();
                 let related = rf.related_field();
-                let is_inline = relation.is_inline_relation();
+                let is_inline = relation.is_inline();
                 let is_self = relation.is_self_relation();
 
                 let is_intable = relation
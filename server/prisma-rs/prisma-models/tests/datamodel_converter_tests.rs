@@ -1,5 +1,6 @@
 #![allow(non_snake_case)]
 use prisma_models::*;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 #[test]
@@ -36,7 +37,8 @@ fn converting_enums() {
         field.internal_enum,
         Some(InternalEnum {
             name: "MyEnum".to_string(),
-            values: expected_values
+            values: expected_values,
+            db_values: BTreeMap::new(),
         })
     );
 }
@@ -135,6 +137,39 @@ fn scalar_lists_work() {
         });
 }
 
+#[test]
+fn scalar_list_tables_must_be_listed_per_model() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id String @id @default(cuid())
+                strings String[]
+                enums Status[]
+            }
+
+            enum Status {
+                DRAFT
+                PUBLISHED
+            }
+        "#,
+    );
+    let model = datamodel.assert_model("Test");
+
+    assert_eq!(
+        model.scalar_list_tables(),
+        vec![
+            ScalarListTableDescriptor {
+                table_name: "Test_strings".to_string(),
+                node_id_type: TypeIdentifier::GraphQLID,
+            },
+            ScalarListTableDescriptor {
+                table_name: "Test_enums".to_string(),
+                node_id_type: TypeIdentifier::GraphQLID,
+            },
+        ]
+    );
+}
+
 #[test]
 fn unique_works() {
     let datamodel = convert(
@@ -185,6 +220,214 @@ fn cuid_fields_must_work() {
         .assert_type_identifier(TypeIdentifier::GraphQLID);
 }
 
+#[test]
+fn string_ids_must_map_to_the_type_identifier_of_their_generation_strategy() {
+    let cases = vec![
+        ("@default(cuid())", TypeIdentifier::GraphQLID),
+        ("@default(auto())", TypeIdentifier::GraphQLID),
+        ("@default(uuid())", TypeIdentifier::UUID),
+        ("", TypeIdentifier::String),
+    ];
+
+    for (default, expected) in cases {
+        let datamodel = convert(&format!(
+            r#"
+                model Test {{
+                    id String @id {}
+                }}
+            "#,
+            default
+        ));
+
+        let field = datamodel.assert_model("Test").assert_scalar_field("id");
+        assert_eq!(field.type_identifier, expected, "for `String @id {}`", default);
+    }
+}
+
+#[test]
+fn xml_fields_must_work() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id Int @id
+                content Xml
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Test");
+    model
+        .assert_scalar_field("content")
+        .assert_type_identifier(TypeIdentifier::Xml);
+}
+
+#[test]
+fn big_int_fields_must_work() {
+    let datamodel = convert(
+        r#"
+            model Tweet {
+                id BigInt @id @default(auto())
+                retweets BigInt @default(9007199254740993)
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Tweet");
+    model
+        .assert_scalar_field("id")
+        .assert_type_identifier(TypeIdentifier::BigInt)
+        .assert_is_auto_generated_by_db();
+
+    let retweets = model.assert_scalar_field("retweets");
+    retweets.assert_type_identifier(TypeIdentifier::BigInt);
+    assert_eq!(retweets.default_value, Some(PrismaValue::Int(9007199254740993)));
+}
+
+#[test]
+fn big_int_ids_must_work_without_a_default() {
+    let datamodel = convert(
+        r#"
+            model Tweet {
+                id BigInt @id
+            }
+
+            model Retweet {
+                id BigInt @id(strategy: NONE)
+            }
+        "#,
+    );
+
+    datamodel
+        .assert_model("Tweet")
+        .assert_scalar_field("id")
+        .assert_type_identifier(TypeIdentifier::BigInt)
+        .assert_behaviour(FieldBehaviour::Id {
+            strategy: IdStrategy::Auto,
+            sequence: None,
+        })
+        .assert_is_auto_generated_by_db();
+    datamodel
+        .assert_model("Retweet")
+        .assert_scalar_field("id")
+        .assert_type_identifier(TypeIdentifier::BigInt)
+        .assert_behaviour(FieldBehaviour::Id {
+            strategy: IdStrategy::None,
+            sequence: None,
+        });
+}
+
+#[test]
+fn negative_numeric_defaults_must_be_kept() {
+    let datamodel = convert(
+        r#"
+            model Counter {
+                id Int @id
+                count Int @default(-1)
+                ratio Float @default(-0.5)
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Counter");
+    assert_eq!(
+        model.assert_scalar_field("count").default_value,
+        Some(PrismaValue::Int(-1))
+    );
+    assert_eq!(
+        model.assert_scalar_field("ratio").default_value,
+        Some(PrismaValue::Float(-0.5))
+    );
+}
+
+#[test]
+fn auto_ids_must_be_generated_by_the_datastore() {
+    let datamodel = convert(
+        r#"
+            model IntId {
+                id Int @id @default(auto())
+            }
+
+            model StringId {
+                id String @id @default(auto())
+            }
+        "#,
+    );
+
+    let external_id = FieldBehaviour::Id {
+        strategy: IdStrategy::External,
+        sequence: None,
+    };
+    datamodel
+        .assert_model("IntId")
+        .assert_scalar_field("id")
+        .assert_type_identifier(TypeIdentifier::Int)
+        .assert_behaviour(external_id.clone())
+        .assert_is_auto_generated_by_db();
+    datamodel
+        .assert_model("StringId")
+        .assert_scalar_field("id")
+        .assert_type_identifier(TypeIdentifier::GraphQLID)
+        .assert_behaviour(external_id);
+}
+
+#[test]
+fn sequence_ids_must_keep_their_sequence() {
+    let datamodel = convert(
+        r#"
+            model Named {
+                id Int @id(strategy: SEQUENCE, sequence: "named_id_seq")
+            }
+
+            model Unnamed {
+                id Int @id(strategy: SEQUENCE)
+            }
+        "#,
+    );
+
+    datamodel
+        .assert_model("Named")
+        .assert_scalar_field("id")
+        .assert_behaviour(FieldBehaviour::Id {
+            strategy: IdStrategy::Sequence,
+            sequence: Some(Sequence {
+                name: "named_id_seq".to_string(),
+                initial_value: 1,
+                allocation_size: 1,
+            }),
+        })
+        .assert_is_auto_generated_by_db();
+    datamodel
+        .assert_model("Unnamed")
+        .assert_scalar_field("id")
+        .assert_behaviour(FieldBehaviour::Id {
+            strategy: IdStrategy::Sequence,
+            sequence: None,
+        });
+}
+
+#[test]
+fn documentation_must_be_preserved() {
+    let datamodel = convert(
+        r#"
+            /// A user of the blog.
+            model User {
+                id Int @id
+                /// The name that is shown next to posts.
+                name String
+                age Int
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("User");
+    assert_eq!(model.documentation, Some("A user of the blog.".to_string()));
+    assert_eq!(
+        model.assert_scalar_field("name").documentation,
+        Some("The name that is shown next to posts.".to_string())
+    );
+    assert_eq!(model.assert_scalar_field("age").documentation, None);
+}
+
 #[test]
 fn createdAt_works() {
     let datamodel = convert(
@@ -261,6 +504,156 @@ fn explicit_relation_fields() {
             in_table_of_model_name: "Post".to_string(),
             referencing_column: "blog_id".to_string(),
         }));
+
+    assert!(relation.is_inline());
+    assert!(!relation.is_relation_table());
+}
+
+#[test]
+fn on_delete_must_cascade_from_the_referenced_side_of_required_relations() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id Int @id
+                posts Post[]
+                comments Comment[]
+                reviews Review[]
+            }
+
+            model Post {
+                id Int @id
+                author User
+            }
+
+            model Comment {
+                id Int @id
+                author User?
+            }
+
+            model Review {
+                id Int @id
+                author User @relation(onDelete: RESTRICT)
+            }
+        "#,
+    );
+
+    let posts = datamodel.assert_relation("PostToUser");
+    assert_eq!(posts.model_a_on_delete, OnDelete::SetNull);
+    assert_eq!(posts.model_b_on_delete, OnDelete::Cascade);
+
+    let comments = datamodel.assert_relation("CommentToUser");
+    assert_eq!(comments.model_a_on_delete, OnDelete::SetNull);
+    assert_eq!(comments.model_b_on_delete, OnDelete::SetNull);
+
+    let reviews = datamodel.assert_relation("ReviewToUser");
+    assert_eq!(reviews.model_a_on_delete, OnDelete::SetNull);
+    assert_eq!(reviews.model_b_on_delete, OnDelete::SetNull);
+
+    let user = datamodel.assert_model("User");
+    let cascading: Vec<String> = user
+        .fields()
+        .cascading_relation()
+        .iter()
+        .map(|f| f.name.clone())
+        .collect();
+    assert_eq!(cascading, vec!["posts".to_string()]);
+}
+
+#[test]
+fn explicit_link_models_must_relate_to_both_sides() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id Int @id
+                tags PostToTag[]
+            }
+
+            model Tag {
+                id Int @id
+                posts PostToTag[]
+            }
+
+            model PostToTag {
+                id Int @id
+                post Post
+                tag Tag
+                createdAt DateTime @default(now())
+            }
+        "#,
+    );
+
+    let link = datamodel.assert_model("PostToTag");
+    link.assert_scalar_field("createdAt")
+        .assert_type_identifier(TypeIdentifier::DateTime);
+    link.assert_relation_field("post")
+        .assert_relation_name("PostToPostToTag")
+        .assert_side(RelationSide::B);
+    link.assert_relation_field("tag")
+        .assert_relation_name("PostToTagToTag")
+        .assert_side(RelationSide::A);
+
+    datamodel
+        .assert_relation("PostToPostToTag")
+        .assert_model_a("Post")
+        .assert_model_b("PostToTag")
+        .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
+            in_table_of_model_name: "PostToTag".to_string(),
+            referencing_column: "post".to_string(),
+        }));
+    datamodel
+        .assert_relation("PostToTagToTag")
+        .assert_model_a("PostToTag")
+        .assert_model_b("Tag")
+        .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
+            in_table_of_model_name: "PostToTag".to_string(),
+            referencing_column: "tag".to_string(),
+        }));
+
+    // The link model replaces the relation table, so there is no many-to-many relation left.
+    assert!(datamodel
+        .relations()
+        .iter()
+        .all(|r| r.inline_relation_column().is_some()));
+}
+
+#[test]
+fn explicit_foreign_key_fields() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blogId Int? @map(name: "blog_id")
+                blog Blog? @relation(fields: [blogId], references: [id])
+            }
+        "#,
+    );
+
+    let relation_name = "BlogToPost";
+    let post = datamodel.assert_model("Post");
+    let relation = datamodel.assert_relation(relation_name);
+
+    post.assert_scalar_field("blogId")
+        .assert_type_identifier(TypeIdentifier::Int)
+        .assert_optional();
+
+    post.assert_relation_field("blog")
+        .assert_optional()
+        .assert_relation_name(relation_name)
+        .assert_side(RelationSide::B);
+
+    relation
+        .assert_name(relation_name)
+        .assert_model_a("Blog")
+        .assert_model_b("Post")
+        .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
+            in_table_of_model_name: "Post".to_string(),
+            referencing_column: "blog_id".to_string(),
+        }));
 }
 
 #[test]
@@ -306,6 +699,95 @@ fn many_to_many_relations() {
             model_b_column: "B".to_string(),
             id_column: None,
         }));
+
+    assert!(relation.inline_relation_column().is_none());
+    assert!(relation.is_relation_table());
+    assert!(!relation.is_inline());
+}
+
+#[test]
+fn table_names_must_include_model_scalar_list_and_relation_tables() {
+    let datamodel = convert(
+        r#"
+            model Post {
+                id Int @id
+                tags String[]
+                blogs Blog[]
+                author User?
+            }
+
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+
+            model User {
+                id Int @id
+                posts Post[]
+            }
+        "#,
+    );
+
+    assert_eq!(
+        datamodel.table_names(),
+        vec!["Post", "Blog", "User", "Post_tags", "_BlogToPost"]
+    );
+}
+
+#[test]
+fn table_names_must_not_include_tables_for_embedded_lists_and_relations() {
+    let datamodel = convert(
+        r#"
+            model Parent {
+                id Int @id
+                tags String[]
+                scores Int[] @scalarList(strategy: EMBEDDED)
+                child Child
+            }
+
+            model Child {
+                id Int @id
+
+                @@embedded
+            }
+        "#,
+    );
+
+    assert_eq!(datamodel.table_names(), vec!["Parent", "Child", "Parent_tags"]);
+}
+
+#[test]
+fn many_to_many_relation_tables_must_follow_the_naming_strategy() {
+    let datamodel = datamodel::parse(
+        r#"
+            model Post {
+                id Int @id
+                blogs Blog[]
+            }
+
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+        "#,
+    )
+    .unwrap();
+    let naming = RelationTableNaming {
+        underscore_prefix: false,
+        alphabetical: false,
+    };
+    let datamodel = DatamodelConverter::convert_with_relation_table_naming(&datamodel, naming)
+        .unwrap()
+        .build("not_important".to_string());
+
+    datamodel
+        .assert_relation("BlogToPost")
+        .assert_manifestation(RelationLinkManifestation::RelationTable(RelationTable {
+            table: "PostToBlog".to_string(),
+            model_a_column: "A".to_string(),
+            model_b_column: "B".to_string(),
+            id_column: None,
+        }));
 }
 
 #[test]
@@ -337,6 +819,9 @@ fn implicit_relation_fields() {
             in_table_of_model_name: "Post".to_string(),
             referencing_column: "blog".to_string(),
         }));
+
+    let (model, column) = relation.inline_relation_column().unwrap();
+    assert_eq!((model.name.as_str(), column.as_str()), ("Post", "blog"));
 }
 
 #[test]
@@ -415,9 +900,387 @@ fn ambiguous_relations() {
     post.assert_relation_field("blog2").assert_relation_name("Relation2");
 }
 
+#[test]
+fn find_relation_field_must_resolve_the_field_by_model_and_relation_name() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id    Int  @id
+                post1 Post @relation(name: "Relation1")
+                post2 Post @relation(name: "Relation2")
+            }
+
+            model Post {
+                id    Int  @id
+                blog1 Blog @relation(name: "Relation1")
+                blog2 Blog @relation(name: "Relation2")
+            }
+        "#,
+    );
+
+    let field = datamodel.find_relation_field("Blog", "Relation1").unwrap();
+    assert_eq!(field.name, "post1");
+    assert_eq!(field.model().name, "Blog");
+
+    let field = datamodel.find_relation_field("Post", "Relation2").unwrap();
+    assert_eq!(field.name, "blog2");
+
+    assert!(datamodel.find_relation_field("Blog", "DoesNotExist").is_none());
+    assert!(datamodel.find_relation_field("DoesNotExist", "Relation1").is_none());
+}
+
+#[test]
+fn find_field_resolves_scalar_and_relation_fields() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                name String
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blog Blog
+            }
+        "#,
+    );
+
+    match datamodel.find_field("Blog", "name") {
+        Some(Field::Scalar(field)) => assert_eq!(field.type_identifier, TypeIdentifier::String),
+        x => panic!("Expected a scalar field, got {:?}", x),
+    }
+
+    match datamodel.find_field("Blog", "posts") {
+        Some(Field::Relation(field)) => assert_eq!(field.is_list, true),
+        x => panic!("Expected a relation field, got {:?}", x),
+    }
+
+    assert!(datamodel.find_field("Blog", "doesNotExist").is_none());
+    assert!(datamodel.find_field("DoesNotExist", "name").is_none());
+}
+
+#[test]
+fn fields_of_a_model_must_be_iterable_as_one_enum() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                name String?
+                tags String[]
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blog Blog
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Blog");
+    let fields: Vec<(&str, FieldArity, bool)> = model
+        .fields()
+        .iter()
+        .map(|field| (field.name(), field.arity(), field.is_scalar()))
+        .collect();
+
+    assert_eq!(
+        fields,
+        vec![
+            ("id", FieldArity::Required, true),
+            ("name", FieldArity::Optional, true),
+            ("tags", FieldArity::List, true),
+            ("posts", FieldArity::List, false),
+        ]
+    );
+    assert_eq!(datamodel.assert_model("Post").fields().into_iter().count(), 2);
+}
+
+#[test]
+fn models_topologically_sorted_must_put_referenced_models_first() {
+    let datamodel = convert(
+        r#"
+            model Comment {
+                id Int @id
+                post Post
+            }
+
+            model Post {
+                id Int @id
+                blog Blog
+                comments Comment[]
+            }
+
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+        "#,
+    );
+
+    let names: Vec<String> = datamodel
+        .models_topologically_sorted()
+        .unwrap()
+        .iter()
+        .map(|model| model.name.clone())
+        .collect();
+
+    assert_eq!(names, vec!["Blog", "Post", "Comment"]);
+}
+
+#[test]
+fn models_topologically_sorted_must_fail_on_cycles() {
+    let datamodel = convert(
+        r#"
+            model A {
+                id Int @id
+                b B
+                cs C[]
+            }
+
+            model B {
+                id Int @id
+                c C
+                aList A[]
+            }
+
+            model C {
+                id Int @id
+                a A
+                bs B[]
+            }
+        "#,
+    );
+
+    assert_eq!(
+        datamodel.models_topologically_sorted().map(|_| ()),
+        Err(CycleError {
+            models: vec!["A".to_string(), "B".to_string(), "C".to_string()]
+        })
+    );
+}
+
+#[test]
+fn relations_to_embedded_models_without_back_relation_field_must_work() {
+    let datamodel = convert(
+        r#"
+            model Parent {
+                id Int @id
+                child Child
+            }
+
+            model Child {
+                id Int @id
+                name String
+
+                @@embedded
+            }
+        "#,
+    );
+
+    let parent = datamodel.assert_model("Parent");
+    let child = datamodel.assert_model("Child");
+    assert_eq!(child.is_embedded, true);
+
+    parent
+        .assert_relation_field("child")
+        .assert_relation_name("ChildToParent");
+
+    // The embedded model is stored within its parent, so no column or table links them.
+    let relation = datamodel.assert_relation("ChildToParent");
+    relation.assert_model_a("Child").assert_model_b("Parent");
+    assert_eq!(relation.manifestation, None);
+}
+
+#[test]
+fn the_primary_identifier_of_a_model_with_a_single_id_must_be_its_id_field() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id Int @id
+                email String @unique
+            }
+        "#,
+    );
+
+    let identifier = datamodel.assert_model("User").primary_identifier().unwrap();
+    let names: Vec<&str> = identifier.iter().map(|sf| sf.name.as_str()).collect();
+
+    assert_eq!(names, vec!["id"]);
+}
+
+#[test]
+fn the_primary_identifier_of_a_model_with_a_compound_id_must_be_the_id_fields_in_order() {
+    let datamodel = convert(
+        r#"
+            model User {
+                firstName String
+                lastName String
+                email String @unique
+
+                @@id([lastName, firstName])
+            }
+        "#,
+    );
+
+    let identifier = datamodel.assert_model("User").primary_identifier().unwrap();
+    let names: Vec<&str> = identifier.iter().map(|sf| sf.name.as_str()).collect();
+
+    assert_eq!(names, vec!["lastName", "firstName"]);
+}
+
+#[test]
+fn the_primary_identifier_of_a_join_model_without_id_must_be_its_first_unique_index() {
+    let datamodel = datamodel::parse(
+        r#"
+            model PostToTag {
+                id Int @id
+                postId Int
+                tagId Int
+                label String @unique
+
+                @@unique([postId, tagId])
+            }
+        "#,
+    )
+    .unwrap();
+
+    // The datamodel requires an id, so it is removed from the template, like in join tables without id.
+    let mut template = DatamodelConverter::convert(&datamodel).unwrap();
+    template.models[0].fields.retain(|field| match field {
+        FieldTemplate::Scalar(sf) => sf.name != "id",
+        FieldTemplate::Relation(_) => true,
+    });
+    let datamodel = template.build("not_important".to_string());
+
+    let identifier = datamodel.assert_model("PostToTag").primary_identifier().unwrap();
+    let names: Vec<&str> = identifier.iter().map(|sf| sf.name.as_str()).collect();
+
+    assert_eq!(names, vec!["postId", "tagId"]);
+}
+
+#[test]
+fn the_primary_identifier_of_a_model_with_a_relation_field_in_its_compound_id_must_be_an_error() {
+    let datamodel = datamodel::parse(
+        r#"
+            model Post {
+                id Int @id
+                author User
+                slug String
+            }
+
+            model User {
+                id Int @id
+                posts Post[]
+            }
+        "#,
+    )
+    .unwrap();
+
+    // The datamodel rejects relation fields in `@@id([author, slug])`, so the compound id is set on the template.
+    let mut template = DatamodelConverter::convert(&datamodel).unwrap();
+    let post = template.models.iter_mut().find(|model| model.name == "Post").unwrap();
+    post.compound_id_field_names = vec!["author".to_string(), "slug".to_string()];
+    let datamodel = template.build("not_important".to_string());
+
+    let error = datamodel.assert_model("Post").primary_identifier().unwrap_err();
+
+    assert_eq!(error.to_string(), "ScalarField `author` on model `Post` not found");
+}
+
+#[test]
+fn default_values_that_cannot_be_coerced_must_be_reported_as_errors() {
+    let mut datamodel = datamodel::parse(
+        r#"
+            model User {
+                id Int @id
+                age Int @default(0)
+            }
+        "#,
+    )
+    .unwrap();
+
+    // The parser only produces valid defaults, so the invalid one is set directly.
+    datamodel
+        .find_model_mut("User")
+        .unwrap()
+        .find_field_mut("age")
+        .unwrap()
+        .default_value = Some(datamodel::dml::Value::String(String::from("zero")));
+
+    let errors = DatamodelConverter::convert(&datamodel).unwrap_err();
+
+    assert_eq!(
+        errors.errors,
+        vec![datamodel::errors::ValidationError::new_directive_validation_error(
+            "Invalid default value of field `age`: The value `zero` cannot be coerced to the type `Int`.",
+            "default",
+            datamodel::ast::Span::empty(),
+        )]
+    );
+}
+
+#[test]
+fn final_database_names_must_resolve_map_and_fall_back_to_the_field_name() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                title String @map(name: "blog_title")
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blog Blog? @map(name: "blog_id")
+            }
+        "#,
+    );
+
+    let blog = datamodel.assert_model("Blog");
+    let post = datamodel.assert_model("Post");
+
+    assert_eq!(blog.assert_scalar_field("title").final_database_name(), "blog_title");
+    assert_eq!(blog.assert_scalar_field("id").final_database_name(), "id");
+    assert_eq!(post.assert_relation_field("blog").final_database_name(), "blog_id");
+    assert_eq!(blog.assert_relation_field("posts").final_database_name(), "posts");
+}
+
+#[test]
+fn the_other_side_of_a_relation_must_be_the_opposite_model() {
+    let datamodel = convert(
+        r#"
+            model Blog {
+                id Int @id
+                posts Post[]
+            }
+
+            model Post {
+                id Int @id
+                blog Blog
+            }
+
+            model Employee {
+                id Int @id
+                reportsTo Employee?
+            }
+        "#,
+    );
+
+    let relation = datamodel.assert_relation("BlogToPost");
+    assert_eq!(relation.other_side("Blog").unwrap().name, "Post");
+    assert_eq!(relation.other_side("Post").unwrap().name, "Blog");
+    assert!(relation.other_side("Employee").is_none());
+
+    let self_relation = datamodel.assert_relation("EmployeeToEmployee");
+    assert_eq!(self_relation.other_side("Employee").unwrap().name, "Employee");
+    assert!(self_relation.other_side("Blog").is_none());
+}
+
 fn convert(datamodel: &str) -> Arc<InternalDataModel> {
     let datamodel = dbg!(datamodel::parse(datamodel).unwrap());
-    let template = DatamodelConverter::convert(&datamodel);
+    let template = DatamodelConverter::convert(&datamodel).unwrap();
     template.build("not_important".to_string())
 }
 
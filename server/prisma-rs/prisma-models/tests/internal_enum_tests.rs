@@ -0,0 +1,48 @@
+use prisma_models::*;
+use std::collections::BTreeMap;
+
+#[test]
+fn mapped_enum_values_must_be_translated_in_both_directions() {
+    let mut db_values = BTreeMap::new();
+    db_values.insert("ACTIVE".to_string(), "active".to_string());
+    db_values.insert("INACTIVE".to_string(), "inactive".to_string());
+
+    let internal_enum = InternalEnum {
+        name: "Status".to_string(),
+        values: vec!["ACTIVE".to_string(), "INACTIVE".to_string(), "ARCHIVED".to_string()],
+        db_values,
+    };
+
+    assert_eq!(internal_enum.db_value_for("ACTIVE"), "active");
+    assert_eq!(internal_enum.db_value_for("INACTIVE"), "inactive");
+    assert_eq!(internal_enum.name_for_db_value("active"), "ACTIVE");
+    assert_eq!(internal_enum.name_for_db_value("inactive"), "INACTIVE");
+
+    // Values without a mapping are stored under their name.
+    assert_eq!(internal_enum.db_value_for("ARCHIVED"), "ARCHIVED");
+    assert_eq!(internal_enum.name_for_db_value("ARCHIVED"), "ARCHIVED");
+}
+
+#[test]
+fn unmapped_enum_values_must_be_stored_under_their_name() {
+    let datamodel = DatamodelConverter::convert_string(
+        r#"
+            model Test {
+                id Int @id
+                status Status
+            }
+
+            enum Status {
+                ACTIVE
+                INACTIVE
+            }
+        "#
+        .to_string(),
+    );
+    let internal_enum = datamodel.enums.iter().find(|e| e.name == "Status").unwrap();
+
+    for value in internal_enum.values.iter() {
+        assert_eq!(internal_enum.db_value_for(value), value);
+        assert_eq!(internal_enum.name_for_db_value(value), value);
+    }
+}
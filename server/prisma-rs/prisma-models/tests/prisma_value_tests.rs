@@ -0,0 +1,40 @@
+use prisma_models::*;
+
+#[test]
+fn strings_must_be_coerced_to_ints() {
+    let value = PrismaValue::from("0").coerce(TypeIdentifier::Int).unwrap();
+
+    assert_eq!(value, PrismaValue::Int(0));
+}
+
+#[test]
+fn strings_must_be_coerced_to_booleans() {
+    assert_eq!(
+        PrismaValue::from("true").coerce(TypeIdentifier::Boolean).unwrap(),
+        PrismaValue::Boolean(true)
+    );
+    assert_eq!(
+        PrismaValue::from("false").coerce(TypeIdentifier::Boolean).unwrap(),
+        PrismaValue::Boolean(false)
+    );
+}
+
+#[test]
+fn coercing_a_string_that_is_not_a_number_to_an_int_must_fail() {
+    let error = PrismaValue::from("zero").coerce(TypeIdentifier::Int).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "The value `zero` cannot be coerced to the type `Int`."
+    );
+}
+
+#[test]
+fn values_of_lists_must_be_coerced_one_by_one() {
+    let list = PrismaValue::List(Some(vec![PrismaValue::Int(1), PrismaValue::from("2")]));
+
+    assert_eq!(
+        list.coerce(TypeIdentifier::Float).unwrap(),
+        PrismaValue::List(Some(vec![PrismaValue::Float(1.0), PrismaValue::Float(2.0)]))
+    );
+}
@@ -47,9 +47,7 @@ impl WriteQueryBuilder {
         let relation = field.relation();
 
         match relation.inline_relation_column() {
-            Some(column) => {
-                let referencing_column = column.name.to_string();
-
+            Some((model, referencing_column)) => {
                 let (update_id, link_id) = match field.relation_is_inlined_in_parent() {
                     true => (parent_id, child_id),
                     false => (child_id, parent_id),
@@ -60,7 +58,7 @@ impl WriteQueryBuilder {
                     false => field.related_model().fields().id().as_column().equals(update_id),
                 };
 
-                Update::table(relation.relation_table())
+                Update::table(model.table())
                     .set(referencing_column, link_id.clone())
                     .so_that(update_condition)
                     .into()
@@ -63,8 +63,8 @@ pub trait NestedActions {
         let condition = relation_column.equals(id.clone());
 
         match relation.inline_relation_column() {
-            Some(column) => Update::table(relation.relation_table())
-                .set(column.name.to_string(), PrismaValue::Null)
+            Some((model, column)) => Update::table(model.table())
+                .set(column, PrismaValue::Null)
                 .so_that(condition)
                 .into(),
             None => Delete::from_table(relation.relation_table()).so_that(condition).into(),
@@ -82,8 +82,8 @@ pub trait NestedActions {
             .equals(id.clone());
 
         match relation.inline_relation_column() {
-            Some(column) => Update::table(relation.relation_table())
-                .set(column.name.to_string(), PrismaValue::Null)
+            Some((model, column)) => Update::table(model.table())
+                .set(column, PrismaValue::Null)
                 .so_that(condition)
                 .into(),
             None => Delete::from_table(relation.relation_table()).so_that(condition).into(),
@@ -100,8 +100,8 @@ pub trait NestedActions {
         let table = relation.relation_table();
 
         match relation.inline_relation_column() {
-            Some(column) => Update::table(table)
-                .set(column.name.to_string(), PrismaValue::Null)
+            Some((model, column)) => Update::table(model.table())
+                .set(column, PrismaValue::Null)
                 .so_that(is_child.and(is_parent))
                 .into(),
             None => Delete::from_table(table).so_that(is_child.and(is_parent)).into(),
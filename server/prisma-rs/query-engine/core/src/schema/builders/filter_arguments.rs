@@ -67,11 +67,13 @@ pub fn get_field_filters<'a>(field: &Field) -> Vec<&'a FilterArgument> {
             TypeIdentifier::GraphQLID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::BigInt => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Json => vec![],
+            TypeIdentifier::Xml => vec![&args.base, &args.inclusion],
             TypeIdentifier::Relation => vec![&args.one_relation],
         };
 
@@ -36,6 +36,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                     match (f.behaviour.as_ref(), f.type_identifier) {
                         (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::UUID)      => self.map_optional_input_type(f),
                         (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::GraphQLID) => self.map_optional_input_type(f),
+                        (Some(FieldBehaviour::Id { strategy: IdStrategy::External, .. }), TypeIdentifier::GraphQLID) => self.map_optional_input_type(f),
                         (None, TypeIdentifier::UUID)                                                             => self.map_optional_input_type(f),
                         (None, TypeIdentifier::GraphQLID)                                                        => self.map_optional_input_type(f),
 
@@ -146,6 +147,8 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
 
             (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::Int)       => false,
             (None, TypeIdentifier::Int)                                                              => false,
+            (None, TypeIdentifier::BigInt)                                                           => false,
+            (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::BigInt)    => false,
 
             (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::UUID)      => true,
             (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::GraphQLID) => true,
@@ -153,12 +156,17 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             (None, TypeIdentifier::GraphQLID)                                                        => true,
 
             (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::Int)       => false,
+            (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::BigInt)    => false,
 
             (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::UUID)      => true,
             (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::GraphQLID) => true,
 
             (Some(FieldBehaviour::Id { strategy: IdStrategy::Sequence, .. }), TypeIdentifier::Int)   => false,
 
+            (Some(FieldBehaviour::Id { strategy: IdStrategy::External, .. }), TypeIdentifier::Int)       => false,
+            (Some(FieldBehaviour::Id { strategy: IdStrategy::External, .. }), TypeIdentifier::BigInt)    => false,
+            (Some(FieldBehaviour::Id { strategy: IdStrategy::External, .. }), TypeIdentifier::GraphQLID) => true,
+
             _ => panic!("Id Behaviour unhandled"),
         }
     }
@@ -9,8 +9,8 @@ pub trait InputBuilderExtensions {
 
     fn map_required_input_type(&self, field: ScalarFieldRef) -> InputType {
         let typ = match field.type_identifier {
-            TypeIdentifier::String => InputType::string(),
-            TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::String | TypeIdentifier::Xml => InputType::string(),
+            TypeIdentifier::Int | TypeIdentifier::BigInt => InputType::int(),
             TypeIdentifier::Float => InputType::float(),
             TypeIdentifier::Boolean => InputType::boolean(),
             TypeIdentifier::GraphQLID => InputType::id(),
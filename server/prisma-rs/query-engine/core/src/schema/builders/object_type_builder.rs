@@ -106,7 +106,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                 }
             }
             ModelField::Scalar(sf) => match sf.type_identifier {
-                TypeIdentifier::String => OutputType::string(),
+                TypeIdentifier::String | TypeIdentifier::Xml => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
@@ -114,7 +114,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                 TypeIdentifier::DateTime => OutputType::date_time(),
                 TypeIdentifier::GraphQLID => OutputType::id(),
                 TypeIdentifier::UUID => OutputType::uuid(),
-                TypeIdentifier::Int => OutputType::int(),
+                TypeIdentifier::Int | TypeIdentifier::BigInt => OutputType::int(),
                 TypeIdentifier::Relation => unreachable!(), // Scalar fields can't have a Relation type identifier.
             },
         };
@@ -1,4 +1,4 @@
-use crate::{data_model_loader::{load_data_model_components, load_configuration}, dmmf, PrismaResult};
+use crate::{data_model_loader::{load_data_model_components, load_configuration}, dmmf, PrismaError, PrismaResult};
 use clap::ArgMatches;
 use core::{
     schema::{QuerySchemaBuilder, QuerySchemaRef, SupportedCapabilities},
@@ -78,7 +78,7 @@ impl CliCommand {
     }
 
     fn dmmf_to_dml(input: DmmfToDmlInput) -> PrismaResult<()> {
-        let datamodel = datamodel::dmmf::parse_from_dmmf(&input.dmmf);
+        let datamodel = datamodel::dmmf::parse_from_dmmf(&input.dmmf).map_err(PrismaError::SerializationError)?;
         let config = datamodel::config_from_mcf_json_value(input.config);
         let serialized = datamodel::render_with_config(&datamodel, &config)?;
 
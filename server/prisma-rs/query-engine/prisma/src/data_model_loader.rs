@@ -73,7 +73,7 @@ pub fn load_data_model_components() -> PrismaResult<(DatamodelV2Components, Inte
     // Load data model in order of precedence.
     match load_datamodel_v2()? {
         Some(v2components) => {
-            let template = DatamodelConverter::convert(&v2components.datamodel);
+            let template = DatamodelConverter::convert(&v2components.datamodel)?;
             Ok((v2components, template))
         }
         None => {